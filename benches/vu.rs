@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ps2_floating_point::vu::instruction::{decode_lower, decode_upper};
+use ps2_floating_point::vu::{FlagMode, VuInterpreter};
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+
+/// ADD.xyzw, SUB.xyzw and MAX.xyzw vf3, vf1, vf2.
+const UPPER: [u32; 3] = [0x01E208E8, 0x01E208EC, 0x01E208EB];
+/// NOP and MR32.xyzw vf4, vf5.
+const LOWER: [u32; 2] = [0x8000033C, 0x81E42B3D];
+const E_BIT: u32 = 0x40000000;
+const NOP_PAIR: u64 = 0x0000_02FF_8000_033C;
+
+/// Returns a straight-line program of `len` pairs ending with the E bit.
+fn program(len: usize) -> Vec<u64> {
+    let mut program: Vec<u64> = (0..len)
+        .map(|i| (UPPER[i % UPPER.len()] as u64) << 32 | LOWER[i % LOWER.len()] as u64)
+        .collect();
+    program[len - 1] |= (E_BIT as u64) << 32;
+    program.push(NOP_PAIR);
+    program
+}
+
+fn interpreter(len: usize) -> VuInterpreter {
+    let mut vu = VuInterpreter::new(program(len));
+    vu.registers.set_vf(1, Ps2Vec4::splat(Ps2Float::new(0x3F800000)));
+    vu.registers.set_vf(2, Ps2Vec4::splat(Ps2Float::new(0x40000000)));
+    vu
+}
+
+fn vu_run(c: &mut Criterion) {
+    let vu = interpreter(1024);
+    c.bench_function("vu_run_1024_pairs", |b| {
+        b.iter_batched(|| vu.clone(), |mut vu| vu.run(2048), BatchSize::SmallInput)
+    });
+
+    let mut vu = interpreter(1024);
+    vu.set_flag_mode(FlagMode::Deferred);
+    c.bench_function("vu_run_1024_pairs_deferred_flags", |b| {
+        b.iter_batched(|| vu.clone(), |mut vu| vu.run(2048), BatchSize::SmallInput)
+    });
+}
+
+fn vu_decode(c: &mut Criterion) {
+    let program = program(1024);
+    c.bench_function("vu_decode_1024_pairs", |b| {
+        b.iter(|| {
+            for pair in &program {
+                let upper = decode_upper(black_box((pair >> 32) as u32)).unwrap();
+                let lower = decode_lower(black_box(*pair as u32)).unwrap();
+                black_box((upper, lower));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, vu_run, vu_decode);
+criterion_main!(benches);
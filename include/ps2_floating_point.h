@@ -0,0 +1,59 @@
+/*
+ * C interface of the ps2_floating_point crate, built with `--features ffi`.
+ *
+ * Floats are passed as their 32-bit patterns.
+ */
+
+#ifndef PS2_FLOATING_POINT_H
+#define PS2_FLOATING_POINT_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The flags an operation raises. */
+typedef struct Ps2FloatFlags {
+    bool zero;
+    bool sign;
+    bool underflow;
+    bool overflow;
+} Ps2FloatFlags;
+
+/* The flags a division raises. */
+typedef struct Ps2FloatDivideFlags {
+    bool invalid;
+    bool divide;
+} Ps2FloatDivideFlags;
+
+/* Arithmetic with PS2 semantics. */
+uint32_t ps2float_add(uint32_t a, uint32_t b);
+uint32_t ps2float_sub(uint32_t a, uint32_t b);
+uint32_t ps2float_mul(uint32_t a, uint32_t b);
+uint32_t ps2float_div(uint32_t a, uint32_t b);
+
+/* Arithmetic also writing the flags it raises, unless flags is NULL. */
+uint32_t ps2float_add_with_flags(uint32_t a, uint32_t b, Ps2FloatFlags *flags);
+uint32_t ps2float_sub_with_flags(uint32_t a, uint32_t b, Ps2FloatFlags *flags);
+uint32_t ps2float_mul_with_flags(uint32_t a, uint32_t b, Ps2FloatFlags *flags);
+uint32_t ps2float_div_with_flags(uint32_t a, uint32_t b, Ps2FloatFlags *flags);
+
+/* Division writing the invalid and divide flags of DIV, unless flags is NULL. */
+uint32_t ps2float_div_with_divide_flags(uint32_t a, uint32_t b, Ps2FloatDivideFlags *flags);
+
+/* Converts a host float, flushing denormals to zero and keeping INF and NaN bits. */
+uint32_t ps2float_from_f32(float value);
+
+/* Returns the exact value of a PS2 float. */
+double ps2float_to_f64(uint32_t value);
+
+/* Returns -1, 0 or 1 if a is less than, equal to or greater than b. */
+int32_t ps2float_cmp(uint32_t a, uint32_t b);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PS2_FLOATING_POINT_H */
@@ -0,0 +1,113 @@
+//! The accumulator of the EE FPU multiply-accumulate instructions.
+//!
+//! ADDA.S, SUBA.S, MULA.S, MADDA.S and MSUBA.S write ACC instead of a float
+//! register, and MADD.S and MSUB.S read it. ACC is a 32-bit register like the
+//! others: every step truncates the product, then truncates the sum into ACC,
+//! so a chain is only bit exact if each step is stored the same way. The
+//! accumulator also remembers whether any step overflowed, like the
+//! accumulator flag PCSX2 saves with the FPU registers.
+
+use crate::Ps2Float;
+
+/// The EE FPU accumulator, with a method for each instruction using it.
+///
+/// The accumulator holds a stored 32-bit float, not a wider intermediate
+/// mantissa. ACC is a register like the others on both the EE FPU and the
+/// VU, and [`Ps2Float::madd`],
+/// [`Ps2Matrix::transform`](crate::Ps2Matrix::transform) and the VU
+/// interpreter truncate into it at every step. Keeping extra bits between
+/// steps would round long MADD chains differently from the console, so
+/// [`Ps2Accumulator::store`] returns the value as held.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{Ps2Accumulator, Ps2Float};
+///
+/// let f = Ps2Float::new;
+/// let (m, v) = ([0x3F800000, 0x40000000, 0x40400000].map(f), [0x40800000; 3].map(f));
+///
+/// // 1 * 4 + 2 * 4 + 3 * 4
+/// let acc = Ps2Accumulator::mula(m[0], v[0]).madda(m[1], v[1]).madda(m[2], v[2]);
+/// assert_eq!(acc.store().as_u32(), 0x41C00000); // 24.0
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2Accumulator {
+    value: Ps2Float,
+    overflow: bool,
+}
+
+impl Ps2Accumulator {
+    /// Creates an accumulator holding a value, like a restored ACC.
+    pub fn new(value: Ps2Float) -> Self {
+        Self { value, overflow: false }
+    }
+
+    /// Sets the accumulator to the sum of two floats, like ADDA.S.
+    ///
+    /// The sum is truncated like [`Ps2Float::add`].
+    pub fn adda(a: Ps2Float, b: Ps2Float) -> Self {
+        let (value, overflow) = a.overflowing_add(b);
+        Self { value, overflow }
+    }
+
+    /// Sets the accumulator to the difference of two floats, like SUBA.S.
+    ///
+    /// The difference is truncated like [`Ps2Float::sub`].
+    pub fn suba(a: Ps2Float, b: Ps2Float) -> Self {
+        let (value, overflow) = a.overflowing_sub(b);
+        Self { value, overflow }
+    }
+
+    /// Sets the accumulator to the product of two floats, like MULA.S.
+    ///
+    /// The product is truncated like [`Ps2Float::mul`].
+    pub fn mula(a: Ps2Float, b: Ps2Float) -> Self {
+        let (value, overflow) = a.overflowing_mul(b);
+        Self { value, overflow }
+    }
+
+    /// Adds the product of two floats to the accumulator, like MADDA.S.
+    ///
+    /// The product is truncated before being added like in
+    /// [`Ps2Float::madd`].
+    pub fn madda(self, a: Ps2Float, b: Ps2Float) -> Self {
+        let (product, product_overflow) = a.overflowing_mul(b);
+        let (value, overflow) = self.value.overflowing_add(product);
+        Self { value, overflow: self.overflow || product_overflow || overflow }
+    }
+
+    /// Subtracts the product of two floats from the accumulator, like
+    /// MSUBA.S.
+    ///
+    /// The product is truncated before being subtracted like in
+    /// [`Ps2Float::msub`].
+    pub fn msuba(self, a: Ps2Float, b: Ps2Float) -> Self {
+        let (product, product_overflow) = a.overflowing_mul(b);
+        let (value, overflow) = self.value.overflowing_sub(product);
+        Self { value, overflow: self.overflow || product_overflow || overflow }
+    }
+
+    /// Returns the accumulator plus the product of two floats without
+    /// changing it, like MADD.S.
+    pub fn madd(&self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        a.madd(b, self.value)
+    }
+
+    /// Returns the accumulator minus the product of two floats without
+    /// changing it, like MSUB.S.
+    pub fn msub(&self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        a.msub(b, self.value)
+    }
+
+    /// Returns if a step feeding the accumulator overflowed and saturated to
+    /// +/- Fmax.
+    pub fn overflowed(&self) -> bool {
+        self.overflow
+    }
+
+    /// Returns the value of the accumulator as stored to a float register.
+    pub fn store(self) -> Ps2Float {
+        self.value
+    }
+}
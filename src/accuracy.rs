@@ -0,0 +1,246 @@
+//! Measuring the accuracy of PS2 float operations in ULPs.
+//!
+//! An [`AccuracyAnalysis`] samples operands with a [`Sampling`] strategy and
+//! collects a histogram of how many ULPs each result is away from the exact
+//! result, or from the result of another implementation.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::sampling::EdgeCaseGenerator;
+use crate::{Operation, Ps2Float};
+
+/// How the operands of an [`AccuracyAnalysis`] are sampled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sampling {
+    /// Uniformly over all 32-bit patterns.
+    Uniform,
+    /// Uniformly over the normalized floats, skipping zero and denormals.
+    Normalized,
+    /// Biased toward edge cases, see [`crate::sampling::edge_case_from_bits`].
+    EdgeCases,
+}
+
+/// A seeded analysis of the ULP error of a single operation.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::accuracy::{AccuracyAnalysis, Sampling};
+/// use ps2_floating_point::Operation;
+///
+/// let report = AccuracyAnalysis::new(Operation::Add)
+///     .sampling(Sampling::Normalized)
+///     .samples(1000)
+///     .run();
+///
+/// assert_eq!(report.samples, 1000);
+/// assert!(report.max_ulps() <= 2);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AccuracyAnalysis {
+    operation: Operation,
+    sampling: Sampling,
+    seed: u64,
+    samples: u64,
+}
+
+/// A sampled operation and its error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UlpErrorSample {
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The result of the crate.
+    pub actual: Ps2Float,
+    /// The exact result rounded to the nearest PS2 float, or the result of the
+    /// reference.
+    pub expected: Ps2Float,
+    /// The ULP distance between both results.
+    pub ulps: u32,
+}
+
+/// The ULP error distribution of an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UlpErrorReport {
+    /// The operation that was analysed.
+    pub operation: Operation,
+    /// The number of samples.
+    pub samples: u64,
+    /// The number of samples per ULP error.
+    pub histogram: BTreeMap<u32, u64>,
+    /// The first sample with the largest error, if any sample was taken.
+    pub worst: Option<UlpErrorSample>,
+}
+
+impl AccuracyAnalysis {
+    /// The default number of samples to take.
+    pub const DEFAULT_SAMPLES: u64 = 100_000;
+
+    /// Creates a new analysis of the operation.
+    ///
+    /// Takes [`AccuracyAnalysis::DEFAULT_SAMPLES`] samples of
+    /// [`Sampling::Normalized`] operands from seed 0 by default.
+    pub fn new(operation: Operation) -> Self {
+        Self { operation, sampling: Sampling::Normalized, seed: 0, samples: Self::DEFAULT_SAMPLES }
+    }
+
+    /// Sets how the operands are sampled.
+    pub fn sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Sets the seed the operands are sampled from.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the number of samples to take.
+    pub fn samples(mut self, samples: u64) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Measures the error versus the exact result rounded to the nearest PS2
+    /// float.
+    ///
+    /// Denormalized operands are zero on the PS2, so their exact value is zero.
+    pub fn run(&self) -> UlpErrorReport {
+        let operation = self.operation;
+        self.run_against(|a, b| {
+            let (a, b) = (operand_value(&a), operand_value(&b));
+            let exact = match operation {
+                Operation::Add => a + b,
+                Operation::Sub => a - b,
+                Operation::Mul => a * b,
+                Operation::Div => a / b,
+            };
+            Ps2Float::nearest(exact)
+        })
+    }
+
+    /// Measures the error versus another implementation of the operation, such
+    /// as a faster or more accurate backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - Computes the expected result of the operation.
+    pub fn run_against<F>(&self, mut reference: F) -> UlpErrorReport
+    where
+        F: FnMut(Ps2Float, Ps2Float) -> Ps2Float,
+    {
+        let mut generator = EdgeCaseGenerator::new(self.seed);
+        let mut report = UlpErrorReport {
+            operation: self.operation,
+            samples: self.samples,
+            histogram: BTreeMap::new(),
+            worst: None,
+        };
+
+        for _ in 0..self.samples {
+            let a = self.sample(&mut generator);
+            let b = self.sample(&mut generator);
+            let actual = self.operation.apply(a, b);
+            let expected = reference(a, b);
+            let ulps = actual.ulp_distance(expected);
+
+            *report.histogram.entry(ulps).or_insert(0) += 1;
+            if report.worst.is_none_or(|worst| ulps > worst.ulps) {
+                report.worst = Some(UlpErrorSample { a, b, actual, expected, ulps });
+            }
+        }
+
+        report
+    }
+
+    /// Samples a single operand.
+    fn sample(&self, generator: &mut EdgeCaseGenerator) -> Ps2Float {
+        match self.sampling {
+            Sampling::Uniform => Ps2Float::new(generator.next_bits() as u32),
+            Sampling::Normalized => {
+                let bits = generator.next_bits();
+                let exponent = (bits >> 32) % 254 + 1;
+                Ps2Float::new((bits as u32 & 0x807FFFFF) | (exponent as u32) << 23)
+            }
+            Sampling::EdgeCases => generator.next().unwrap(),
+        }
+    }
+}
+
+/// Returns the value of an operand as the PS2 sees it.
+fn operand_value(value: &Ps2Float) -> f64 {
+    if value.exponent() == 0 {
+        0.0
+    } else {
+        value.exact_value()
+    }
+}
+
+impl UlpErrorReport {
+    /// Returns the largest error in ULPs.
+    pub fn max_ulps(&self) -> u32 {
+        self.worst.map_or(0, |worst| worst.ulps)
+    }
+
+    /// Returns the mean error in ULPs.
+    pub fn mean_ulps(&self) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+
+        let total: f64 =
+            self.histogram.iter().map(|(ulps, count)| *ulps as f64 * *count as f64).sum();
+        total / self.samples as f64
+    }
+
+    /// Returns the fraction of samples without any error.
+    pub fn exact_fraction(&self) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+
+        *self.histogram.get(&0).unwrap_or(&0) as f64 / self.samples as f64
+    }
+
+    /// Formats the histogram as CSV with an `ulps,count,fraction` header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("ulps,count,fraction\n");
+        for (ulps, count) in &self.histogram {
+            let fraction = *count as f64 / self.samples as f64;
+            csv.push_str(&format!("{},{},{:.6}\n", ulps, count, fraction));
+        }
+        csv
+    }
+}
+
+impl Display for UlpErrorReport {
+    /// Formats a one line summary of the report.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} samples, {:.2}% exact, mean {:.4} ULPs, max {} ULPs",
+            self.operation,
+            self.samples,
+            self.exact_fraction() * 100.0,
+            self.mean_ulps(),
+            self.max_ulps()
+        )?;
+
+        if let Some(worst) = self.worst.filter(|worst| worst.ulps > 0) {
+            write!(
+                f,
+                " (0x{:08X} {} 0x{:08X} = 0x{:08X}, expected 0x{:08X})",
+                worst.a.as_u32(),
+                self.operation.symbol(),
+                worst.b.as_u32(),
+                worst.actual.as_u32(),
+                worst.expected.as_u32()
+            )?;
+        }
+
+        Ok(())
+    }
+}
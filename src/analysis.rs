@@ -0,0 +1,198 @@
+//! Searching and triaging PS2 floats in dumped data.
+//!
+//! Reverse engineering a game often starts from a memory dump or a data table
+//! and a constant known to be in it, like `1/60` or an approximation of
+//! `pi/2`. Compilers and tools rarely produce the exact same bits, so values
+//! are matched by their ULP distance rather than by equality.
+//!
+//! A [`Histogram`] of a dump tells at a glance whether it holds floats at all
+//! and whether any of them hit the PS2's edge cases: denormalized floats the
+//! hardware reads as zero, Fmax patterns left by saturation and negative
+//! zeros.
+
+use crate::{Classification, Ps2Float};
+
+/// A value of a haystack near the needle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the value in the haystack.
+    pub index: usize,
+    /// The value found.
+    pub value: Ps2Float,
+    /// The ULP distance between the value and the needle.
+    pub ulps: u32,
+}
+
+/// The counts of a slice of floats by exponent and by classification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// The number of values with each biased exponent, denormalized floats and
+    /// zeros in bucket 0.
+    pub exponents: [usize; 256],
+    /// The number of +/- 0.
+    pub zeros: usize,
+    /// The number of -0, also counted in `zeros`.
+    pub negative_zeros: usize,
+    /// The number of denormalized floats.
+    pub denormals: usize,
+    /// The number of ordinary numbers.
+    pub normalized: usize,
+    /// The number of +/- Fmax.
+    pub fmax: usize,
+    /// The number of IEEE 754 infinity patterns.
+    pub infinities: usize,
+    /// The number of other IEEE 754 NaN patterns.
+    pub nans: usize,
+}
+
+/// Returns the value of `haystack` closest to `needle` in ULPs, the first one
+/// on ties, or `None` if the haystack is empty.
+///
+/// See [`Ps2Float::ulp_distance`] for how the distance treats denormalized
+/// floats and zeros.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::analysis::find_closest;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let table = [0x3F800000, 0x3C888889, 0x3FC90FDB].map(Ps2Float::new);
+/// let sixtieth = Ps2Float::new(0x3C888888); // 1/60, truncated
+///
+/// let found = find_closest(&table, sixtieth).unwrap();
+/// assert_eq!((found.index, found.ulps), (1, 1));
+/// ```
+pub fn find_closest(haystack: &[Ps2Float], needle: Ps2Float) -> Option<Match> {
+    matches(haystack, needle).min_by_key(|found| found.ulps)
+}
+
+/// Returns the `k` values of `haystack` closest to `needle` in ULPs, closest
+/// first and in haystack order on ties.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::analysis::find_k_closest;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let table = [0x3FC90FDB, 0x3F800000, 0x3FC90FD0, 0x3FC90FDA].map(Ps2Float::new);
+/// let half_pi = Ps2Float::new(0x3FC90FDB);
+///
+/// let indices: Vec<usize> =
+///     find_k_closest(&table, half_pi, 2).iter().map(|found| found.index).collect();
+/// assert_eq!(indices, [0, 3]);
+/// ```
+pub fn find_k_closest(haystack: &[Ps2Float], needle: Ps2Float, k: usize) -> Vec<Match> {
+    let mut found: Vec<Match> = matches(haystack, needle).collect();
+    let key = |found: &Match| (found.ulps, found.index);
+
+    if k < found.len() {
+        found.select_nth_unstable_by_key(k, key);
+        found.truncate(k);
+    }
+    found.sort_unstable_by_key(key);
+    found
+}
+
+/// Returns every value of `haystack` within `max_ulps` of `needle`, in
+/// haystack order.
+pub fn find_within(haystack: &[Ps2Float], needle: Ps2Float, max_ulps: u32) -> Vec<Match> {
+    matches(haystack, needle).filter(|found| found.ulps <= max_ulps).collect()
+}
+
+fn matches(haystack: &[Ps2Float], needle: Ps2Float) -> impl Iterator<Item = Match> + '_ {
+    haystack.iter().enumerate().map(move |(index, &value)| Match {
+        index,
+        value,
+        ulps: value.ulp_distance(needle),
+    })
+}
+
+impl Histogram {
+    /// Counts the floats of a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::analysis::Histogram;
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let values = [0x3F800000, 0x3F000000, 0x80000000, 0x7FFFFFFF].map(Ps2Float::new);
+    /// let histogram = Histogram::of(&values);
+    ///
+    /// assert_eq!(histogram.exponents[127], 1);
+    /// assert_eq!((histogram.negative_zeros, histogram.fmax), (1, 1));
+    /// assert_eq!(histogram.suspicious(), 2);
+    /// ```
+    pub fn of(values: &[Ps2Float]) -> Self {
+        Self::count_all(values.iter().copied())
+    }
+
+    /// Counts the little-endian 32-bit words of a memory dump as floats,
+    /// ignoring a trailing partial word.
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        Self::count_all(
+            bytes
+                .chunks_exact(4)
+                .map(|word| Ps2Float::new(u32::from_le_bytes(word.try_into().unwrap()))),
+        )
+    }
+
+    /// Returns the number of values counted.
+    pub fn total(&self) -> usize {
+        self.exponents.iter().sum()
+    }
+
+    /// Returns the number of values of a classification.
+    pub fn count(&self, classification: Classification) -> usize {
+        match classification {
+            Classification::Zero => self.zeros,
+            Classification::Denormalized => self.denormals,
+            Classification::Normalized => self.normalized,
+            Classification::Fmax => self.fmax,
+            Classification::Infinity => self.infinities,
+            Classification::Nan => self.nans,
+        }
+    }
+
+    /// Returns the number of values worth a closer look: denormalized floats,
+    /// Fmax and negative zeros.
+    pub fn suspicious(&self) -> usize {
+        self.denormals + self.fmax + self.negative_zeros
+    }
+
+    fn count_all(values: impl Iterator<Item = Ps2Float>) -> Self {
+        let mut histogram = Self {
+            exponents: [0; 256],
+            zeros: 0,
+            negative_zeros: 0,
+            denormals: 0,
+            normalized: 0,
+            fmax: 0,
+            infinities: 0,
+            nans: 0,
+        };
+        for value in values {
+            histogram.push(value);
+        }
+        histogram
+    }
+
+    fn push(&mut self, value: Ps2Float) {
+        self.exponents[value.exponent() as usize] += 1;
+
+        let count = match value.classify() {
+            Classification::Zero => {
+                self.negative_zeros += value.sign() as usize;
+                &mut self.zeros
+            }
+            Classification::Denormalized => &mut self.denormals,
+            Classification::Normalized => &mut self.normalized,
+            Classification::Fmax => &mut self.fmax,
+            Classification::Infinity => &mut self.infinities,
+            Classification::Nan => &mut self.nans,
+        };
+        *count += 1;
+    }
+}
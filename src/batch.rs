@@ -0,0 +1,87 @@
+//! Operations over slices of PS2 floats with packed flags.
+//!
+//! The flags of every group of 4 elements are packed into a single
+//! [`MacFlags`], laid out like the VU MAC register with element `4k` as lane
+//! `x`, instead of a flag struct per element. Lanes of a trailing partial
+//! group have all their flags cleared.
+
+use crate::vu::MacFlags;
+use crate::{Ps2Float, Ps2Vec4};
+
+/// Adds two slices element by element.
+///
+/// # Arguments
+///
+/// * `a` - The addends on the left-hand side.
+/// * `b` - The addends on the right-hand side.
+///
+/// # Returns
+///
+/// The sums, and the MAC flags of every group of 4 sums.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{batch, Ps2Float};
+///
+/// let a = [0x3F800000, 0x7FFFFFFF, 0x3F800000, 0x3F800000, 0xBF800000].map(Ps2Float::new);
+/// let b = [0xBF800000, 0x7F7FFFFF, 0xC0000000, 0x3F800000, 0x00000000].map(Ps2Float::new);
+/// let (sums, flags) = batch::add(&a, &b);
+///
+/// assert_eq!(sums.len(), 5);
+/// assert_eq!(flags.len(), 2);
+/// assert_eq!(flags[0].bits(), 0x4028); // Zx Sz Oy
+/// assert_eq!(flags[1].bits(), 0x0080); // Sx
+/// ```
+pub fn add(a: &[Ps2Float], b: &[Ps2Float]) -> (Vec<Ps2Float>, Vec<MacFlags>) {
+    apply(a, b, Ps2Vec4::add_flagged)
+}
+
+/// Subtracts two slices element by element.
+///
+/// # Arguments
+///
+/// * `a` - The minuends.
+/// * `b` - The subtrahends.
+///
+/// # Returns
+///
+/// The differences, and the MAC flags of every group of 4 differences.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn sub(a: &[Ps2Float], b: &[Ps2Float]) -> (Vec<Ps2Float>, Vec<MacFlags>) {
+    apply(a, b, Ps2Vec4::sub_flagged)
+}
+
+/// Applies a flagged vector operation to every group of 4 elements.
+fn apply<F>(a: &[Ps2Float], b: &[Ps2Float], f: F) -> (Vec<Ps2Float>, Vec<MacFlags>)
+where
+    F: Fn(&Ps2Vec4, &Ps2Vec4) -> (Ps2Vec4, MacFlags),
+{
+    assert_eq!(a.len(), b.len(), "batch operands have different lengths");
+
+    let mut results = Vec::with_capacity(a.len());
+    let mut flags = Vec::with_capacity(a.len().div_ceil(4));
+    for (a, b) in a.chunks(4).zip(b.chunks(4)) {
+        let (result, group_flags) = f(&group(a), &group(b));
+        results.extend_from_slice(&result.lanes()[..a.len()]);
+
+        let dest = (0xF0u8 >> a.len()) & 0xF;
+        flags.push(group_flags.masked(dest));
+    }
+
+    (results, flags)
+}
+
+/// Returns up to 4 elements as a vector, padding the missing lanes with zero.
+fn group(elements: &[Ps2Float]) -> Ps2Vec4 {
+    let mut lanes = [Ps2Float::default(); 4];
+    lanes[..elements.len()].copy_from_slice(elements);
+    Ps2Vec4::from_lanes(lanes)
+}
@@ -0,0 +1,109 @@
+//! Comparing two binary dumps as arrays of PS2 floats.
+
+use ps2_floating_point::Ps2Float;
+
+/// The options of the `diff` subcommand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffOptions {
+    /// The paths of the two dumps.
+    pub paths: [String; 2],
+    /// The number of bytes between consecutive floats.
+    pub stride: usize,
+    /// The byte offset of the first float.
+    pub offset: usize,
+    /// Differences of at most this many ULPs aren't reported, by default every
+    /// differing bit pattern is.
+    pub max_ulps: Option<u32>,
+}
+
+impl DiffOptions {
+    /// Parses `<a.bin> <b.bin> [--stride N] [--offset N] [--max-ulps N]`.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut paths = Vec::new();
+        let mut options =
+            DiffOptions { paths: Default::default(), stride: 4, offset: 0, max_ulps: None };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| -> Result<u32, String> {
+                let value = args.next().ok_or_else(|| format!("missing value for {}", name))?;
+                crate::parse_u32(value)
+            };
+
+            match arg.as_str() {
+                "--stride" => options.stride = value("--stride")? as usize,
+                "--offset" => options.offset = value("--offset")? as usize,
+                "--max-ulps" => options.max_ulps = Some(value("--max-ulps")?),
+                flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+                path => paths.push(path.to_string()),
+            }
+        }
+
+        options.paths = paths.try_into().map_err(|_| "expected two dump files".to_string())?;
+        if options.stride < 4 {
+            return Err("the stride must be at least 4 bytes".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+/// Reads the floats at `offset + i * stride` as little endian words.
+pub fn read_floats(data: &[u8], offset: usize, stride: usize) -> Vec<Ps2Float> {
+    data.get(offset..)
+        .unwrap_or_default()
+        .chunks(stride)
+        .filter(|chunk| chunk.len() >= 4)
+        .map(|chunk| Ps2Float::new(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])))
+        .collect()
+}
+
+/// Compares the floats of two dumps and returns the report.
+pub fn diff(a: &[u8], b: &[u8], options: &DiffOptions) -> String {
+    let a = read_floats(a, options.offset, options.stride);
+    let b = read_floats(b, options.offset, options.stride);
+
+    let mut report = Vec::new();
+    let mut differences = 0;
+    let mut max_distance = 0;
+    for (i, (a, b)) in a.iter().zip(&b).enumerate() {
+        let distance = a.ulp_distance(*b);
+        if a == b || options.max_ulps.is_some_and(|max_ulps| distance <= max_ulps) {
+            continue;
+        }
+
+        differences += 1;
+        max_distance = max_distance.max(distance);
+        report.push(format!(
+            "[{}] @0x{:X}: 0x{:08X} ({}) vs 0x{:08X} ({}), {} ULPs",
+            i,
+            options.offset + i * options.stride,
+            a.as_u32(),
+            a.breakdown().decimal(),
+            b.as_u32(),
+            b.breakdown().decimal(),
+            distance
+        ));
+    }
+
+    if a.len() != b.len() {
+        report.push(format!("dumps differ in length: {} vs {} floats", a.len(), b.len()));
+    }
+    report.push(format!(
+        "{} of {} floats differ, max {} ULPs",
+        differences,
+        a.len().min(b.len()),
+        max_distance
+    ));
+
+    report.join("\n")
+}
+
+/// Runs the `diff` subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let options = DiffOptions::parse(args)?;
+    let read = |path: &str| std::fs::read(path).map_err(|e| format!("{}: {}", path, e));
+
+    println!("{}", diff(&read(&options.paths[0])?, &read(&options.paths[1])?, &options));
+    Ok(())
+}
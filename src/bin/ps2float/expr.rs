@@ -0,0 +1,320 @@
+//! Parsing and evaluating arithmetic expressions over PS2 floats.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use ps2_floating_point::{DivideFlags, Operation, ParsePs2FloatError, Ps2Float};
+
+/// The arithmetic used to evaluate expressions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// PS2 floating-point semantics.
+    Ps2,
+    /// Host IEEE 754 single precision semantics.
+    Ieee,
+}
+
+/// The status flags raised by a single operation.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct StepFlags {
+    /// The result is zero.
+    pub zero: bool,
+    /// The result is negative.
+    pub sign: bool,
+    /// The result overflowed and was clamped.
+    pub overflow: bool,
+    /// A non-zero result was too small and flushed to zero.
+    pub underflow: bool,
+    /// Zero was divided by zero, or the operation has no meaningful result.
+    pub invalid: bool,
+    /// A nonzero value was divided by zero.
+    pub divide: bool,
+}
+
+/// A single operation performed while evaluating an expression.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Step {
+    pub operation: Operation,
+    pub a: Ps2Float,
+    pub b: Ps2Float,
+    pub result: Ps2Float,
+    pub flags: StepFlags,
+}
+
+/// The result of evaluating an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    pub value: Ps2Float,
+    pub steps: Vec<Step>,
+}
+
+/// The evaluation mode and named variables of a session.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    pub mode: Mode,
+    pub variables: HashMap<String, Ps2Float>,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self { mode: Mode::Ps2, variables: HashMap::new() }
+    }
+}
+
+impl Mode {
+    /// Applies the operation with the arithmetic of the mode, returning the
+    /// result and the flags it raises.
+    fn apply(&self, operation: Operation, a: Ps2Float, b: Ps2Float) -> (Ps2Float, StepFlags) {
+        match self {
+            Mode::Ps2 => {
+                let (result, flags) = match operation {
+                    Operation::Add => a.add_with_flags(b),
+                    Operation::Sub => a.sub_with_flags(b),
+                    Operation::Mul => a.mul_with_flags(b),
+                    Operation::Div => a.div_with_flags(b),
+                };
+                let divide = match operation {
+                    Operation::Div => DivideFlags::of_div(a, b),
+                    _ => DivideFlags::default(),
+                };
+
+                let flags = StepFlags {
+                    zero: flags.zero,
+                    sign: flags.sign,
+                    overflow: flags.overflow,
+                    underflow: flags.underflow,
+                    invalid: divide.invalid,
+                    divide: divide.divide,
+                };
+                (result, flags)
+            }
+            Mode::Ieee => {
+                let (a, b) = (f32::from_bits(a.as_u32()), f32::from_bits(b.as_u32()));
+                let result = operation.apply_ieee(a, b);
+
+                // Sums, differences and products of two floats are exact in an
+                // `f64`, and a quotient is close enough to tell if it's tiny.
+                let exact = match operation {
+                    Operation::Add => a as f64 + b as f64,
+                    Operation::Sub => a as f64 - b as f64,
+                    Operation::Mul => a as f64 * b as f64,
+                    Operation::Div => a as f64 / b as f64,
+                };
+                let divide = operation == Operation::Div && b == 0.0 && a.is_finite() && a != 0.0;
+
+                let flags = StepFlags {
+                    zero: result == 0.0,
+                    sign: result.is_sign_negative(),
+                    overflow: result.is_infinite() && a.is_finite() && b.is_finite() && !divide,
+                    underflow: exact != 0.0
+                        && exact.abs() < f32::MIN_POSITIVE as f64
+                        && result as f64 != exact,
+                    invalid: result.is_nan() && !a.is_nan() && !b.is_nan(),
+                    divide,
+                };
+                (Ps2Float::new(result.to_bits()), flags)
+            }
+        }
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mode::Ps2 => write!(f, "ps2"),
+            Mode::Ieee => write!(f, "ieee"),
+        }
+    }
+}
+
+impl Display for StepFlags {
+    /// Formats the set flags by name, e.g. `Z S`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = [
+            (self.zero, "Z"),
+            (self.sign, "S"),
+            (self.overflow, "O"),
+            (self.underflow, "U"),
+            (self.invalid, "I"),
+            (self.divide, "D"),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect();
+
+        if names.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", names.join(" "))
+        }
+    }
+}
+
+/// A token of an expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Value(Ps2Float),
+    Variable(String),
+    Operator(Operation),
+    OpenParen,
+    CloseParen,
+}
+
+/// Parses a single float literal in any notation detected by
+/// [`Ps2Float::parse_any`], e.g. hex bits like `0x40A9999A`, a decimal like
+/// `5.3` or a hex float like `0x1.8p+1`.
+pub fn parse_literal(s: &str) -> Result<Ps2Float, String> {
+    s.parse().map_err(|e: ParsePs2FloatError| e.to_string())
+}
+
+/// Returns if the string is a valid variable name.
+pub fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits an expression into tokens.
+fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = expr.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            ' ' | '\t' => i += 1,
+            '(' => {
+                tokens.push(Token::OpenParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::CloseParen);
+                i += 1;
+            }
+            '+' | '-' | '*' | '/' => {
+                tokens.push(Token::Operator(c.to_string().parse().unwrap()));
+                i += 1;
+            }
+            _ if c.is_ascii_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Variable(chars[start..i].iter().collect()));
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    // Keep the sign of an exponent such as `1e-3` or `0x1.8p+1`.
+                    let hex = chars[start..i].iter().any(|c| *c == 'x' || *c == 'X');
+                    let exponent = if hex { ['p', 'P'] } else { ['e', 'E'] };
+                    if exponent.contains(&chars[i])
+                        && matches!(chars.get(i + 1), Some('+') | Some('-'))
+                    {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                tokens.push(Token::Value(parse_literal(&literal)?));
+            }
+            _ => return Err(format!("unexpected character '{}'", c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent evaluator using the usual operator precedence, where
+/// operators of the same precedence are applied left to right.
+struct Evaluator<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    environment: &'a Environment,
+    steps: Vec<Step>,
+}
+
+impl Evaluator<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    /// Applies an operation and records it as a step.
+    fn apply(&mut self, operation: Operation, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        let (result, flags) = self.environment.mode.apply(operation, a, b);
+        self.steps.push(Step { operation, a, b, result, flags });
+        result
+    }
+
+    /// expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Ps2Float, String> {
+        let mut result = self.term()?;
+        while let Some(&Token::Operator(op @ (Operation::Add | Operation::Sub))) = self.peek() {
+            self.position += 1;
+            let rhs = self.term()?;
+            result = self.apply(op, result, rhs);
+        }
+        Ok(result)
+    }
+
+    /// term := factor (('*' | '/') factor)*
+    fn term(&mut self) -> Result<Ps2Float, String> {
+        let mut result = self.factor()?;
+        while let Some(&Token::Operator(op @ (Operation::Mul | Operation::Div))) = self.peek() {
+            self.position += 1;
+            let rhs = self.factor()?;
+            result = self.apply(op, result, rhs);
+        }
+        Ok(result)
+    }
+
+    /// factor := '-' factor | '(' expression ')' | value | variable
+    fn factor(&mut self) -> Result<Ps2Float, String> {
+        let token = self.peek().cloned().ok_or("unexpected end of expression")?;
+        self.position += 1;
+
+        match token {
+            Token::Operator(Operation::Sub) => {
+                // Negation only flips the sign bit.
+                Ok(self.factor()?.neg())
+            }
+            Token::OpenParen => {
+                let value = self.expression()?;
+                match self.peek() {
+                    Some(Token::CloseParen) => {
+                        self.position += 1;
+                        Ok(value)
+                    }
+                    _ => Err("missing ')'".to_string()),
+                }
+            }
+            Token::Value(value) => Ok(value),
+            Token::Variable(name) => self
+                .environment
+                .variables
+                .get(&name)
+                .copied()
+                .ok_or_else(|| format!("unknown variable '{}'", name)),
+            _ => Err("expected a number".to_string()),
+        }
+    }
+}
+
+/// Evaluates an expression such as `0x40A9999A + 1.0` in the environment.
+pub fn evaluate_in(expr: &str, environment: &Environment) -> Result<Evaluation, String> {
+    let tokens = tokenize(expr)?;
+    let mut evaluator = Evaluator { tokens: &tokens, position: 0, environment, steps: Vec::new() };
+
+    let value = evaluator.expression()?;
+    if evaluator.position != tokens.len() {
+        return Err("unexpected trailing input".to_string());
+    }
+
+    Ok(Evaluation { value, steps: evaluator.steps })
+}
+
+/// Evaluates an expression such as `0x40A9999A + 1.0` with PS2 semantics.
+pub fn evaluate(expr: &str) -> Result<Ps2Float, String> {
+    evaluate_in(expr, &Environment::default()).map(|evaluation| evaluation.value)
+}
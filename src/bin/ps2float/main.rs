@@ -0,0 +1,89 @@
+//! `ps2float`, a converter and calculator for PS2 floats.
+//!
+//! ```text
+//! ps2float 0x40A9999A              # Show hex bits, decimal and fields
+//! ps2float 5.3                     # Convert a decimal
+//! ps2float "0x40A9999A + 1.0"      # Evaluate with PS2 semantics
+//! ps2float fields 0 0x81 0x29999A  # Build a float from its fields
+//! ps2float repl                    # Start an interactive session
+//! ps2float diff a.bin b.bin        # Compare two dumps float by float
+//! ps2float scan game.elf           # Find float constants in a binary
+//! ```
+
+mod diff;
+mod expr;
+mod repl;
+mod scan;
+
+use std::process::ExitCode;
+
+use ps2_floating_point::Ps2Float;
+
+const USAGE: &str = "\
+Usage:
+  ps2float <expression>                         Evaluate and describe an expression
+  ps2float fields <sign> <exponent> <mantissa>  Build a float from its fields
+  ps2float repl                                 Start an interactive session
+  ps2float diff <a.bin> <b.bin> [options]       Compare two dumps as float arrays
+    --stride <bytes>    Bytes between consecutive floats (default 4)
+    --offset <bytes>    Byte offset of the first float (default 0)
+    --max-ulps <ulps>   Hide differences of at most this many ULPs
+  ps2float scan <file> [options]                Find plausible float constants
+    --min-exp <exp>     Smallest power of two to report (default -20)
+    --max-exp <exp>     Biggest power of two to report (default 20)
+    --digits <digits>   Most significant decimal digits to report (default 6),
+                        use 8 to include computed constants such as 1/60
+
+Numbers are hex bits (0x40A9999A), decimals (5.3) or hex floats (0x1.8p+1).
+Expressions support + - * / and parentheses, and are evaluated with PS2
+semantics.";
+
+/// Parses an unsigned integer in decimal or `0x` prefixed hex.
+pub fn parse_u32(s: &str) -> Result<u32, String> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    parsed.map_err(|_| format!("invalid integer '{}'", s))
+}
+
+/// Builds a float from its `sign exponent mantissa` fields.
+fn from_fields(args: &[String]) -> Result<Ps2Float, String> {
+    let [sign, exponent, mantissa] = args else {
+        return Err("expected <sign> <exponent> <mantissa>".to_string());
+    };
+
+    let sign = parse_u32(sign)?;
+    let exponent = parse_u32(exponent)?;
+    let mantissa = parse_u32(mantissa)?;
+    if sign > 1 || exponent > 0xFF || mantissa > 0x7FFFFF {
+        return Err("field out of range".to_string());
+    }
+
+    Ok(Ps2Float::from_params(sign == 1, exponent as u8, mantissa))
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(String::as_str) {
+        None | Some("-h") | Some("--help") | Some("help") => println!("{}", USAGE),
+        Some("fields") => println!("{}", from_fields(&args[1..])?.breakdown()),
+        Some("repl") => repl::run()?,
+        Some("diff") => diff::run(&args[1..])?,
+        Some("scan") => scan::run(&args[1..])?,
+        Some(_) => println!("{}", expr::evaluate(&args.join(" "))?.breakdown()),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! An interactive read-eval-print loop for PS2 float expressions.
+
+use std::io::{BufRead, Write};
+
+use crate::expr::{self, Environment, Evaluation, Mode};
+
+const HELP: &str = "\
+Enter an expression to evaluate it, or assign it with `name = expression`.
+Every operation is printed with its flags: Z zero, S sign, O overflow,
+U underflow, I invalid, D divide by zero.
+
+Commands:
+  :mode ps2|ieee  Switch between PS2 and IEEE 754 evaluation
+  :vars           List the variables
+  :help           Show this help
+  :quit           Exit";
+
+/// Formats an evaluation, printing every operation with its flags.
+fn format_evaluation(evaluation: &Evaluation) -> String {
+    let mut output = String::new();
+    for step in &evaluation.steps {
+        output.push_str(&format!(
+            "  0x{:08X} {} 0x{:08X} = 0x{:08X}  [{}]\n",
+            step.a.as_u32(),
+            step.operation.symbol(),
+            step.b.as_u32(),
+            step.result.as_u32(),
+            step.flags
+        ));
+    }
+    output.push_str(&evaluation.value.breakdown().to_string());
+    output
+}
+
+/// Handles a single line of input and returns the output to print, or `None`
+/// to exit.
+pub fn handle_line(line: &str, environment: &mut Environment) -> Option<Result<String, String>> {
+    let line = line.trim();
+
+    let result = match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [] => Ok(String::new()),
+        [":quit"] | [":q"] | [":exit"] => return None,
+        [":help"] => Ok(HELP.to_string()),
+        [":mode"] => Ok(format!("mode: {}", environment.mode)),
+        [":mode", "ps2"] => {
+            environment.mode = Mode::Ps2;
+            Ok("mode: ps2".to_string())
+        }
+        [":mode", "ieee"] => {
+            environment.mode = Mode::Ieee;
+            Ok("mode: ieee".to_string())
+        }
+        [":vars"] => {
+            let mut names: Vec<_> = environment.variables.keys().collect();
+            names.sort();
+            Ok(names
+                .into_iter()
+                .map(|name| {
+                    let value = &environment.variables[name];
+                    format!("{} = 0x{:08X}  {}", name, value.as_u32(), value.breakdown().decimal())
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        [command, ..] if command.starts_with(':') => Err(format!("unknown command '{}'", command)),
+        _ => match line.split_once('=') {
+            Some((name, expression)) => {
+                let name = name.trim();
+                if !expr::is_identifier(name) {
+                    return Some(Err(format!("invalid variable name '{}'", name)));
+                }
+                expr::evaluate_in(expression, environment).map(|evaluation| {
+                    environment.variables.insert(name.to_string(), evaluation.value);
+                    format!("{} = {}", name, format_evaluation(&evaluation))
+                })
+            }
+            None => expr::evaluate_in(line, environment).map(|e| format_evaluation(&e)),
+        },
+    };
+
+    Some(result)
+}
+
+/// Runs the REPL on stdin until `:quit` or the end of input.
+pub fn run() -> Result<(), String> {
+    let mut environment = Environment::default();
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+
+    println!("ps2float REPL, type :help for help");
+    loop {
+        print!("{}> ", environment.mode);
+        stdout.flush().map_err(|e| e.to_string())?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            return Ok(());
+        }
+
+        match handle_line(&line, &mut environment) {
+            None => return Ok(()),
+            Some(Ok(output)) if output.is_empty() => {}
+            Some(Ok(output)) => println!("{}", output),
+            Some(Err(e)) => println!("error: {}", e),
+        }
+    }
+}
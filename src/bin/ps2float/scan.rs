@@ -0,0 +1,95 @@
+//! Scanning binaries for plausible PS2 float constants.
+
+use ps2_floating_point::Ps2Float;
+
+/// The options of the `scan` subcommand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// The path of the binary to scan.
+    pub path: String,
+    /// The unbiased exponent range of reported floats.
+    pub exponents: (i32, i32),
+    /// The maximum significant decimal digits of reported floats.
+    pub max_digits: usize,
+}
+
+impl ScanOptions {
+    /// Parses `<file> [--min-exp N] [--max-exp N] [--digits N]`.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut path = None;
+        let mut options = ScanOptions { path: String::new(), exponents: (-20, 20), max_digits: 6 };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| -> Result<i32, String> {
+                let value = args.next().ok_or_else(|| format!("missing value for {}", name))?;
+                value.parse().map_err(|_| format!("invalid value '{}' for {}", value, name))
+            };
+
+            match arg.as_str() {
+                "--min-exp" => options.exponents.0 = value("--min-exp")?,
+                "--max-exp" => options.exponents.1 = value("--max-exp")?,
+                "--digits" => options.max_digits = value("--digits")?.max(1) as usize,
+                flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+                arg if path.is_none() => path = Some(arg.to_string()),
+                _ => return Err("expected a single file".to_string()),
+            }
+        }
+
+        options.path = path.ok_or("expected a file to scan")?;
+        Ok(options)
+    }
+}
+
+/// Returns the number of significant digits in the shortest decimal that
+/// round-trips to the float.
+fn significant_digits(value: f32) -> usize {
+    let shortest = format!("{:e}", value.abs());
+    let mantissa = shortest.split('e').next().unwrap_or_default();
+    mantissa.chars().filter(char::is_ascii_digit).count()
+}
+
+/// Returns if the float looks like a constant written by a programmer.
+///
+/// Zero, denormalized and abnormal floats are rejected, as are floats outside
+/// the exponent range or needing too many digits to write down, which are most
+/// likely code, pointers or packed integers.
+pub fn is_plausible_constant(value: &Ps2Float, options: &ScanOptions) -> bool {
+    if value.exponent() == 0 || value.exponent() == 0xFF {
+        return false;
+    }
+
+    let exponent = value.exponent() as i32 - 127;
+    if exponent < options.exponents.0 || exponent > options.exponents.1 {
+        return false;
+    }
+
+    significant_digits(f32::from_bits(value.as_u32())) <= options.max_digits
+}
+
+/// Scans 4-byte aligned little endian words and returns a line per plausible
+/// constant.
+pub fn scan(data: &[u8], options: &ScanOptions) -> Vec<String> {
+    data.chunks_exact(4)
+        .enumerate()
+        .map(|(i, word)| (i * 4, Ps2Float::new(u32::from_le_bytes(word.try_into().unwrap()))))
+        .filter(|(_, value)| is_plausible_constant(value, options))
+        .map(|(offset, value)| {
+            format!("0x{:08X}: 0x{:08X}  {}", offset, value.as_u32(), value.breakdown().decimal())
+        })
+        .collect()
+}
+
+/// Runs the `scan` subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let options = ScanOptions::parse(args)?;
+    let data = std::fs::read(&options.path).map_err(|e| format!("{}: {}", options.path, e))?;
+
+    let constants = scan(&data, &options);
+    for line in &constants {
+        println!("{}", line);
+    }
+    println!("{} plausible constants in {} words", constants.len(), data.len() / 4);
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! The Booth multiplier of the EE FPU and the VU FMAC units.
+//!
+//! The hardware multiplies the 24-bit mantissas with radix-4 Booth recoding
+//! and a tree of carry-save adders, and drops the low bits of some partial
+//! products to save adders. Only bit 15 of the 48-bit product can come out
+//! differently from the exact product, but when it's set in the hardware sum
+//! and clear in the exact one, the borrow lowers the mantissa of the result by
+//! one ULP.
+//!
+//! The reduction below follows the adder tree cycle by cycle on the low 32
+//! bits, which is enough to know bit 15.
+
+/// A partial product of the Booth recoding, and the `+1` completing the two's
+/// complement of negative ones.
+struct BoothRecode {
+    data: u32,
+    negate: u32,
+}
+
+/// The sum and carry rows of a carry-save adder.
+struct AddResult {
+    lo: u32,
+    hi: u32,
+}
+
+/// Returns the partial product of `a` for the Booth digit `bit` of `b`.
+#[inline(always)]
+fn booth(a: u32, b: u32, bit: u32) -> BoothRecode {
+    let test = if bit == 0 { b << 1 } else { b >> (bit * 2 - 1) } & 7;
+    let mut a = a << (bit * 2);
+    if test == 3 || test == 4 {
+        a = a.wrapping_add(a);
+    }
+    let neg = if (4..=6).contains(&test) { u32::MAX } else { 0 };
+    let pos = 1u32 << (bit * 2);
+    a ^= neg & pos.wrapping_neg();
+    if !(1..=6).contains(&test) {
+        a = 0;
+    }
+
+    BoothRecode { data: a, negate: neg & pos }
+}
+
+/// Adds three rows of bits in parallel.
+#[inline(always)]
+fn add3(a: u32, b: u32, c: u32) -> AddResult {
+    let u = a ^ b;
+    AddResult { lo: u ^ c, hi: ((u & c) | (a & b)) << 1 }
+}
+
+/// Multiplies two 24-bit mantissas with their leading bits like the hardware,
+/// returning the 48-bit product.
+// Inlined into the guarded entry points so the `no-panic` feature can see
+// through it.
+#[inline(always)]
+pub(crate) fn mul_mantissa(a: u32, b: u32) -> u64 {
+    let full = a as u64 * b as u64;
+    let [b0, b1, b2, b3, b4, b5, b6, mut b7] = std::array::from_fn(|bit| booth(a, b, bit as u32));
+
+    // First cycle.
+    let t0 = add3(b1.data, b2.data, b3.data);
+    let mut t1 = add3(b4.data & !0x7FF, b5.data & !0xFFF, b6.data);
+    // A few adds get skipped, squeeze them back in.
+    t1.hi |= b6.negate | (b5.data & 0x800);
+    b7.data |= (b5.data & 0x400) + b5.negate;
+
+    // Second cycle.
+    let t2 = add3(b0.data, t0.lo, t0.hi);
+    let t3 = add3(b7.data, t1.lo, t1.hi);
+
+    // Third cycle.
+    let t4 = add3(t2.hi, t3.lo, t3.hi);
+
+    // Fourth cycle.
+    let mut t5 = add3(t2.lo, t4.lo, t4.hi);
+
+    // Discard the low bits and sum.
+    t5.hi = t5.hi.wrapping_add(b7.negate);
+    t5.lo &= !0x7FFF;
+    t5.hi &= !0x7FFF;
+    let ps2_lo = t5.lo.wrapping_add(t5.hi);
+
+    full.wrapping_sub((ps2_lo as u64 ^ full) & 0x8000)
+}
@@ -0,0 +1,183 @@
+//! Breaking PS2 floats down into their bit fields.
+
+use std::fmt::{Display, Formatter};
+
+use crate::Ps2Float;
+
+/// The kind of value a PS2 float encodes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Classification {
+    /// +/- 0.
+    Zero,
+    /// Exponent 0 with a non-zero mantissa, treated as zero by the PS2.
+    Denormalized,
+    /// An ordinary number.
+    Normalized,
+    /// +/- Fmax, the biggest magnitude, which is NaN in IEEE 754.
+    Fmax,
+    /// The IEEE 754 infinity pattern, an ordinary big number on the PS2.
+    Infinity,
+    /// Any other IEEE 754 NaN pattern, an ordinary big number on the PS2.
+    Nan,
+}
+
+/// One of the four encodings the arithmetic special-cases when both operands
+/// have one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Abnormal {
+    /// +Fmax, `0x7FFFFFFF`.
+    Max,
+    /// -Fmax, `0xFFFFFFFF`.
+    Min,
+    /// The positive IEEE 754 infinity pattern, `0x7F800000`.
+    PositiveInfinity,
+    /// The negative IEEE 754 infinity pattern, `0xFF800000`.
+    NegativeInfinity,
+}
+
+/// The special-case combination of the two operands of an operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PairClass {
+    /// Neither operand is denormalized and at most one is abnormal, so the
+    /// regular algorithm applies.
+    Regular,
+    /// At least one operand is denormalized and treated as zero.
+    Denormalized,
+    /// Both operands are abnormal, `a` first.
+    Abnormal(Abnormal, Abnormal),
+}
+
+/// Returns the special-case combination of two operands.
+///
+/// Operations dispatch on this once instead of checking each operand against
+/// every special encoding.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{classify_pair, Abnormal, PairClass, Ps2Float};
+///
+/// let max = Ps2Float::max();
+/// let infinity = Ps2Float::new(0x7F800000);
+///
+/// assert_eq!(
+///     classify_pair(max, infinity),
+///     PairClass::Abnormal(Abnormal::Max, Abnormal::PositiveInfinity)
+/// );
+/// assert_eq!(classify_pair(max, Ps2Float::new(1)), PairClass::Denormalized);
+/// ```
+pub fn classify_pair(a: Ps2Float, b: Ps2Float) -> PairClass {
+    const EXPONENT_MASK: u32 = 0x7F800000;
+
+    let (a, b) = (a.as_u32(), b.as_u32());
+    if a & EXPONENT_MASK == 0 || b & EXPONENT_MASK == 0 {
+        return PairClass::Denormalized;
+    }
+
+    match (abnormal(a), abnormal(b)) {
+        (Some(a), Some(b)) => PairClass::Abnormal(a, b),
+        _ => PairClass::Regular,
+    }
+}
+
+/// Returns the abnormal encoding of the bits of a float, if any.
+fn abnormal(bits: u32) -> Option<Abnormal> {
+    let negative = bits & 0x80000000 != 0;
+    match (bits & 0x7FFFFFFF, negative) {
+        (0x7FFFFFFF, false) => Some(Abnormal::Max),
+        (0x7FFFFFFF, true) => Some(Abnormal::Min),
+        (0x7F800000, false) => Some(Abnormal::PositiveInfinity),
+        (0x7F800000, true) => Some(Abnormal::NegativeInfinity),
+        _ => None,
+    }
+}
+
+/// The bit fields of a PS2 float along with its exact value.
+///
+/// Displays as the hex bits, decimal value and field breakdown:
+///
+/// ```text
+/// 0x40A9999A  5.300000190734863
+///   sign: 0 (+)  exponent: 0x81 (2^2)  mantissa: 0x29999A  [normalized]
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Breakdown {
+    value: Ps2Float,
+}
+
+impl Ps2Float {
+    /// Returns the kind of value `self` encodes.
+    pub fn classify(&self) -> Classification {
+        match (self.exponent(), self.mantissa()) {
+            (0, 0) => Classification::Zero,
+            (0, _) => Classification::Denormalized,
+            (0xFF, 0x7FFFFF) => Classification::Fmax,
+            (0xFF, 0) => Classification::Infinity,
+            (0xFF, _) => Classification::Nan,
+            _ => Classification::Normalized,
+        }
+    }
+
+    /// Returns the bit field breakdown of `self`.
+    pub fn breakdown(&self) -> Breakdown {
+        Breakdown { value: *self }
+    }
+}
+
+impl Classification {
+    /// Returns a short description of the classification.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Classification::Zero => "zero",
+            Classification::Denormalized => "denormalized, zero on the PS2",
+            Classification::Normalized => "normalized",
+            Classification::Fmax => "Fmax",
+            Classification::Infinity => "IEEE 754 infinity pattern",
+            Classification::Nan => "IEEE 754 NaN pattern",
+        }
+    }
+}
+
+impl Breakdown {
+    /// Returns the exact value of the float, treating exponent 255 as a regular
+    /// exponent like the PS2 does.
+    pub fn exact_value(&self) -> f64 {
+        self.value.exact_value()
+    }
+
+    /// Returns the unbiased exponent, the power of two of the float.
+    pub fn power_of_two(&self) -> i32 {
+        self.value.exponent() as i32 - 127
+    }
+
+    /// Formats the exact value, switching to scientific notation for very big
+    /// and very small magnitudes.
+    pub fn decimal(&self) -> String {
+        let exact = self.exact_value();
+        let magnitude = exact.abs();
+        if magnitude != 0.0 && !(1e-6..1e9).contains(&magnitude) {
+            format!("{:e}", exact)
+        } else {
+            format!("{}", exact)
+        }
+    }
+}
+
+impl Display for Breakdown {
+    /// Formats the hex bits, decimal value and field breakdown.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let value = &self.value;
+        write!(
+            f,
+            "0x{:08X}  {}\n  sign: {} ({})  exponent: 0x{:02X} (2^{})  mantissa: 0x{:06X}  [{}]",
+            value.as_u32(),
+            self.decimal(),
+            value.sign() as u8,
+            if value.sign() { '-' } else { '+' },
+            value.exponent(),
+            self.power_of_two(),
+            value.mantissa(),
+            value.classify().description()
+        )
+    }
+}
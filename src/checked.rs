@@ -0,0 +1,274 @@
+//! Arithmetic reporting the results the hardware silently clamps.
+//!
+//! The PS2 never traps: overflows saturate to +/- Fmax, underflows flush to
+//! zero and dividing by zero gives +/- Fmax. The checked operations return
+//! these cases as errors, each carrying the result the hardware gives, so
+//! tooling can find where game math clamped without losing the value.
+//!
+//! The operations returning [`OperationFlags`] report the same cases as the
+//! overflow and underflow flags, with the zero and sign flags of the result,
+//! for emulators updating a status register after every operation, and
+//! [`DivideFlags`] the invalid and divide flags of DIV, SQRT and RSQRT.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::explain::{Explanation, NormalizationStep};
+use crate::Ps2Float;
+
+/// The condition a checked operation ran into.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ps2FloatError {
+    /// The exponent exceeded 255 and the result saturated to +/- Fmax.
+    Overflow { result: Ps2Float },
+    /// The exponent fell below the normalized range and a nonzero result
+    /// flushed to +/- 0.
+    Underflow { result: Ps2Float },
+    /// A nonzero float was divided by zero, giving +/- Fmax.
+    DivideByZero { result: Ps2Float },
+    /// Zero was divided by zero, giving +/- Fmax.
+    Invalid { result: Ps2Float },
+}
+
+/// The flags an operation raises, like a lane of the VU MAC flags.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OperationFlags {
+    /// Z, the result is +/- 0.
+    pub zero: bool,
+    /// S, the result is negative, -0 included.
+    pub sign: bool,
+    /// U, the result underflowed and flushed to +/- 0.
+    pub underflow: bool,
+    /// O, the result overflowed and saturated to +/- Fmax.
+    pub overflow: bool,
+}
+
+/// The flags DIV, SQRT and RSQRT raise, which games poll through CFC1 and
+/// CFC2 since the results saturate instead of trapping.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DivideFlags {
+    /// I, zero was divided by zero or the square root of a negative float
+    /// taken.
+    pub invalid: bool,
+    /// D, a nonzero float was divided by zero.
+    pub divide: bool,
+}
+
+impl Ps2FloatError {
+    /// Returns the result the hardware gives for the operation.
+    pub fn result(&self) -> Ps2Float {
+        match *self {
+            Ps2FloatError::Overflow { result }
+            | Ps2FloatError::Underflow { result }
+            | Ps2FloatError::DivideByZero { result }
+            | Ps2FloatError::Invalid { result } => result,
+        }
+    }
+}
+
+impl Ps2Float {
+    /// Adds two PS2 floats together, failing if the sum overflowed or
+    /// underflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::{Ps2Float, Ps2FloatError};
+    ///
+    /// let big = Ps2Float::new(0x7FFFFFFE);
+    ///
+    /// assert_eq!(big.checked_add(Ps2Float::new(0x3F800000)), Ok(big));
+    /// assert_eq!(big.checked_add(big), Err(Ps2FloatError::Overflow { result: Ps2Float::max() }));
+    /// ```
+    pub fn checked_add(self, addend: Ps2Float) -> Result<Ps2Float, Ps2FloatError> {
+        Self::checked(self.add_explained(addend))
+    }
+
+    /// Subtracts two PS2 floats from each other, failing if the difference
+    /// overflowed or underflowed.
+    pub fn checked_sub(self, subtrahend: Ps2Float) -> Result<Ps2Float, Ps2FloatError> {
+        Self::checked(self.sub_explained(subtrahend))
+    }
+
+    /// Multiplies two PS2 floats, failing if the product overflowed or
+    /// underflowed.
+    ///
+    /// Denormalized operands are zero, so their product is exactly zero and
+    /// doesn't underflow.
+    pub fn checked_mul(self, factor: Ps2Float) -> Result<Ps2Float, Ps2FloatError> {
+        let result = self.mul(factor);
+        match self.product_exponent(&factor) {
+            Some(exponent) if exponent > 255 => Err(Ps2FloatError::Overflow { result }),
+            Some(exponent) if exponent < 1 => Err(Ps2FloatError::Underflow { result }),
+            _ => Ok(result),
+        }
+    }
+
+    /// Divides two PS2 floats, failing if dividing by zero or if the quotient
+    /// overflowed or underflowed.
+    ///
+    /// Denormalized operands are zero, so dividing by one is dividing by zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::{Ps2Float, Ps2FloatError};
+    ///
+    /// let (zero, one, max) = (Ps2Float::new(0), Ps2Float::new(0x3F800000), Ps2Float::max());
+    ///
+    /// assert_eq!(one.checked_div(zero), Err(Ps2FloatError::DivideByZero { result: max }));
+    /// assert_eq!(zero.checked_div(zero), Err(Ps2FloatError::Invalid { result: max }));
+    /// ```
+    pub fn checked_div(self, divisor: Ps2Float) -> Result<Ps2Float, Ps2FloatError> {
+        let result = self.div(divisor);
+        if divisor.is_denormalized() {
+            return Err(if self.is_denormalized() {
+                Ps2FloatError::Invalid { result }
+            } else {
+                Ps2FloatError::DivideByZero { result }
+            });
+        }
+
+        match self.quotient_exponent(&divisor) {
+            Some(exponent) if exponent > 255 => Err(Ps2FloatError::Overflow { result }),
+            Some(exponent) if exponent < 1 => Err(Ps2FloatError::Underflow { result }),
+            _ => Ok(result),
+        }
+    }
+
+    /// Adds two PS2 floats together, also returning the flags it raises.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let (a, b) = (Ps2Float::new(0x00800001), Ps2Float::new(0x80800000));
+    /// let (sum, flags) = a.add_with_flags(b);
+    ///
+    /// assert_eq!(sum.as_u32(), 0x00000000);
+    /// assert!(flags.underflow && flags.zero && !flags.sign);
+    /// ```
+    pub fn add_with_flags(self, addend: Ps2Float) -> (Ps2Float, OperationFlags) {
+        OperationFlags::of(self.checked_add(addend))
+    }
+
+    /// Subtracts two PS2 floats from each other, also returning the flags it
+    /// raises.
+    pub fn sub_with_flags(self, subtrahend: Ps2Float) -> (Ps2Float, OperationFlags) {
+        OperationFlags::of(self.checked_sub(subtrahend))
+    }
+
+    /// Multiplies two PS2 floats, also returning the flags it raises.
+    pub fn mul_with_flags(self, factor: Ps2Float) -> (Ps2Float, OperationFlags) {
+        OperationFlags::of(self.checked_mul(factor))
+    }
+
+    /// Divides two PS2 floats, also returning the flags it raises.
+    ///
+    /// Dividing by zero saturates to +/- Fmax without raising the overflow
+    /// flag, the hardware raises the divide or invalid flag instead.
+    pub fn div_with_flags(self, divisor: Ps2Float) -> (Ps2Float, OperationFlags) {
+        OperationFlags::of(self.checked_div(divisor))
+    }
+
+    /// Returns the result of a traced operation, or the error it ran into.
+    fn checked(explanation: Explanation) -> Result<Ps2Float, Ps2FloatError> {
+        let result = explanation.result;
+        if explanation.overflowed() {
+            Err(Ps2FloatError::Overflow { result })
+        } else if explanation.normalization.contains(&NormalizationStep::Underflow) {
+            Err(Ps2FloatError::Underflow { result })
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+impl OperationFlags {
+    /// Returns the result of a checked operation and the flags it raises.
+    fn of(checked: Result<Ps2Float, Ps2FloatError>) -> (Ps2Float, Self) {
+        let result = checked.unwrap_or_else(|error| error.result());
+        let flags = OperationFlags {
+            zero: result.exponent() == 0,
+            sign: result.sign(),
+            underflow: matches!(checked, Err(Ps2FloatError::Underflow { .. })),
+            overflow: matches!(checked, Err(Ps2FloatError::Overflow { .. })),
+        };
+
+        (result, flags)
+    }
+
+    /// Returns the flags as the zero, sign, underflow and overflow bits of the
+    /// VU status register, without the sticky bits.
+    pub fn status_bits(&self) -> u16 {
+        [self.zero, self.sign, self.underflow, self.overflow]
+            .into_iter()
+            .enumerate()
+            .fold(0, |bits, (i, set)| bits | ((set as u16) << i))
+    }
+}
+
+impl DivideFlags {
+    /// Returns the flags dividing two floats raises, like DIV.
+    ///
+    /// Denormalized operands are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::{DivideFlags, Ps2Float};
+    ///
+    /// let (zero, one) = (Ps2Float::new(0), Ps2Float::new(0x3F800000));
+    ///
+    /// assert!(DivideFlags::of_div(one, zero).divide);
+    /// assert!(DivideFlags::of_div(zero, zero).invalid);
+    /// assert_eq!(DivideFlags::of_div(zero, one), DivideFlags::default());
+    /// ```
+    pub fn of_div(dividend: Ps2Float, divisor: Ps2Float) -> Self {
+        let divisor_zero = divisor.is_denormalized();
+        let dividend_zero = dividend.is_denormalized();
+        DivideFlags {
+            invalid: divisor_zero && dividend_zero,
+            divide: divisor_zero && !dividend_zero,
+        }
+    }
+
+    /// Returns the flags taking the square root of a float raises, like SQRT.
+    ///
+    /// Only negative floats raise the invalid flag, -0 and negative
+    /// denormalized floats being zero.
+    pub fn of_sqrt(value: Ps2Float) -> Self {
+        DivideFlags { invalid: value.sign() && !value.is_denormalized(), divide: false }
+    }
+
+    /// Returns the flags dividing a float by the square root of another
+    /// raises, like RSQRT.
+    ///
+    /// A negative divisor raises the invalid flag and a zero one the divide
+    /// flag, whatever the dividend.
+    pub fn of_rsqrt(divisor: Ps2Float) -> Self {
+        DivideFlags { divide: divisor.is_denormalized(), ..Self::of_sqrt(divisor) }
+    }
+
+    /// Returns the flags as the invalid and divide bits of the VU status
+    /// register, without the sticky bits.
+    pub fn status_bits(&self) -> u16 {
+        ((self.invalid as u16) << 4) | ((self.divide as u16) << 5)
+    }
+}
+
+impl Display for Ps2FloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let condition = match self {
+            Ps2FloatError::Overflow { .. } => "overflow",
+            Ps2FloatError::Underflow { .. } => "underflow",
+            Ps2FloatError::DivideByZero { .. } => "division by zero",
+            Ps2FloatError::Invalid { .. } => "invalid operation",
+        };
+        let result = self.result();
+        write!(f, "{} resulting in 0x{:08X} ({})", condition, result.as_u32(), result)
+    }
+}
+
+impl Error for Ps2FloatError {}
@@ -0,0 +1,171 @@
+//! View frustum classification with the VU CLIP instruction.
+//!
+//! CLIPw.xyz compares `x`, `y` and `z` against `+|w|` and `-|w|` with PS2
+//! float ordering, where Fmax patterns are ordinary large numbers, rather than
+//! IEEE 754 comparisons. Culling code then tests the judgments of a
+//! primitive's vertices from the clip flag register.
+
+use std::fmt::{Display, Formatter};
+
+use crate::vu::flags::{diff_bits, BitChange};
+use crate::{Ps2Float, Ps2Vec4};
+
+/// The six bits CLIP sets for a single vertex.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ClipJudgment(u8);
+
+/// The 24-bit clip flag register holding the last four judgments, the latest
+/// in the low 6 bits.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ClipFlags(u32);
+
+/// Where a primitive lies relative to the view frustum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Containment {
+    /// Every vertex is inside the frustum.
+    Inside,
+    /// Every vertex is beyond the same plane, so the primitive is culled.
+    Outside,
+    /// The primitive crosses a plane and must be clipped.
+    Straddling,
+}
+
+impl ClipJudgment {
+    /// `x > +|w|`
+    pub const POS_X: u8 = 1 << 0;
+    /// `x < -|w|`
+    pub const NEG_X: u8 = 1 << 1;
+    /// `y > +|w|`
+    pub const POS_Y: u8 = 1 << 2;
+    /// `y < -|w|`
+    pub const NEG_Y: u8 = 1 << 3;
+    /// `z > +|w|`
+    pub const POS_Z: u8 = 1 << 4;
+    /// `z < -|w|`
+    pub const NEG_Z: u8 = 1 << 5;
+
+    /// Judges a vertex against its own `w`, like `CLIPw.xyz v, v`.
+    pub fn of(vertex: &Ps2Vec4) -> Self {
+        Self::against(vertex, &vertex.w)
+    }
+
+    /// Judges a vertex against another `w`, like `CLIPw.xyz v, t`.
+    pub fn against(vertex: &Ps2Vec4, w: &Ps2Float) -> Self {
+        let pos_w = Ps2Float::new(w.as_u32() & 0x7FFFFFFF);
+        let neg_w = Ps2Float::new(w.as_u32() | 0x80000000);
+
+        let mut bits = 0;
+        for (i, lane) in [vertex.x, vertex.y, vertex.z].iter().enumerate() {
+            if *lane > pos_w {
+                bits |= Self::POS_X << (i * 2);
+            }
+            if *lane < neg_w {
+                bits |= Self::NEG_X << (i * 2);
+            }
+        }
+
+        Self(bits)
+    }
+
+    /// Creates a judgment from its 6 bits.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & 0x3F)
+    }
+
+    /// Returns the 6 bits of the judgment.
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns if the vertex is inside the frustum.
+    pub fn is_inside(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl ClipFlags {
+    /// Creates a clip flag register from its 24 bits.
+    pub fn from_bits(bits: u32) -> Self {
+        Self(bits & 0xFFFFFF)
+    }
+
+    /// Returns the 24 bits of the register.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Shifts a judgment into the register like CLIP does, dropping the oldest.
+    pub fn push(&mut self, judgment: ClipJudgment) {
+        self.0 = ((self.0 << 6) | judgment.0 as u32) & 0xFFFFFF;
+    }
+
+    /// Returns a judgment, 0 being the latest and 3 the oldest.
+    pub fn judgment(&self, age: usize) -> ClipJudgment {
+        assert!(age < 4, "The clip flag register holds 4 judgments");
+        ClipJudgment(((self.0 >> (age * 6)) & 0x3F) as u8)
+    }
+
+    /// Returns the bits that differ in `other`, from the lowest.
+    pub fn diff(&self, other: &ClipFlags) -> Vec<BitChange> {
+        diff_bits(self.0, other.0)
+    }
+
+    /// Classifies the primitive formed by the latest `count` judgments.
+    pub fn containment(&self, count: usize) -> Containment {
+        let judgments = (0..count).map(|age| self.judgment(age));
+        let (all, any) = judgments.fold((0x3F, 0), |(all, any), j| (all & j.0, any | j.0));
+
+        if all != 0 {
+            Containment::Outside
+        } else if any == 0 {
+            Containment::Inside
+        } else {
+            Containment::Straddling
+        }
+    }
+}
+
+/// Classifies a point, line or triangle against the view frustum by running
+/// CLIP on every vertex.
+///
+/// # Arguments
+///
+/// * `vertices` - The vertices in clip space, at most 4.
+///
+/// # Returns
+///
+/// [`Containment::Outside`] if all vertices are beyond the same plane,
+/// [`Containment::Inside`] if none are beyond any plane, otherwise
+/// [`Containment::Straddling`].
+pub fn classify(vertices: &[Ps2Vec4]) -> Containment {
+    assert!(vertices.len() <= 4, "The clip flag register holds 4 judgments");
+
+    let mut flags = ClipFlags::default();
+    for vertex in vertices {
+        flags.push(ClipJudgment::of(vertex));
+    }
+
+    flags.containment(vertices.len())
+}
+
+impl Display for ClipJudgment {
+    /// Formats the set bits by name, e.g. `+x -z`, or `-` if inside.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = ["+x", "-x", "+y", "-y", "+z", "-z"];
+        let set: Vec<&str> = (0..6).filter(|i| self.0 & (1 << i) != 0).map(|i| names[i]).collect();
+
+        if set.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", set.join(" "))
+        }
+    }
+}
+
+impl Display for ClipFlags {
+    /// Formats the judgments from the latest, e.g. `+x -z | - | - | -`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let judgments: Vec<String> = (0..4).map(|age| self.judgment(age).to_string()).collect();
+        write!(f, "{}", judgments.join(" | "))
+    }
+}
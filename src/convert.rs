@@ -0,0 +1,554 @@
+//! Converting between host values and PS2 floats.
+//!
+//! PC-authored assets can hold denormalized floats, infinities and NaNs that
+//! the PS2 doesn't have, so importing them into a PS2-accurate pipeline starts
+//! with mapping those values and counting how many were changed.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::vector::fixed_to_float;
+use crate::Ps2Float;
+
+/// How infinities and NaNs are mapped to PS2 floats, and how PS2 floats with
+/// exponent 255 are mapped to host floats.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NonFinitePolicy {
+    /// Clamp to +/- Fmax keeping the sign, like emulators clamping FPU input,
+    /// or to +/- `f32::MAX` on the host.
+    #[default]
+    Clamp,
+    /// Keep the bits, which the PS2 reads as big regular numbers and the host
+    /// as infinities and NaNs.
+    Preserve,
+    /// Replace with zero keeping the sign.
+    Zero,
+}
+
+/// The number of values altered while converting a slice.
+///
+/// Values are only counted when their bits changed, so infinities and NaNs
+/// aren't counted with [`NonFinitePolicy::Preserve`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConversionReport {
+    /// The number of values converted.
+    pub total: usize,
+    /// The number of denormalized values flushed to zero.
+    pub denormals_flushed: usize,
+    /// The number of infinities mapped by the policy.
+    pub infinities_mapped: usize,
+    /// The number of NaNs mapped by the policy.
+    pub nans_mapped: usize,
+}
+
+impl ConversionReport {
+    /// Returns the number of values whose bits changed.
+    pub fn altered(&self) -> usize {
+        self.denormals_flushed + self.infinities_mapped + self.nans_mapped
+    }
+}
+
+/// The error converting a PS2 float to an integer it doesn't exactly equal, or
+/// to a host float out of its range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TryFromPs2FloatError {
+    /// The float has a fractional part.
+    NotAnInteger { value: Ps2Float },
+    /// The float is an integer outside the range of the target type, or has
+    /// exponent 255, which host floats use for infinities and NaNs.
+    OutOfRange { value: Ps2Float },
+}
+
+/// The error converting a host NaN to a PS2 float, which has no NaNs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromF32Error {
+    /// The bits of the NaN.
+    pub bits: u32,
+}
+
+/// Converts host floats to PS2 floats, flushing denormals to zero and clamping
+/// infinities and NaNs to +/- Fmax.
+///
+/// # Arguments
+///
+/// * `values` - The host floats to convert.
+///
+/// # Returns
+///
+/// The PS2 floats, and a report of how many values were altered.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::convert::convert_f32_slice;
+///
+/// let (floats, report) = convert_f32_slice(&[1.0, 1e-40, f32::NEG_INFINITY, f32::NAN]);
+///
+/// let bits: Vec<u32> = floats.iter().map(|float| float.as_u32()).collect();
+/// assert_eq!(bits, [0x3F800000, 0x00000000, 0xFFFFFFFF, 0x7FFFFFFF]);
+/// assert_eq!(report.altered(), 3);
+/// ```
+pub fn convert_f32_slice(values: &[f32]) -> (Vec<Ps2Float>, ConversionReport) {
+    convert_f32_slice_with(values, NonFinitePolicy::default())
+}
+
+/// Converts host floats to PS2 floats, flushing denormals to zero and mapping
+/// infinities and NaNs by the given policy.
+///
+/// # Arguments
+///
+/// * `values` - The host floats to convert.
+/// * `policy` - How to map infinities and NaNs.
+///
+/// # Returns
+///
+/// The PS2 floats, and a report of how many values were altered.
+pub fn convert_f32_slice_with(
+    values: &[f32],
+    policy: NonFinitePolicy,
+) -> (Vec<Ps2Float>, ConversionReport) {
+    let mut report = ConversionReport { total: values.len(), ..Default::default() };
+    let floats = values
+        .iter()
+        .map(|value| {
+            let bits = value.to_bits();
+            let converted = convert_bits(bits, policy);
+            if converted != bits {
+                if value.is_nan() {
+                    report.nans_mapped += 1;
+                } else if value.is_infinite() {
+                    report.infinities_mapped += 1;
+                } else {
+                    report.denormals_flushed += 1;
+                }
+            }
+            Ps2Float::new(converted)
+        })
+        .collect();
+
+    (floats, report)
+}
+
+/// Converts a PS1 GTE 1.3.12 fixed-point value, such as a rotation matrix
+/// element, to a PS2 float.
+///
+/// Every 1.3.12 value has at most 16 significant bits, so the conversion is
+/// exact like ITOF12.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::convert::from_fixed_1_3_12;
+///
+/// assert_eq!(from_fixed_1_3_12(0x1000).as_u32(), 0x3F800000); // 1.0
+/// assert_eq!(from_fixed_1_3_12(-0x0800).as_u32(), 0xBF000000); // -0.5
+/// ```
+pub fn from_fixed_1_3_12(value: i16) -> Ps2Float {
+    Ps2Float::from_ratio(value as i32, 1 << 12)
+}
+
+/// Converts a PS2 float to a PS1 GTE 1.3.12 fixed-point value.
+///
+/// The value is truncated toward zero like FTOI12 and saturates to the range
+/// of the format, `[-8, 8)`, like the GTE saturates its results.
+pub fn to_fixed_1_3_12(value: Ps2Float) -> i16 {
+    to_fixed(value, 12, i16::MIN as i64, i16::MAX as i64) as i16
+}
+
+/// Converts a PS1 GTE 1.15.16 fixed-point value, such as a screen offset, to
+/// a PS2 float.
+///
+/// Values with more than 24 significant bits are truncated toward zero, like
+/// converting the integer and dividing by 2^16 on the PS2.
+pub fn from_fixed_1_15_16(value: i32) -> Ps2Float {
+    Ps2Float::from_ratio(value, 1 << 16)
+}
+
+/// Converts a PS2 float to a PS1 GTE 1.15.16 fixed-point value.
+///
+/// The value is truncated toward zero and saturates to the range of the
+/// format, `[-32768, 32768)`.
+pub fn to_fixed_1_15_16(value: Ps2Float) -> i32 {
+    to_fixed(value, 16, i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Scales a PS2 float by 2^`fraction_bits`, truncates it toward zero and
+/// saturates it to `[min, max]`.
+fn to_fixed(value: Ps2Float, fraction_bits: i32, min: i64, max: i64) -> i64 {
+    if value.is_denormalized() {
+        return 0;
+    }
+
+    // The scaled value is exact in an f64, and anything out of range saturates.
+    let scaled = (value.exact_value() * 2f64.powi(fraction_bits)).trunc();
+    scaled.clamp(min as f64, max as f64) as i64
+}
+
+/// Returns the bits of the PS2 float a host float converts to.
+fn convert_bits(bits: u32, policy: NonFinitePolicy) -> u32 {
+    let sign = bits & 0x80000000;
+    match bits & 0x7F800000 {
+        0 => sign,
+        0x7F800000 => match policy {
+            NonFinitePolicy::Clamp => sign | Ps2Float::MAX_FLOATING_POINT_VALUE,
+            NonFinitePolicy::Preserve => bits,
+            NonFinitePolicy::Zero => sign,
+        },
+        _ => bits,
+    }
+}
+
+impl Ps2Float {
+    /// Returns if `self` holds an exact integer.
+    ///
+    /// Denormalized floats are zero on the PS2 and count as integers, and every
+    /// float from 2^23 up, including the INF and NaN patterns, is one.
+    pub fn is_integer(&self) -> bool {
+        match self.exponent() {
+            0 => true,
+            // Below 1, so only zero would be an integer.
+            1..=126 => false,
+            // The low bits of the mantissa below the binary point must be clear.
+            exponent @ 127..=149 => self.mantissa() & ((1 << (150 - exponent as u32)) - 1) == 0,
+            _ => true,
+        }
+    }
+
+    /// Returns the integer `self` holds, if it's exactly an integer that fits
+    /// in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0x46EA6000).to_exact_int(), Some(30000));
+    /// assert_eq!(Ps2Float::new(0x3FC00000).to_exact_int(), None); // 1.5
+    /// ```
+    pub fn to_exact_int(&self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        }
+        if self.is_denormalized() {
+            return Some(0);
+        }
+
+        // Every integer float has an exponent of at least 127, and 2^63 needs 40
+        // more than the 23 bits of the mantissa.
+        let shift = self.exponent() as i32 - 150;
+        if shift > 40 {
+            return None;
+        }
+
+        let significand = (self.mantissa() | 0x800000) as i128;
+        let magnitude = if shift >= 0 { significand << shift } else { significand >> -shift };
+        i64::try_from(if self.sign() { -magnitude } else { magnitude }).ok()
+    }
+
+    /// Converts the float to an integer like CVT.W.S, truncating toward zero.
+    ///
+    /// Host `as` casts go through the IEEE 754 value, whereas the EE reads the
+    /// INF and NaN patterns as big numbers: every float out of the range of an
+    /// `i32` saturates to 0x7FFFFFFF when positive and 0x80000000 when
+    /// negative. Denormalized floats are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0xC0F00000).to_i32_cvtw(), -7); // -7.5
+    /// assert_eq!(Ps2Float::new(0x7FC00000).to_i32_cvtw(), i32::MAX); // NaN pattern
+    /// assert_eq!(Ps2Float::min().to_i32_cvtw(), i32::MIN);
+    /// ```
+    pub fn to_i32_cvtw(&self) -> i32 {
+        to_fixed(*self, 0, i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Converts an integer to a float like CVT.S.W.
+    ///
+    /// Integers with more than 24 significant bits keep the highest 24, the
+    /// low bits being truncated toward zero instead of rounded to nearest like
+    /// host `as` casts do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::from_i32_cvtsw(-42).as_u32(), 0xC2280000);
+    /// // 2^24 + 3 rounds up to 2^24 + 4 on a host but truncates on the EE.
+    /// assert_eq!(Ps2Float::from_i32_cvtsw(0x1000003).as_u32(), 0x4B800001);
+    /// assert_eq!((0x1000003 as f32).to_bits(), 0x4B800002);
+    /// ```
+    pub fn from_i32_cvtsw(value: i32) -> Ps2Float {
+        fixed_to_float(value, 0)
+    }
+
+    /// Converts a host float to a PS2 float, flushing denormals to zero and
+    /// mapping infinities and NaNs by the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::convert::NonFinitePolicy;
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let inf = Ps2Float::from_f32_with_policy(f32::NEG_INFINITY, NonFinitePolicy::Preserve);
+    /// assert_eq!(inf.as_u32(), 0xFF800000);
+    ///
+    /// let denormal = Ps2Float::from_f32_with_policy(-1e-40, NonFinitePolicy::Clamp);
+    /// assert_eq!(denormal.as_u32(), 0x80000000);
+    /// ```
+    pub fn from_f32_with_policy(value: f32, policy: NonFinitePolicy) -> Ps2Float {
+        Ps2Float::new(convert_bits(value.to_bits(), policy))
+    }
+
+    /// Converts a host float to a PS2 float like
+    /// [`Ps2Float::from_f32_with_policy`], failing on NaNs instead of mapping
+    /// them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::convert::{NonFinitePolicy, TryFromF32Error};
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let inf = Ps2Float::try_from_f32(f32::INFINITY, NonFinitePolicy::Clamp);
+    /// assert_eq!(inf, Ok(Ps2Float::max()));
+    /// assert_eq!(
+    ///     Ps2Float::try_from_f32(f32::NAN, NonFinitePolicy::Clamp),
+    ///     Err(TryFromF32Error { bits: 0x7FC00000 })
+    /// );
+    /// ```
+    pub fn try_from_f32(value: f32, policy: NonFinitePolicy) -> Result<Ps2Float, TryFromF32Error> {
+        if value.is_nan() {
+            return Err(TryFromF32Error { bits: value.to_bits() });
+        }
+
+        Ok(Self::from_f32_with_policy(value, policy))
+    }
+
+    /// Converts the float to a host float, flushing denormals to zero and
+    /// clamping exponent 255 to +/- `f32::MAX`.
+    ///
+    /// Every other float has the same value on the host.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0xC0200000).to_f32(), -2.5);
+    /// assert_eq!(Ps2Float::max().to_f32(), f32::MAX);
+    /// ```
+    pub fn to_f32(&self) -> f32 {
+        self.to_f32_with_policy(NonFinitePolicy::default())
+    }
+
+    /// Converts the float to a host float, flushing denormals to zero and
+    /// mapping exponent 255 by the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::convert::NonFinitePolicy;
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let nan = Ps2Float::new(0x7FC00000);
+    /// assert!(nan.to_f32_with_policy(NonFinitePolicy::Preserve).is_nan());
+    /// assert_eq!(nan.to_f32_with_policy(NonFinitePolicy::Zero), 0.0);
+    /// ```
+    pub fn to_f32_with_policy(&self, policy: NonFinitePolicy) -> f32 {
+        let sign = self.as_u32() & 0x80000000;
+        let bits = match self.exponent() {
+            0 => sign,
+            255 => match policy {
+                NonFinitePolicy::Clamp => sign | f32::MAX.to_bits(),
+                NonFinitePolicy::Preserve => self.as_u32(),
+                NonFinitePolicy::Zero => sign,
+            },
+            _ => self.as_u32(),
+        };
+        f32::from_bits(bits)
+    }
+
+    /// Converts the float to a host float like [`Ps2Float::to_f32`], failing
+    /// on exponent 255 instead of clamping it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::convert::TryFromPs2FloatError;
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0x3F800000).try_to_f32(), Ok(1.0));
+    /// assert_eq!(
+    ///     Ps2Float::max().try_to_f32(),
+    ///     Err(TryFromPs2FloatError::OutOfRange { value: Ps2Float::max() })
+    /// );
+    /// ```
+    pub fn try_to_f32(&self) -> Result<f32, TryFromPs2FloatError> {
+        if self.exponent() == 255 {
+            return Err(TryFromPs2FloatError::OutOfRange { value: *self });
+        }
+
+        Ok(self.to_f32())
+    }
+
+    /// Converts a host double to a PS2 float, truncating the significand to 24
+    /// bits toward zero.
+    ///
+    /// Values below the normalized range flush to zero and values past Fmax
+    /// saturate to Fmax, keeping their sign. Infinities and NaNs saturate to
+    /// +/- Fmax like [`NonFinitePolicy::Clamp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// // 1/3 rounds up to 0x3EAAAAAB as an f32 but truncates here.
+    /// assert_eq!(Ps2Float::from_f64(1.0 / 3.0).as_u32(), 0x3EAAAAAA);
+    /// // Exponent 255 is in range, so only values from 2^129 saturate.
+    /// assert_eq!(Ps2Float::from_f64(2f64.powi(128)).as_u32(), 0x7F800000);
+    /// assert_eq!(Ps2Float::from_f64(-1e300), Ps2Float::min());
+    /// ```
+    pub fn from_f64(value: f64) -> Ps2Float {
+        let sign = value.is_sign_negative();
+        if !value.is_finite() {
+            return if sign { Self::min() } else { Self::max() };
+        }
+
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7FF) as i32 - 1023 + 127;
+        // Dropping the low 29 bits of the 52-bit fraction truncates toward zero.
+        let mantissa = ((bits >> 29) & 0x7FFFFF) as u32;
+        if exponent < 1 {
+            Self::from_params(sign, 0, 0)
+        } else if exponent > 255 {
+            if sign {
+                Self::min()
+            } else {
+                Self::max()
+            }
+        } else {
+            Self::from_params(sign, exponent as u8, mantissa)
+        }
+    }
+
+    /// Converts the float to a host double.
+    ///
+    /// Every PS2 float is exact in an `f64`, exponent 255 included, so the
+    /// conversion never rounds or clamps. Denormalized floats are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0x3EAAAAAA).to_f64(), 11184810.0 / 33554432.0);
+    /// assert_eq!(Ps2Float::max().to_f64(), 2f64.powi(129) - 2f64.powi(105));
+    /// assert_eq!(Ps2Float::from_f64(Ps2Float::max().to_f64()), Ps2Float::max());
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        if self.is_denormalized() {
+            return if self.sign() { -0.0 } else { 0.0 };
+        }
+
+        self.exact_value()
+    }
+}
+
+/// Converts a host float to a PS2 float, flushing denormals to zero and
+/// clamping infinities and NaNs to +/- Fmax.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::Ps2Float;
+///
+/// assert_eq!(Ps2Float::from(-2.5).as_u32(), 0xC0200000);
+/// assert_eq!(Ps2Float::from(f32::NEG_INFINITY), Ps2Float::min());
+/// ```
+impl From<f32> for Ps2Float {
+    fn from(value: f32) -> Self {
+        Ps2Float::from_f32_with_policy(value, NonFinitePolicy::default())
+    }
+}
+
+/// Converts a PS2 float to an `i32`, succeeding only if it's exactly an
+/// integer in range.
+///
+/// Denormalized floats are zero on the PS2 and convert to 0. Exponent 255 is a
+/// regular exponent, so INF and NaN patterns are big integers out of range.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::convert::TryFromPs2FloatError;
+/// use ps2_floating_point::Ps2Float;
+///
+/// assert_eq!(i32::try_from(Ps2Float::new(0xC2280000)), Ok(-42));
+///
+/// let half = Ps2Float::new(0x3F000000);
+/// assert_eq!(i32::try_from(half), Err(TryFromPs2FloatError::NotAnInteger { value: half }));
+/// ```
+impl TryFrom<Ps2Float> for i32 {
+    type Error = TryFromPs2FloatError;
+
+    fn try_from(value: Ps2Float) -> Result<Self, Self::Error> {
+        if value.is_denormalized() {
+            return Ok(0);
+        }
+
+        let exact = value.exact_value();
+        if exact.fract() != 0.0 {
+            return Err(TryFromPs2FloatError::NotAnInteger { value });
+        }
+        if exact < i32::MIN as f64 || exact > i32::MAX as f64 {
+            return Err(TryFromPs2FloatError::OutOfRange { value });
+        }
+
+        Ok(exact as i32)
+    }
+}
+
+impl Display for TryFromPs2FloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TryFromPs2FloatError::NotAnInteger { value } => {
+                write!(f, "0x{:08X} ({}) is not an integer", value.as_u32(), value)
+            }
+            TryFromPs2FloatError::OutOfRange { value } => {
+                write!(f, "0x{:08X} ({}) is out of range", value.as_u32(), value)
+            }
+        }
+    }
+}
+
+impl Error for TryFromPs2FloatError {}
+
+impl Display for TryFromF32Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{:08X} is a NaN", self.bits)
+    }
+}
+
+impl Error for TryFromF32Error {}
+
+impl Display for ConversionReport {
+    /// Formats the report as a summary, e.g. `2 of 4 values altered: 1
+    /// denormals flushed, 1 infinities and 0 NaNs mapped`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} values altered: {} denormals flushed, {} infinities and {} NaNs mapped",
+            self.altered(),
+            self.total,
+            self.denormals_flushed,
+            self.infinities_mapped,
+            self.nans_mapped
+        )
+    }
+}
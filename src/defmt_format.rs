@@ -0,0 +1,40 @@
+//! Compact `defmt` logging of PS2 floats and the flag registers.
+//!
+//! Values are logged as their raw bits, which defmt encodes without formatting
+//! on the target and which exactly identify the value.
+
+use defmt::{Format, Formatter};
+
+use crate::clip::{ClipFlags, ClipJudgment};
+use crate::vu::{MacFlags, StatusFlags};
+use crate::Ps2Float;
+
+impl Format for Ps2Float {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "Ps2Float({=u32:#010X})", self.as_u32())
+    }
+}
+
+impl Format for MacFlags {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "MacFlags({=u16:#06X})", self.bits())
+    }
+}
+
+impl Format for StatusFlags {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "StatusFlags({=u16:#05X})", self.bits())
+    }
+}
+
+impl Format for ClipFlags {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "ClipFlags({=u32:#08X})", self.bits())
+    }
+}
+
+impl Format for ClipJudgment {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "ClipJudgment({=u8:#04X})", self.bits())
+    }
+}
@@ -0,0 +1,162 @@
+//! An experimental mode carrying denormalized floats through the arithmetic.
+//!
+//! The crate flushes denormalized operands and results to zero, which is what
+//! the PS2 is documented and observed to do. Passing them through instead runs
+//! them through the same truncating adder, so a hardware capture that disagrees
+//! with the flush policy can be checked against this alternative:
+//!
+//! - A denormalized operand has no implicit leading bit and the exponent of
+//!   the smallest normalized floats, so its value is `mantissa * 2^-149`.
+//! - The operands are aligned and added or subtracted like normalized ones,
+//!   truncating the bits shifted out.
+//! - A result below the normalized range is kept as a denormalized float,
+//!   truncated to a multiple of `2^-149`, instead of flushing to zero.
+//!
+//! Zeros, Fmax and every operation between normalized floats whose result
+//! stays normalized behave the same in both modes. Only the truncating adder
+//! carries denormals. With [`RoundingMode::NearestEven`], results of
+//! normalized operands are still rounded and flushed as before.
+//!
+//! Like the rounding mode, the mode is set per thread.
+//!
+//! [`RoundingMode::NearestEven`]: crate::rounding::RoundingMode::NearestEven
+
+use std::cell::Cell;
+
+use crate::Ps2Float;
+
+/// How the arithmetic treats denormalized floats.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DenormalMode {
+    /// Flush denormalized operands and results to zero like the hardware.
+    #[default]
+    Flush,
+    /// Carry denormalized operands and results through the arithmetic.
+    PassThrough,
+}
+
+thread_local! {
+    static DENORMAL_MODE: Cell<DenormalMode> = const { Cell::new(DenormalMode::Flush) };
+}
+
+/// Restores the previous denormal mode when dropped.
+struct RestoreDenormalMode(DenormalMode);
+
+impl Drop for RestoreDenormalMode {
+    fn drop(&mut self) {
+        DENORMAL_MODE.with(|mode| mode.set(self.0));
+    }
+}
+
+/// Returns the denormal mode of the current thread, [`DenormalMode::Flush`]
+/// unless changed by [`with_denormal_mode`].
+///
+/// Returns the default mode while the thread is being torn down.
+pub fn denormal_mode() -> DenormalMode {
+    DENORMAL_MODE.try_with(Cell::get).unwrap_or_default()
+}
+
+/// Runs `f` with the denormal mode of the current thread set to `mode`,
+/// restoring the previous mode afterwards even if `f` panics.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::denormals::{with_denormal_mode, DenormalMode};
+/// use ps2_floating_point::Ps2Float;
+///
+/// let a = Ps2Float::new(0x00000003); // 3 * 2^-149
+/// let b = Ps2Float::new(0x00000001); // 2^-149
+///
+/// assert_eq!(a.add(b).as_u32(), 0x00000000);
+/// let carried = with_denormal_mode(DenormalMode::PassThrough, || a.add(b));
+/// assert_eq!(carried.as_u32(), 0x00000004);
+/// ```
+pub fn with_denormal_mode<T>(mode: DenormalMode, f: impl FnOnce() -> T) -> T {
+    let _restore = RestoreDenormalMode(denormal_mode());
+    DENORMAL_MODE.with(|current| current.set(mode));
+    f()
+}
+
+impl Ps2Float {
+    /// Adds or subtracts two floats of which at least one is denormalized,
+    /// carrying the denormalized ones through if the mode says so.
+    ///
+    /// Returns `None` to flush them like the hardware, which is also the case
+    /// when the only denormalized operands are zeros.
+    pub(crate) fn pass_through_denormals(&self, other: &Ps2Float, add: bool) -> Option<Ps2Float> {
+        let nonzero_denormal = |value: &Ps2Float| value.exponent == 0 && value.mantissa != 0;
+        if !(nonzero_denormal(self) || nonzero_denormal(other))
+            || denormal_mode() != DenormalMode::PassThrough
+        {
+            return None;
+        }
+
+        if !add && self == other {
+            let mut result = Self::new(0);
+            result.sign = Self::determine_subtraction_operation_sign(self, other);
+            return Some(result);
+        }
+
+        Some(self.add_or_sub_gradual(other, /* add= */ (self.sign == other.sign) == add))
+    }
+
+    /// Adds or subtracts the magnitudes of two floats like the truncating
+    /// adder, reading denormalized operands as `mantissa * 2^-149` and keeping
+    /// results below the normalized range denormalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other float to add or subtract.
+    /// * `add` - Adds the magnitudes if true, otherwise subtract them.
+    pub(crate) fn add_or_sub_gradual(&self, other: &Ps2Float, add: bool) -> Ps2Float {
+        // Denormalized floats share the exponent of the smallest normalized ones
+        // without the implicit leading bit.
+        let operand = |value: &Ps2Float| match value.exponent {
+            0 => (1, value.mantissa),
+            exponent => (exponent as i32, value.mantissa | 0x800000),
+        };
+        let (self_exponent, mut self_mantissa) = operand(self);
+        let (other_exponent, mut other_mantissa) = operand(other);
+
+        // Align the exponents, truncating the bits shifted out.
+        let exponent_difference = self_exponent.abs_diff(other_exponent);
+        let mut exponent = self_exponent.max(other_exponent);
+        if self_exponent >= other_exponent {
+            other_mantissa = other_mantissa.checked_shr(exponent_difference).unwrap_or(0);
+        } else {
+            self_mantissa = self_mantissa.checked_shr(exponent_difference).unwrap_or(0);
+        }
+
+        let (mut mantissa, sign) = if add {
+            (self_mantissa + other_mantissa, self.sign)
+        } else if self_mantissa >= other_mantissa {
+            (self_mantissa - other_mantissa, self.sign)
+        } else {
+            (other_mantissa - self_mantissa, !self.sign)
+        };
+
+        // Both magnitudes cancelled each other out.
+        if mantissa == 0 {
+            return Self::default();
+        }
+
+        while mantissa >= 0x1000000 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+        if exponent > 255 {
+            return if sign { Self::min() } else { Self::max() };
+        }
+        while mantissa < 0x800000 && exponent > 1 {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+
+        // A mantissa still without its leading bit is denormalized.
+        if mantissa < 0x800000 {
+            exponent = 0;
+        }
+        Self::from_params(sign, exponent as u8, mantissa & 0x7FFFFF)
+    }
+}
@@ -0,0 +1,224 @@
+//! Markdown and LaTeX derivations of traced operations.
+//!
+//! A derivation splits the operands and the result into their fields, lays
+//! out the mantissa arithmetic in aligned binary columns and annotates every
+//! step of the [`Explanation`], so worked examples for articles and course
+//! material come straight from the implementation.
+
+use std::fmt::Write;
+
+use crate::explain::{Explanation, SpecialCase};
+use crate::Ps2Float;
+
+/// An annotated step of a derivation.
+struct Step {
+    title: String,
+    text: String,
+    /// The aligned mantissas and the raw result of the step adding or
+    /// subtracting the magnitudes.
+    columns: Option<(u32, u32, u32)>,
+}
+
+impl Step {
+    fn new(title: &str, text: String) -> Self {
+        Self { title: title.to_string(), text, columns: None }
+    }
+}
+
+impl Explanation {
+    /// Renders the trace as a Markdown derivation.
+    ///
+    /// The fields of the operands and the result are listed in a table and the
+    /// magnitudes are added or subtracted in a code block, right-aligned in
+    /// binary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let three = Ps2Float::new(0x40400000);
+    /// let markdown = three.add_explained(Ps2Float::new(0x3FC00001)).to_markdown();
+    ///
+    /// assert!(markdown.starts_with("**`0x40400000` + `0x3FC00001`**"));
+    /// assert!(markdown.contains("   = 1001000000000000000000000\n"));
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let _ = writeln!(
+            markdown,
+            "**`0x{:08X}` {} `0x{:08X}`**\n",
+            self.a.as_u32(),
+            self.operation.symbol(),
+            self.b.as_u32()
+        );
+
+        markdown.push_str("| | Sign | Exponent | Mantissa | Value |\n");
+        markdown.push_str("|---|---|---|---|---|\n");
+        for (name, value) in self.rows() {
+            let (sign, exponent, mantissa) = fields(value);
+            let _ = writeln!(
+                markdown,
+                "| {} | `{}` | `{}` | `{}` | {} |",
+                name, sign, exponent, mantissa, value
+            );
+        }
+
+        for (i, step) in self.steps().iter().enumerate() {
+            let _ = write!(markdown, "\n{}. **{}:**", i + 1, step.title);
+            if !step.text.is_empty() {
+                let _ = write!(markdown, " {}", step.text);
+            }
+            markdown.push('\n');
+
+            if let Some((a, b, raw)) = step.columns {
+                let symbol = if self.magnitude_add { '+' } else { '-' };
+                let width = format!("{:b}", a.max(b).max(raw)).len();
+                markdown.push_str("\n   ```text\n");
+                for (symbol, value) in [(' ', a), (symbol, b), ('=', raw)] {
+                    let _ = writeln!(markdown, "   {} {:>width$b}", symbol, value, width = width);
+                }
+                markdown.push_str("   ```\n");
+            }
+        }
+
+        markdown
+    }
+
+    /// Renders the trace as a LaTeX derivation.
+    ///
+    /// The fields of the operands and the result are listed in a `tabular` and
+    /// the magnitudes are added or subtracted in a right-aligned `array`, in an
+    /// `enumerate` of the steps. Only the default LaTeX environments are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let three = Ps2Float::new(0x40400000);
+    /// let latex = three.add_explained(Ps2Float::new(0x3FC00001)).to_latex();
+    ///
+    /// assert!(latex.contains("\\item \\textbf{Add magnitudes:}"));
+    /// assert!(latex.contains("& \\texttt{1001000000000000000000000}"));
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut latex = String::new();
+        let _ = writeln!(
+            latex,
+            "\\[ \\texttt{{0x{:08X}}} {} \\texttt{{0x{:08X}}} \\]",
+            self.a.as_u32(),
+            self.operation.symbol(),
+            self.b.as_u32()
+        );
+
+        latex.push_str("\\begin{tabular}{lllll}\n & Sign & Exponent & Mantissa & Value \\\\\n");
+        latex.push_str("\\hline\n");
+        for (name, value) in self.rows() {
+            let (sign, exponent, mantissa) = fields(value);
+            let _ = writeln!(
+                latex,
+                "{} & \\texttt{{{}}} & \\texttt{{{}}} & \\texttt{{{}}} & {} \\\\",
+                name,
+                sign,
+                exponent,
+                mantissa,
+                escape_latex(&value.to_string())
+            );
+        }
+        latex.push_str("\\end{tabular}\n\\begin{enumerate}\n");
+
+        for step in self.steps() {
+            let _ = write!(latex, "\\item \\textbf{{{}:}}", step.title);
+            if !step.text.is_empty() {
+                let _ = write!(latex, " {}", escape_latex(&step.text));
+            }
+            latex.push('\n');
+
+            if let Some((a, b, raw)) = step.columns {
+                let symbol = if self.magnitude_add { '+' } else { '-' };
+                latex.push_str("\\[\n\\begin{array}{cr}\n");
+                let _ = writeln!(latex, " & \\texttt{{{:b}}} \\\\", a);
+                let _ = writeln!(latex, "{} & \\texttt{{{:b}}} \\\\", symbol, b);
+                let _ = writeln!(latex, "\\hline\n & \\texttt{{{:b}}}", raw);
+                latex.push_str("\\end{array}\n\\]\n");
+            }
+        }
+
+        latex.push_str("\\end{enumerate}\n");
+        latex
+    }
+
+    /// Returns the floats listed in the table of fields.
+    fn rows(&self) -> [(&'static str, Ps2Float); 3] {
+        [("a", self.a), ("b", self.b), ("result", self.result)]
+    }
+
+    /// Returns the steps of the derivation, in the order of the
+    /// [`Display`](std::fmt::Display) trace.
+    fn steps(&self) -> Vec<Step> {
+        let (a_class, b_class) = self.classifications();
+        let mut steps = vec![Step::new(
+            "Classify",
+            format!("a is {}, b is {}.", a_class.description(), b_class.description()),
+        )];
+
+        if matches!(self.special_case, None | Some(SpecialCase::Cancellation)) {
+            let truncated = match self.alignment_shift.min(24) {
+                0 => "nothing".to_string(),
+                width => format!("bits {:0width$b}", self.truncated_bits, width = width as usize),
+            };
+            steps.push(Step::new(
+                "Align",
+                format!(
+                    "shift the smaller mantissa right by {}, truncating {}.",
+                    self.alignment_shift, truncated
+                ),
+            ));
+
+            let title = if self.magnitude_add { "Add magnitudes" } else { "Subtract magnitudes" };
+            let (a, b) = self.aligned_mantissas;
+            steps.push(Step {
+                columns: Some((a, b, self.raw_mantissa)),
+                ..Step::new(title, String::new())
+            });
+
+            for normalization in &self.normalization {
+                steps.push(Step::new("Normalize", format!("{}.", normalization)));
+            }
+        }
+
+        if let Some(special_case) = self.special_case {
+            steps.push(Step::new("Special case", format!("{}.", special_case)));
+        }
+
+        steps.push(Step::new(
+            "Result",
+            format!("0x{:08X} ({}).", self.result.as_u32(), self.result),
+        ));
+        steps
+    }
+}
+
+/// Returns the sign, exponent and mantissa fields of a float in binary.
+fn fields(value: Ps2Float) -> (u8, String, String) {
+    (value.sign() as u8, format!("{:08b}", value.exponent()), format!("{:023b}", value.mantissa()))
+}
+
+/// Escapes the characters LaTeX treats specially in text.
+fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
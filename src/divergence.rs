@@ -0,0 +1,165 @@
+//! Finding where the EE FPU and a VU disagree on the same operation.
+//!
+//! Games sometimes compute the same quantity on both units and compare the
+//! results, for example culling on VU1 what the EE already tested. The units
+//! share the arithmetic and raise the same conditions, the EE FPU in FCR31 and
+//! the VU in its MAC and status flags. Running the same operand pairs through
+//! [`FpuContext`] and one lane of a [`VuInterpreter`] checks that such code
+//! takes the same branches on both, reporting the pairs where the two models
+//! disagree.
+
+use std::fmt::{Display, Formatter};
+
+use crate::fpu::{FpuContext, FCR31_D, FCR31_I, FCR31_O, FCR31_U};
+use crate::vu::instruction::{FmacOperation, Lane, LowerInstruction, Operand, UpperInstruction};
+use crate::vu::{StatusFlags, VuInterpreter};
+use crate::{Operation, Ps2Float, Ps2Vec4};
+
+/// The result of an operation on one unit and the flags it raised.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitOutcome {
+    /// The result, in the destination register or Q for a VU DIV.
+    pub result: Ps2Float,
+    /// O, the result overflowed.
+    pub overflow: bool,
+    /// U, the result underflowed.
+    pub underflow: bool,
+    /// I, zero was divided by zero.
+    pub invalid: bool,
+    /// D, a nonzero float was divided by zero.
+    pub divide: bool,
+}
+
+/// An operand pair on which the EE FPU and the VU disagree.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// The operation run on both units.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The outcome on the EE FPU.
+    pub ee: UnitOutcome,
+    /// The outcome on the VU.
+    pub vu: UnitOutcome,
+}
+
+/// Runs an operation on the EE FPU, like ADD.S, SUB.S, MUL.S or DIV.S.
+pub fn run_ee(operation: Operation, a: Ps2Float, b: Ps2Float) -> UnitOutcome {
+    let mut fpu = FpuContext::new();
+    let result = match operation {
+        Operation::Add => fpu.add(a, b),
+        Operation::Sub => fpu.sub(a, b),
+        Operation::Mul => fpu.mul(a, b),
+        Operation::Div => fpu.div(a, b),
+    };
+
+    let fcr31 = fpu.cfc1(31);
+    UnitOutcome {
+        result,
+        overflow: fcr31 & FCR31_O != 0,
+        underflow: fcr31 & FCR31_U != 0,
+        invalid: fcr31 & FCR31_I != 0,
+        divide: fcr31 & FCR31_D != 0,
+    }
+}
+
+/// Runs an operation on the `x` lane of a VU, like ADD.x, SUB.x and MUL.x
+/// into a VF register, or DIV into Q.
+pub fn run_vu(operation: Operation, a: Ps2Float, b: Ps2Float) -> UnitOutcome {
+    let zero = Ps2Float::default();
+    let mut vu = VuInterpreter::new(Vec::new());
+    vu.registers.set_vf(1, Ps2Vec4::new(a, zero, zero, zero));
+    vu.registers.set_vf(2, Ps2Vec4::new(b, zero, zero, zero));
+
+    let fmac = |operation| UpperInstruction::Fmac {
+        operation,
+        dest: 0b1000,
+        fd: Some(3),
+        fs: 1,
+        operand: Operand::Register(2),
+    };
+    let (upper, lower) = match operation {
+        Operation::Add => (fmac(FmacOperation::Add), LowerInstruction::Nop),
+        Operation::Sub => (fmac(FmacOperation::Sub), LowerInstruction::Nop),
+        Operation::Mul => (fmac(FmacOperation::Mul), LowerInstruction::Nop),
+        Operation::Div => (
+            UpperInstruction::Nop,
+            LowerInstruction::Div { fs: 1, fsf: Lane::X, ft: 2, ftf: Lane::X },
+        ),
+    };
+    vu.execute(upper, lower);
+
+    let registers = &vu.registers;
+    let (_, _, underflow, overflow) = registers.mac_flags.lane(0);
+    let result = match operation {
+        Operation::Div => registers.q,
+        _ => Lane::X.of(&registers.vf(3)),
+    };
+    UnitOutcome {
+        result,
+        overflow,
+        underflow,
+        invalid: registers.status_flags.contains(StatusFlags::INVALID),
+        divide: registers.status_flags.contains(StatusFlags::DIVIDE),
+    }
+}
+
+/// Runs an operation on both units, returning where they disagree.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::divergence::compare;
+/// use ps2_floating_point::{Operation, Ps2Float};
+///
+/// let (max, two) = (Ps2Float::max(), Ps2Float::new(0x40000000));
+///
+/// // Both saturate and raise the overflow flag.
+/// assert_eq!(compare(Operation::Mul, max, two), None);
+/// assert_eq!(compare(Operation::Add, max, two), None);
+/// ```
+pub fn compare(operation: Operation, a: Ps2Float, b: Ps2Float) -> Option<Divergence> {
+    let (ee, vu) = (run_ee(operation, a, b), run_vu(operation, a, b));
+    (ee != vu).then_some(Divergence { operation, a, b, ee, vu })
+}
+
+/// Runs an operation on both units for every operand pair, returning the
+/// pairs they disagree on in order.
+pub fn find_divergences<I>(operation: Operation, pairs: I) -> Vec<Divergence>
+where
+    I: IntoIterator<Item = (Ps2Float, Ps2Float)>,
+{
+    pairs.into_iter().filter_map(|(a, b)| compare(operation, a, b)).collect()
+}
+
+impl Display for UnitOutcome {
+    /// Formats the result and the set flags by name, e.g. `0x7FFFFFFF [O]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> =
+            [(self.overflow, "O"), (self.underflow, "U"), (self.invalid, "I"), (self.divide, "D")]
+                .into_iter()
+                .filter_map(|(set, name)| set.then_some(name))
+                .collect();
+
+        let flags = if names.is_empty() { "-".to_string() } else { names.join(" ") };
+        write!(f, "0x{:08X} [{}]", self.result.as_u32(), flags)
+    }
+}
+
+impl Display for Divergence {
+    /// Formats the operation and both outcomes, e.g.
+    /// `0x7FFFFFFF * 0x40000000: EE 0x7FFFFFFF [O], VU 0x7FFFFFFF [-]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "0x{:08X} {} 0x{:08X}: EE {}, VU {}",
+            self.a.as_u32(),
+            self.operation.symbol(),
+            self.b.as_u32(),
+            self.ee,
+            self.vu
+        )
+    }
+}
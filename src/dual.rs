@@ -0,0 +1,158 @@
+//! Dual numbers propagating derivatives through PS2 arithmetic.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{FloatOps, Ps2Float};
+
+/// A dual number `value + derivative * ε` with `ε² = 0`, for forward-mode
+/// automatic differentiation with PS2 arithmetic.
+///
+/// Both the value and the derivative are computed with PS2 operations, so the
+/// derivative carries the truncation of every step the way game physics code
+/// running on the hardware would. Algorithms written over [`FloatOps`] can be
+/// differentiated by instantiating them with `Ps2Dual`.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{Ps2Dual, Ps2Float};
+///
+/// let x = Ps2Dual::variable(Ps2Float::new(0x40400000)); // 3.00
+/// let one = Ps2Dual::constant(Ps2Float::new(0x3F800000));
+///
+/// let y = x + x - one; // 2x - 1
+///
+/// assert_eq!(y.value().as_u32(), 0x40A00000); // 5.00
+/// assert_eq!(y.derivative().as_u32(), 0x40000000); // 2.00
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2Dual {
+    value: Ps2Float,
+    derivative: Ps2Float,
+}
+
+impl Ps2Dual {
+    /// Creates a new dual number from its value and derivative.
+    pub const fn new(value: Ps2Float, derivative: Ps2Float) -> Self {
+        Self { value, derivative }
+    }
+
+    /// Creates a new dual number for a constant, with a derivative of zero.
+    pub const fn constant(value: Ps2Float) -> Self {
+        Self::new(value, Ps2Float::new(0))
+    }
+
+    /// Creates a new dual number for the variable being differentiated, with a
+    /// derivative of one.
+    pub const fn variable(value: Ps2Float) -> Self {
+        Self::new(value, Ps2Float::new(0x3F800000))
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> Ps2Float {
+        self.value
+    }
+
+    /// Returns the derivative.
+    pub fn derivative(&self) -> Ps2Float {
+        self.derivative
+    }
+
+    /// Returns the square root, with the derivative computed as
+    /// `derivative / (2 * sqrt(value))`.
+    pub fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        let two = Ps2Float::new(0x40000000);
+        Self::new(root, self.derivative.div(two.mul(root)))
+    }
+}
+
+impl Add for Ps2Dual {
+    type Output = Ps2Dual;
+
+    fn add(self, rhs: Ps2Dual) -> Ps2Dual {
+        Ps2Dual::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl Sub for Ps2Dual {
+    type Output = Ps2Dual;
+
+    fn sub(self, rhs: Ps2Dual) -> Ps2Dual {
+        Ps2Dual::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl Mul for Ps2Dual {
+    type Output = Ps2Dual;
+
+    /// Multiplies two dual numbers, with the derivative computed as
+    /// `a' * b + a * b'`.
+    fn mul(self, rhs: Ps2Dual) -> Ps2Dual {
+        let derivative = self.derivative * rhs.value + self.value * rhs.derivative;
+        Ps2Dual::new(self.value * rhs.value, derivative)
+    }
+}
+
+impl Div for Ps2Dual {
+    type Output = Ps2Dual;
+
+    /// Divides two dual numbers, with the derivative of the quotient `q`
+    /// computed as `(a' - q * b') / b`.
+    fn div(self, rhs: Ps2Dual) -> Ps2Dual {
+        let quotient = self.value / rhs.value;
+        let derivative = (self.derivative - quotient * rhs.derivative) / rhs.value;
+        Ps2Dual::new(quotient, derivative)
+    }
+}
+
+impl From<Ps2Float> for Ps2Dual {
+    /// Creates a constant dual number.
+    fn from(value: Ps2Float) -> Self {
+        Self::constant(value)
+    }
+}
+
+/// Constants are constant dual numbers, and the bits are those of the value.
+impl FloatOps for Ps2Dual {
+    const ZERO: Self = Ps2Dual::constant(Ps2Float::ZERO);
+    const ONE: Self = Ps2Dual::constant(Ps2Float::ONE);
+    const MAX: Self = Ps2Dual::constant(Ps2Float::MAX);
+    const MIN: Self = Ps2Dual::constant(Ps2Float::MIN);
+
+    fn from_bits(bits: u32) -> Self {
+        Ps2Dual::constant(Ps2Float::new(bits))
+    }
+
+    fn to_bits(self) -> u32 {
+        self.value.as_u32()
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn div(self, other: Self) -> Self {
+        self / other
+    }
+
+    fn sqrt(self) -> Self {
+        Ps2Dual::sqrt(self)
+    }
+}
+
+impl Display for Ps2Dual {
+    /// Formats the dual number as `value + derivativeε`, e.g. `5.00 + 2.00ε`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
@@ -0,0 +1,216 @@
+//! Step-by-step traces of PS2 float operations.
+//!
+//! The traces are recorded by the arithmetic itself, so they always describe
+//! exactly what the operation did.
+
+use std::fmt::{Display, Formatter};
+
+use crate::{Classification, Operation, Ps2Float};
+
+/// A special case that short-circuits the regular algorithm.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SpecialCase {
+    /// An operand is denormalized and treated as zero.
+    DenormalizedOperand,
+    /// Both operands are abnormal and the result comes from a lookup table.
+    AbnormalOperands,
+    /// The operands are equal so the difference is zero.
+    EqualOperands,
+    /// The mantissas cancelled each other out.
+    Cancellation,
+}
+
+/// A single step of normalizing the raw result mantissa.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NormalizationStep {
+    /// The mantissa was shifted right and the exponent incremented.
+    ShiftRight { mantissa: u32, exponent: u8 },
+    /// The mantissa was shifted left and the exponent decremented.
+    ShiftLeft { mantissa: u32, exponent: u8 },
+    /// The exponent overflowed and the result clamped to +/- Fmax.
+    Overflow,
+    /// The exponent underflowed and the result flushed to +/- 0.
+    Underflow,
+}
+
+/// Where adding and subtracting record what they did.
+pub(crate) enum Trace<'a> {
+    /// Nothing is recorded.
+    Off,
+    /// Only if the result overflowed is recorded.
+    Overflow(&'a mut bool),
+    /// Every step is recorded.
+    Full(&'a mut Explanation),
+}
+
+/// The trace of a single operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The operation that was performed.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The special case taken instead of the regular algorithm, if any.
+    pub special_case: Option<SpecialCase>,
+    /// Whether the magnitudes were added, otherwise subtracted.
+    pub magnitude_add: bool,
+    /// How many bits the mantissa of the smaller operand was shifted right to
+    /// align the exponents.
+    pub alignment_shift: u32,
+    /// The mantissas with the implicit leading bit after alignment, `a` first.
+    pub aligned_mantissas: (u32, u32),
+    /// The bits shifted out of the smaller operand while aligning, which the
+    /// PS2 discards instead of keeping guard bits.
+    pub truncated_bits: u32,
+    /// The sum or difference of the aligned mantissas before normalization.
+    pub raw_mantissa: u32,
+    /// The steps that normalized the raw mantissa.
+    pub normalization: Vec<NormalizationStep>,
+    /// The final result.
+    pub result: Ps2Float,
+}
+
+impl Explanation {
+    /// Creates an empty trace of the operation, filled in while it runs.
+    pub(crate) fn new(operation: Operation, a: &Ps2Float, b: &Ps2Float) -> Self {
+        Self {
+            operation,
+            a: *a,
+            b: *b,
+            special_case: None,
+            magnitude_add: false,
+            alignment_shift: 0,
+            aligned_mantissas: (0, 0),
+            truncated_bits: 0,
+            raw_mantissa: 0,
+            normalization: Vec::new(),
+            result: Ps2Float::default(),
+        }
+    }
+
+    /// Returns the classification of both operands, `a` first.
+    pub fn classifications(&self) -> (Classification, Classification) {
+        (self.a.classify(), self.b.classify())
+    }
+
+    /// Returns if the exact result was past Fmax and saturated.
+    ///
+    /// The abnormal table saturates without normalizing, but adding the
+    /// magnitudes of two floats with exponent 255 always goes past Fmax.
+    pub(crate) fn overflowed(&self) -> bool {
+        let adds_magnitudes =
+            (self.a.sign() == self.b.sign()) == (self.operation == Operation::Add);
+        self.normalization.contains(&NormalizationStep::Overflow)
+            || (self.special_case == Some(SpecialCase::AbnormalOperands) && adds_magnitudes)
+    }
+}
+
+impl Ps2Float {
+    /// Adds two PS2 floats together and returns a trace of every step.
+    ///
+    /// # Arguments
+    ///
+    /// * `addend` - The addend float to add to `self`.
+    ///
+    /// # Returns
+    ///
+    /// An [`Explanation`] of the addition, with the sum as its result.
+    pub fn add_explained(self, addend: Ps2Float) -> Explanation {
+        let mut explanation = Explanation::new(Operation::Add, &self, &addend);
+        explanation.result = self.add_traced(&addend, Trace::Full(&mut explanation));
+        explanation
+    }
+
+    /// Subtracts two PS2 floats from each other and returns a trace of every
+    /// step.
+    ///
+    /// # Arguments
+    ///
+    /// * `subtrahend` - The float to subtract from `self`.
+    ///
+    /// # Returns
+    ///
+    /// An [`Explanation`] of the subtraction, with the difference as its result.
+    pub fn sub_explained(self, subtrahend: Ps2Float) -> Explanation {
+        let mut explanation = Explanation::new(Operation::Sub, &self, &subtrahend);
+        explanation.result = self.sub_traced(&subtrahend, Trace::Full(&mut explanation));
+        explanation
+    }
+}
+
+impl Display for SpecialCase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecialCase::DenormalizedOperand => write!(f, "denormalized operand treated as zero"),
+            SpecialCase::AbnormalOperands => write!(f, "both operands abnormal, result from table"),
+            SpecialCase::EqualOperands => write!(f, "equal operands, difference is zero"),
+            SpecialCase::Cancellation => write!(f, "mantissas cancelled out, result is zero"),
+        }
+    }
+}
+
+impl Display for NormalizationStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizationStep::ShiftRight { mantissa, exponent } => {
+                write!(f, "shift right to 0x{:X}, exponent 0x{:02X}", mantissa, exponent)
+            }
+            NormalizationStep::ShiftLeft { mantissa, exponent } => {
+                write!(f, "shift left to 0x{:X}, exponent 0x{:02X}", mantissa, exponent)
+            }
+            NormalizationStep::Overflow => write!(f, "exponent overflow, clamp to Fmax"),
+            NormalizationStep::Underflow => write!(f, "exponent underflow, flush to zero"),
+        }
+    }
+}
+
+impl Display for Explanation {
+    /// Formats the trace as one numbered step per line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (a_class, b_class) = self.classifications();
+        writeln!(
+            f,
+            "0x{:08X} {} 0x{:08X}",
+            self.a.as_u32(),
+            self.operation.symbol(),
+            self.b.as_u32()
+        )?;
+        writeln!(f, "1. classify: a is {}, b is {}", a_class.description(), b_class.description())?;
+
+        let mut step = 2;
+        let has_mantissa_steps =
+            matches!(self.special_case, None | Some(SpecialCase::Cancellation));
+        if has_mantissa_steps {
+            writeln!(
+                f,
+                "{}. align: shift smaller mantissa right by {}, truncating bits 0x{:X}",
+                step, self.alignment_shift, self.truncated_bits
+            )?;
+            writeln!(
+                f,
+                "{}. {} magnitudes: 0x{:06X} {} 0x{:06X} = 0x{:X}",
+                step + 1,
+                if self.magnitude_add { "add" } else { "subtract" },
+                self.aligned_mantissas.0,
+                if self.magnitude_add { '+' } else { '-' },
+                self.aligned_mantissas.1,
+                self.raw_mantissa
+            )?;
+            step += 2;
+
+            for normalization in &self.normalization {
+                writeln!(f, "{}. normalize: {}", step, normalization)?;
+                step += 1;
+            }
+        }
+
+        if let Some(special_case) = self.special_case {
+            writeln!(f, "{}. special case: {}", step, special_case)?;
+            step += 1;
+        }
+
+        write!(f, "{}. result: 0x{:08X} ({})", step, self.result.as_u32(), self.result)
+    }
+}
@@ -0,0 +1,152 @@
+//! C interface for emulators written in C or C++.
+//!
+//! Floats cross the boundary as their 32-bit patterns. See
+//! `include/ps2_floating_point.h` for the matching declarations.
+//!
+//! The flag-returning functions write the flags through a pointer, which may
+//! be null when the caller only wants the result.
+
+use crate::convert::NonFinitePolicy;
+use crate::{DivideFlags, OperationFlags, Ps2Float};
+
+/// The flags an operation raises, see [`OperationFlags`].
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2FloatFlags {
+    /// Z, the result is +/- 0.
+    pub zero: bool,
+    /// S, the result is negative, -0 included.
+    pub sign: bool,
+    /// U, the result underflowed and flushed to +/- 0.
+    pub underflow: bool,
+    /// O, the result overflowed and saturated to +/- Fmax.
+    pub overflow: bool,
+}
+
+/// The flags a division raises, see [`DivideFlags`].
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2FloatDivideFlags {
+    /// I, zero was divided by zero.
+    pub invalid: bool,
+    /// D, a nonzero float was divided by zero.
+    pub divide: bool,
+}
+
+/// Adds two PS2 floats, see [`Ps2Float::add`].
+#[no_mangle]
+pub extern "C" fn ps2float_add(a: u32, b: u32) -> u32 {
+    Ps2Float::new(a).add(Ps2Float::new(b)).as_u32()
+}
+
+/// Subtracts two PS2 floats, see [`Ps2Float::sub`].
+#[no_mangle]
+pub extern "C" fn ps2float_sub(a: u32, b: u32) -> u32 {
+    Ps2Float::new(a).sub(Ps2Float::new(b)).as_u32()
+}
+
+/// Multiplies two PS2 floats, see [`Ps2Float::mul`].
+#[no_mangle]
+pub extern "C" fn ps2float_mul(a: u32, b: u32) -> u32 {
+    Ps2Float::new(a).mul(Ps2Float::new(b)).as_u32()
+}
+
+/// Divides two PS2 floats, see [`Ps2Float::div`].
+#[no_mangle]
+pub extern "C" fn ps2float_div(a: u32, b: u32) -> u32 {
+    Ps2Float::new(a).div(Ps2Float::new(b)).as_u32()
+}
+
+/// Adds two PS2 floats and writes the flags it raises to `flags`, see
+/// [`Ps2Float::add_with_flags`].
+#[no_mangle]
+pub extern "C" fn ps2float_add_with_flags(
+    a: u32,
+    b: u32,
+    flags: Option<&mut Ps2FloatFlags>,
+) -> u32 {
+    write_flags(Ps2Float::new(a).add_with_flags(Ps2Float::new(b)), flags)
+}
+
+/// Subtracts two PS2 floats and writes the flags it raises to `flags`, see
+/// [`Ps2Float::sub_with_flags`].
+#[no_mangle]
+pub extern "C" fn ps2float_sub_with_flags(
+    a: u32,
+    b: u32,
+    flags: Option<&mut Ps2FloatFlags>,
+) -> u32 {
+    write_flags(Ps2Float::new(a).sub_with_flags(Ps2Float::new(b)), flags)
+}
+
+/// Multiplies two PS2 floats and writes the flags it raises to `flags`, see
+/// [`Ps2Float::mul_with_flags`].
+#[no_mangle]
+pub extern "C" fn ps2float_mul_with_flags(
+    a: u32,
+    b: u32,
+    flags: Option<&mut Ps2FloatFlags>,
+) -> u32 {
+    write_flags(Ps2Float::new(a).mul_with_flags(Ps2Float::new(b)), flags)
+}
+
+/// Divides two PS2 floats and writes the flags it raises to `flags`, see
+/// [`Ps2Float::div_with_flags`].
+#[no_mangle]
+pub extern "C" fn ps2float_div_with_flags(
+    a: u32,
+    b: u32,
+    flags: Option<&mut Ps2FloatFlags>,
+) -> u32 {
+    write_flags(Ps2Float::new(a).div_with_flags(Ps2Float::new(b)), flags)
+}
+
+/// Divides two PS2 floats and writes the invalid and divide flags it raises
+/// to `flags`, like DIV, see [`DivideFlags::of_div`].
+#[no_mangle]
+pub extern "C" fn ps2float_div_with_divide_flags(
+    a: u32,
+    b: u32,
+    flags: Option<&mut Ps2FloatDivideFlags>,
+) -> u32 {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+    if let Some(flags) = flags {
+        let DivideFlags { invalid, divide } = DivideFlags::of_div(a, b);
+        *flags = Ps2FloatDivideFlags { invalid, divide };
+    }
+    a.div(b).as_u32()
+}
+
+/// Converts a host float to a PS2 float, flushing denormalized floats to zero
+/// like the PS2 does and keeping the bits of infinities and NaNs, see
+/// [`Ps2Float::from_f32_with_policy`].
+#[no_mangle]
+pub extern "C" fn ps2float_from_f32(value: f32) -> u32 {
+    Ps2Float::from_f32_with_policy(value, NonFinitePolicy::Preserve).as_u32()
+}
+
+/// Returns the exact value of a PS2 float as a double. Exponent 255 is a
+/// regular exponent on the PS2, so INF and NaN patterns become big numbers.
+#[no_mangle]
+pub extern "C" fn ps2float_to_f64(value: u32) -> f64 {
+    Ps2Float::new(value).exact_value()
+}
+
+/// Compares two PS2 floats, returning -1, 0 or 1 if `a` is less than, equal to
+/// or greater than `b`.
+#[no_mangle]
+pub extern "C" fn ps2float_cmp(a: u32, b: u32) -> i32 {
+    Ps2Float::new(a).cmp(&Ps2Float::new(b)) as i32
+}
+
+/// Writes the flags of an operation if asked to, returning the result.
+fn write_flags(
+    (result, operation_flags): (Ps2Float, OperationFlags),
+    flags: Option<&mut Ps2FloatFlags>,
+) -> u32 {
+    if let Some(flags) = flags {
+        let OperationFlags { zero, sign, underflow, overflow } = operation_flags;
+        *flags = Ps2FloatFlags { zero, sign, underflow, overflow };
+    }
+    result.as_u32()
+}
@@ -0,0 +1,127 @@
+//! Writing algorithms once over PS2 floats and host floats.
+
+use crate::Ps2Float;
+
+/// The arithmetic shared by [`Ps2Float`] and [`f32`], so game logic can be
+/// written once and instantiated with either numeric model for A/B
+/// comparisons.
+///
+/// The methods mirror the inherent methods of [`Ps2Float`], so calls keep
+/// resolving to them when the trait is in scope.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{FloatOps, Ps2Float};
+///
+/// fn sum<T: FloatOps>(values: &[T]) -> T {
+///     values.iter().fold(T::ZERO, |total, value| total.add(*value))
+/// }
+///
+/// let ieee = [1.0f32, 2e8, -2e8];
+/// let ps2 = ieee.map(|value| Ps2Float::from_bits(value.to_bits()));
+///
+/// assert_eq!(sum(&ieee), 0.0); // 1 is rounded away by the big sum
+/// assert_eq!(sum(&ps2).to_bits(), 0);
+/// ```
+pub trait FloatOps: Copy {
+    /// 0.0.
+    const ZERO: Self;
+    /// 1.0.
+    const ONE: Self;
+    /// The biggest finite value, Fmax on the PS2.
+    const MAX: Self;
+    /// The smallest finite value, -Fmax on the PS2.
+    const MIN: Self;
+
+    /// Returns the float with the given IEEE 754 single bits.
+    fn from_bits(bits: u32) -> Self;
+
+    /// Returns the IEEE 754 single bits of the float.
+    fn to_bits(self) -> u32;
+
+    /// Returns `self + other`.
+    fn add(self, other: Self) -> Self;
+
+    /// Returns `self - other`.
+    fn sub(self, other: Self) -> Self;
+
+    /// Returns `self * other`.
+    fn mul(self, other: Self) -> Self;
+
+    /// Returns `self / other`.
+    fn div(self, other: Self) -> Self;
+
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+}
+
+impl FloatOps for Ps2Float {
+    const ZERO: Self = Ps2Float::new(0);
+    const ONE: Self = Ps2Float::new(0x3F800000);
+    const MAX: Self = Ps2Float::new(Ps2Float::MAX_FLOATING_POINT_VALUE);
+    const MIN: Self = Ps2Float::new(Ps2Float::MIN_FLOATING_POINT_VALUE);
+
+    fn from_bits(bits: u32) -> Self {
+        Ps2Float::new(bits)
+    }
+
+    fn to_bits(self) -> u32 {
+        self.as_u32()
+    }
+
+    fn add(self, other: Self) -> Self {
+        Ps2Float::add(self, other)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Ps2Float::sub(self, other)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Ps2Float::mul(self, other)
+    }
+
+    fn div(self, other: Self) -> Self {
+        Ps2Float::div(self, other)
+    }
+
+    fn sqrt(self) -> Self {
+        Ps2Float::sqrt(self)
+    }
+}
+
+impl FloatOps for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const MAX: Self = f32::MAX;
+    const MIN: Self = f32::MIN;
+
+    fn from_bits(bits: u32) -> Self {
+        f32::from_bits(bits)
+    }
+
+    fn to_bits(self) -> u32 {
+        f32::to_bits(self)
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn div(self, other: Self) -> Self {
+        self / other
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
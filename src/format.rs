@@ -0,0 +1,132 @@
+//! Formatting PS2 floats without allocating.
+//!
+//! Panic handlers, embedded targets and WASM loggers often can't allocate, so
+//! values are written into any [`fmt::Write`] or a fixed-size buffer on the
+//! stack.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::Ps2Float;
+
+/// A fixed-capacity string buffer on the stack.
+///
+/// The default capacity of 64 bytes fits the [`Display`] form of every
+/// [`Ps2Float`]. Writes past the capacity are truncated at a character boundary
+/// and fail with [`fmt::Error`], keeping what fit.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use ps2_floating_point::format::FormatBuffer;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let mut buffer = FormatBuffer::<64>::new();
+/// Ps2Float::new(0x40A9999A).write_to(&mut buffer).unwrap();
+/// assert_eq!(buffer.as_str(), "5.30");
+///
+/// let mut small = FormatBuffer::<4>::new();
+/// assert!(write!(small, "{}", Ps2Float::max()).is_err());
+/// assert_eq!(small.as_str(), "Fmax");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FormatBuffer<const N: usize = 64> {
+    bytes: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> FormatBuffer<N> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Self {
+        Self { bytes: [0; N], len: 0, truncated: false }
+    }
+
+    /// Returns the text written so far.
+    pub fn as_str(&self) -> &str {
+        // Only whole UTF-8 characters are ever copied in.
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns if nothing was written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns if a write didn't fit and was truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Empties the buffer to reuse it.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+}
+
+impl<const N: usize> Default for FormatBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for FormatBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let available = N - self.len;
+        let mut end = s.len().min(available);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.bytes[self.len..self.len + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+
+        if end < s.len() {
+            self.truncated = true;
+            return Err(fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Display for FormatBuffer<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Ps2Float {
+    /// Writes the [`Display`] form of the float into `writer` without
+    /// allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer to format into, such as a [`FormatBuffer`].
+    ///
+    /// # Returns
+    ///
+    /// The error of the writer if it failed.
+    pub fn write_to(&self, writer: &mut impl Write) -> fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Formats the float into a [`FormatBuffer`] on the stack.
+    ///
+    /// # Returns
+    ///
+    /// The [`Display`] form of the float, truncated if it doesn't fit in `N`
+    /// bytes.
+    pub fn to_format_buffer<const N: usize>(&self) -> FormatBuffer<N> {
+        let mut buffer = FormatBuffer::new();
+        // Truncation is recorded in the buffer.
+        let _ = self.write_to(&mut buffer);
+        buffer
+    }
+}
@@ -0,0 +1,383 @@
+//! The EE FPU (COP1) control register and branch instructions.
+//!
+//! [`FpuContext`] owns FCR31 and runs arithmetic through it, raising the cause
+//! flags of the last operation and the sticky flags of every operation since
+//! they were cleared, so an EE interpreter only has to map each instruction to
+//! a call.
+//!
+//! BC1F, BC1T, BC1FL and BC1TL branch on the condition bit the `C.cond.S`
+//! compares of [`FpuCompare`] set, bit 23 of FCR31. The likely variants
+//! nullify their delay slot when the branch isn't taken, so an EE interpreter
+//! needs both answers from the instruction: whether to branch and whether to
+//! run the delay slot.
+
+use crate::vu::instruction::UnsupportedInstruction;
+use crate::{DivideFlags, OperationFlags, Ps2Float};
+
+/// The condition bit of FCR31.
+pub const FCR31_C: u32 = 1 << 23;
+/// The invalid cause flag of FCR31.
+pub const FCR31_I: u32 = 1 << 17;
+/// The divide by zero cause flag of FCR31.
+pub const FCR31_D: u32 = 1 << 16;
+/// The overflow cause flag of FCR31.
+pub const FCR31_O: u32 = 1 << 15;
+/// The underflow cause flag of FCR31.
+pub const FCR31_U: u32 = 1 << 14;
+/// The sticky invalid flag of FCR31.
+pub const FCR31_SI: u32 = 1 << 6;
+/// The sticky divide by zero flag of FCR31.
+pub const FCR31_SD: u32 = 1 << 5;
+/// The sticky overflow flag of FCR31.
+pub const FCR31_SO: u32 = 1 << 4;
+/// The sticky underflow flag of FCR31.
+pub const FCR31_SU: u32 = 1 << 3;
+
+/// The bits of FCR31 CTC1 can write, the condition bit and the flags.
+const FCR31_WRITABLE: u32 = 0x0083C078;
+/// The bits of FCR31 that always read as set.
+const FCR31_FIXED: u32 = 0x01000001;
+/// The value of FCR0, the implementation and revision of the FPU.
+pub const FCR0_REVISION: u32 = 0x2E30;
+
+/// The primary opcode of COP1 instructions.
+const COP1: u32 = 0x11;
+/// The `fmt` field of the COP1 branch instructions.
+const BC: u32 = 0x08;
+
+/// The EE FPU control state, FCR31, updated by the operations run through it.
+///
+/// Every operation replaces the cause flags it can raise and sets their
+/// sticky copies, which stay set until cleared through [`FpuContext::ctc1`].
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::fpu::{FpuContext, FCR31_D, FCR31_SD, FCR31_SO};
+/// use ps2_floating_point::Ps2Float;
+///
+/// let mut fpu = FpuContext::new();
+/// fpu.mul(Ps2Float::max(), Ps2Float::new(0x40000000)); // Overflows
+/// fpu.div(Ps2Float::new(0x3F800000), Ps2Float::new(0));
+///
+/// let fcr31 = fpu.cfc1(31);
+/// assert_eq!(fcr31 & (FCR31_D | FCR31_SD | FCR31_SO), FCR31_D | FCR31_SD | FCR31_SO);
+///
+/// fpu.ctc1(31, 0); // Clears every flag
+/// assert_eq!(fpu.cfc1(31), 0x01000001);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FpuContext {
+    fcr31: u32,
+}
+
+/// A COP1 compare instruction, setting the condition bit of FCR31.
+///
+/// The floats are compared like [`Ps2Float`]'s [`Ord`], as sign-magnitude
+/// integers: Fmax and the IEEE 754 infinity and NaN patterns are ordinary
+/// numbers and +0 equals -0. Denormalized floats are compared as zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FpuCompare {
+    /// C.F.S, never true.
+    F,
+    /// C.EQ.S, true if `fs` equals `ft`.
+    Eq,
+    /// C.LT.S, true if `fs` is less than `ft`.
+    Lt,
+    /// C.LE.S, true if `fs` is less than or equal to `ft`.
+    Le,
+}
+
+/// A decoded COP1 branch instruction.
+///
+/// `offset` is the signed offset in instructions from the delay slot.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FpuBranch {
+    /// BC1F, branches when the condition bit is clear.
+    Bc1f { offset: i16 },
+    /// BC1T, branches when the condition bit is set.
+    Bc1t { offset: i16 },
+    /// BC1FL, BC1F nullifying the delay slot when not taken.
+    Bc1fl { offset: i16 },
+    /// BC1TL, BC1T nullifying the delay slot when not taken.
+    Bc1tl { offset: i16 },
+}
+
+/// What an evaluated branch does.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BranchOutcome {
+    /// Whether the branch is taken.
+    pub taken: bool,
+    /// Whether the delay slot runs, which only a likely branch not taken
+    /// skips.
+    pub executes_delay_slot: bool,
+}
+
+impl FpuBranch {
+    /// Decodes a COP1 branch instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::fpu::FpuBranch;
+    ///
+    /// assert_eq!(FpuBranch::decode(0x4501FFFF), Ok(FpuBranch::Bc1t { offset: -1 }));
+    /// assert!(FpuBranch::decode(0x46000800).is_err()); // ADD.S
+    /// ```
+    pub fn decode(word: u32) -> Result<Self, UnsupportedInstruction> {
+        if word >> 26 != COP1 || (word >> 21) & 0x1F != BC {
+            return Err(UnsupportedInstruction(word));
+        }
+
+        let offset = word as i16;
+        let branch = match (word >> 16) & 0x1F {
+            0 => FpuBranch::Bc1f { offset },
+            1 => FpuBranch::Bc1t { offset },
+            2 => FpuBranch::Bc1fl { offset },
+            3 => FpuBranch::Bc1tl { offset },
+            _ => return Err(UnsupportedInstruction(word)),
+        };
+
+        Ok(branch)
+    }
+
+    /// Returns the offset in instructions from the delay slot.
+    pub fn offset(self) -> i16 {
+        match self {
+            FpuBranch::Bc1f { offset }
+            | FpuBranch::Bc1t { offset }
+            | FpuBranch::Bc1fl { offset }
+            | FpuBranch::Bc1tl { offset } => offset,
+        }
+    }
+
+    /// Returns if the branch is a likely variant.
+    pub fn is_likely(self) -> bool {
+        matches!(self, FpuBranch::Bc1fl { .. } | FpuBranch::Bc1tl { .. })
+    }
+
+    /// Returns the condition bit value the branch is taken on.
+    pub fn branches_on(self) -> bool {
+        matches!(self, FpuBranch::Bc1t { .. } | FpuBranch::Bc1tl { .. })
+    }
+
+    /// Evaluates the branch against the value of FCR31.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::fpu::{FpuBranch, FCR31_C};
+    ///
+    /// let branch = FpuBranch::Bc1tl { offset: 4 };
+    ///
+    /// assert!(branch.evaluate(FCR31_C).taken);
+    /// assert!(!branch.evaluate(0).executes_delay_slot);
+    /// ```
+    pub fn evaluate(self, fcr31: u32) -> BranchOutcome {
+        let taken = (fcr31 & FCR31_C != 0) == self.branches_on();
+        BranchOutcome { taken, executes_delay_slot: taken || !self.is_likely() }
+    }
+
+    /// Returns the address the branch jumps to when taken, from the address of
+    /// the branch.
+    pub fn target(self, pc: u32) -> u32 {
+        pc.wrapping_add(4).wrapping_add(((self.offset() as i32) << 2) as u32)
+    }
+}
+
+impl FpuCompare {
+    /// Returns the condition bit the compare sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::fpu::FpuCompare;
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let (zero, negative_zero) = (Ps2Float::new(0), Ps2Float::new(0x80000000));
+    /// let infinity = Ps2Float::new(0x7F800000);
+    ///
+    /// assert!(FpuCompare::Eq.evaluate(zero, negative_zero));
+    /// assert!(FpuCompare::Lt.evaluate(infinity, Ps2Float::max()));
+    /// ```
+    pub fn evaluate(self, fs: Ps2Float, ft: Ps2Float) -> bool {
+        // Denormalized floats are zero, and both zeros compare equal.
+        let flushed = |value: Ps2Float| match value.exponent() {
+            0 => Ps2Float::new(0),
+            _ => value,
+        };
+        let (fs, ft) = (flushed(fs), flushed(ft));
+        match self {
+            FpuCompare::F => false,
+            FpuCompare::Eq => fs.cmp(&ft).is_eq(),
+            FpuCompare::Lt => fs < ft,
+            FpuCompare::Le => fs <= ft,
+        }
+    }
+}
+
+impl FpuContext {
+    /// Creates a context with FCR31 as after a reset, every flag clear.
+    pub fn new() -> Self {
+        Self { fcr31: FCR31_FIXED }
+    }
+
+    /// Reads a control register, like CFC1.
+    ///
+    /// FCR0 holds the revision and FCR31 the condition bit and the flags, the
+    /// other registers read as zero.
+    pub fn cfc1(&self, fs: u8) -> u32 {
+        match fs {
+            0 => FCR0_REVISION,
+            31 => self.fcr31,
+            _ => 0,
+        }
+    }
+
+    /// Writes a control register, like CTC1.
+    ///
+    /// Only the condition bit and the flags of FCR31 can be written, writes to
+    /// other registers are ignored.
+    pub fn ctc1(&mut self, fs: u8, value: u32) {
+        if fs == 31 {
+            self.fcr31 = (value & FCR31_WRITABLE) | FCR31_FIXED;
+        }
+    }
+
+    /// Returns the condition bit of FCR31.
+    pub fn condition(&self) -> bool {
+        self.fcr31 & FCR31_C != 0
+    }
+
+    /// Sets or clears the condition bit of FCR31.
+    pub fn set_condition(&mut self, condition: bool) {
+        self.fcr31 = (self.fcr31 & !FCR31_C) | if condition { FCR31_C } else { 0 };
+    }
+
+    /// Compares two floats and sets the condition bit to the result, like
+    /// `C.cond.S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::fpu::{FpuBranch, FpuCompare, FpuContext};
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let mut fpu = FpuContext::new();
+    /// fpu.compare(FpuCompare::Le, Ps2Float::new(0x3F800000), Ps2Float::max());
+    ///
+    /// assert!(fpu.condition());
+    /// assert!(fpu.evaluate_branch(FpuBranch::Bc1t { offset: 2 }).taken);
+    /// ```
+    pub fn compare(&mut self, compare: FpuCompare, fs: Ps2Float, ft: Ps2Float) -> bool {
+        let condition = compare.evaluate(fs, ft);
+        self.set_condition(condition);
+        condition
+    }
+
+    /// Evaluates a branch against the condition bit.
+    pub fn evaluate_branch(&self, branch: FpuBranch) -> BranchOutcome {
+        branch.evaluate(self.fcr31)
+    }
+
+    /// Adds two floats, like ADD.S.
+    pub fn add(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.update(a.add_with_flags(b))
+    }
+
+    /// Subtracts two floats, like SUB.S.
+    pub fn sub(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.update(a.sub_with_flags(b))
+    }
+
+    /// Multiplies two floats, like MUL.S.
+    pub fn mul(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.update(a.mul_with_flags(b))
+    }
+
+    /// Multiplies two floats and adds the product to an accumulator, like
+    /// MADD.S.
+    ///
+    /// The product is truncated first like in [`Ps2Float::madd`], and the
+    /// overflow flag is raised if either the product or the sum overflowed,
+    /// like in [`Ps2Accumulator::madda`](crate::Ps2Accumulator::madda).
+    pub fn madd(&mut self, a: Ps2Float, b: Ps2Float, acc: Ps2Float) -> Ps2Float {
+        self.multiply_accumulate(a, b, |product| acc.add_with_flags(product))
+    }
+
+    /// Multiplies two floats and subtracts the product from an accumulator,
+    /// like MSUB.S.
+    ///
+    /// The flags are raised like in [`FpuContext::madd`].
+    pub fn msub(&mut self, a: Ps2Float, b: Ps2Float, acc: Ps2Float) -> Ps2Float {
+        self.multiply_accumulate(a, b, |product| acc.sub_with_flags(product))
+    }
+
+    /// Divides two floats, like DIV.S.
+    ///
+    /// Only the invalid and divide by zero flags are updated.
+    pub fn div(&mut self, dividend: Ps2Float, divisor: Ps2Float) -> Ps2Float {
+        self.update_divide(DivideFlags::of_div(dividend, divisor));
+        dividend.div(divisor)
+    }
+
+    /// Takes the square root of a float, like SQRT.S.
+    ///
+    /// Only the invalid and divide by zero flags are updated.
+    pub fn sqrt(&mut self, value: Ps2Float) -> Ps2Float {
+        self.update_divide(DivideFlags::of_sqrt(value));
+        value.sqrt()
+    }
+
+    /// Divides a float by the square root of another, like RSQRT.S.
+    ///
+    /// Only the invalid and divide by zero flags are updated.
+    pub fn rsqrt(&mut self, dividend: Ps2Float, divisor: Ps2Float) -> Ps2Float {
+        self.update_divide(DivideFlags::of_rsqrt(divisor));
+        dividend.rsqrt(divisor)
+    }
+
+    /// Replaces the overflow and underflow cause flags and sets their sticky
+    /// copies, returning the result.
+    fn update(&mut self, (result, flags): (Ps2Float, OperationFlags)) -> Ps2Float {
+        self.raise(FCR31_O | FCR31_U, [(flags.overflow, FCR31_O), (flags.underflow, FCR31_U)]);
+        result
+    }
+
+    /// Accumulates the product of two floats, merging the overflow of the
+    /// product into the flags of the accumulation.
+    fn multiply_accumulate(
+        &mut self,
+        a: Ps2Float,
+        b: Ps2Float,
+        accumulate: impl FnOnce(Ps2Float) -> (Ps2Float, OperationFlags),
+    ) -> Ps2Float {
+        let (product, product_flags) = a.mul_with_flags(b);
+        let (result, flags) = accumulate(product);
+        let overflow = flags.overflow || product_flags.overflow;
+        self.update((result, OperationFlags { overflow, ..flags }))
+    }
+
+    /// Replaces the invalid and divide by zero cause flags and sets their
+    /// sticky copies.
+    fn update_divide(&mut self, flags: DivideFlags) {
+        self.raise(FCR31_I | FCR31_D, [(flags.invalid, FCR31_I), (flags.divide, FCR31_D)]);
+    }
+
+    /// Clears the cause flags of `causes`, then sets the raised ones and their
+    /// sticky copies, 11 bits below.
+    fn raise(&mut self, causes: u32, raised: [(bool, u32); 2]) {
+        self.fcr31 &= !causes;
+        for (set, cause) in raised {
+            if set {
+                self.fcr31 |= cause | (cause >> 11);
+            }
+        }
+    }
+}
+
+impl Default for FpuContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,82 @@
+//! Structured fuzzing support for PS2 floats.
+//!
+//! Uniformly random bit patterns almost never hit the encodings where the PS2
+//! differs from IEEE 754, so generated values are biased toward them.
+
+use crate::sampling::SPECIAL_ENCODINGS;
+use crate::Ps2Float;
+#[cfg(feature = "arbitrary")]
+use crate::{Ps2Matrix, Ps2Vec4};
+
+/// The odds out of 256 that a generated float is a special encoding.
+const SPECIAL_ENCODING_ODDS: u8 = 64;
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ps2Float {
+    /// Generates a PS2 float, biased toward special encodings.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary::<u8>()? < SPECIAL_ENCODING_ODDS {
+            return Ok(Ps2Float::new(*u.choose(&SPECIAL_ENCODINGS)?));
+        }
+
+        Ok(Ps2Float::new(u.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(5))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ps2Vec4 {
+    /// Generates a vector lane by lane, in `xyzw` order.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Ps2Vec4::from_lanes(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Ps2Float; 4]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ps2Matrix {
+    /// Generates a matrix row by row.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Ps2Matrix(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[Ps2Vec4; 4]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Ps2Float {
+    /// Generates a PS2 float, biased toward special encodings.
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        if u8::arbitrary(g) < SPECIAL_ENCODING_ODDS {
+            return Ps2Float::new(*g.choose(&SPECIAL_ENCODINGS).unwrap());
+        }
+
+        Ps2Float::new(u32::arbitrary(g))
+    }
+
+    /// Shrinks toward the signed zero, then toward the float without its mantissa.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let value = self.as_u32();
+        let mut candidates = Vec::new();
+
+        let zero = Ps2Float::new(value & 0x80000000);
+        if zero != *self {
+            candidates.push(zero);
+        }
+
+        let without_mantissa = Ps2Float::new(value & 0xFF800000);
+        if without_mantissa != *self && without_mantissa != zero {
+            candidates.push(without_mantissa);
+        }
+
+        Box::new(candidates.into_iter())
+    }
+}
@@ -0,0 +1,192 @@
+//! Dataflow graphs of recorded PS2 float operations.
+//!
+//! An [`OpGraph`] exports to Graphviz DOT with the value and flags of every
+//! node, so a numeric divergence can be traced back to the operation it
+//! originated from:
+//!
+//! ```text
+//! dot -Tsvg ops.dot -o ops.svg
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::trace::TraceRecord;
+use crate::{Operation, Ps2Float};
+
+/// The index of a node in an [`OpGraph`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the index of the node, in the order nodes were added.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A node of an [`OpGraph`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// A value entering the computation.
+    Input { value: Ps2Float },
+    /// The result of an operation on two earlier nodes.
+    Operation { operation: Operation, a: NodeId, b: NodeId, value: Ps2Float, flags: u8 },
+}
+
+/// A directed acyclic graph of operations, from inputs to results.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::graph::OpGraph;
+/// use ps2_floating_point::{Operation, Ps2Float};
+///
+/// let mut graph = OpGraph::new();
+/// let one = graph.input(Ps2Float::new(0x3F800000));
+/// let two = graph.apply(Operation::Add, one, one);
+///
+/// assert_eq!(graph.value(two).as_u32(), 0x40000000);
+/// assert!(graph.to_dot().contains("n0 -> n1"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpGraph {
+    nodes: Vec<Node>,
+    highlighted: Vec<bool>,
+}
+
+impl OpGraph {
+    /// Creates a new empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from trace records.
+    ///
+    /// Traces don't record where operands come from, so an operand is linked
+    /// to the latest earlier result with the same bits, or else to an input.
+    /// Records whose replayed result doesn't match are highlighted.
+    pub fn from_trace(records: &[TraceRecord]) -> Self {
+        let mut graph = Self::new();
+        let mut results: HashMap<u32, NodeId> = HashMap::new();
+        let mut inputs: HashMap<u32, NodeId> = HashMap::new();
+
+        for record in records {
+            let mut operand = |graph: &mut Self, value: Ps2Float| {
+                let bits = value.as_u32();
+                match results.get(&bits) {
+                    Some(id) => *id,
+                    None => *inputs.entry(bits).or_insert_with(|| graph.input(value)),
+                }
+            };
+            let a = operand(&mut graph, record.a);
+            let b = operand(&mut graph, record.b);
+
+            let id = graph.record(record.operation, a, b, record.result, record.flags);
+            if record.replay() != record.result {
+                graph.highlight(id);
+            }
+            results.insert(record.result.as_u32(), id);
+        }
+
+        graph
+    }
+
+    /// Adds an input node.
+    pub fn input(&mut self, value: Ps2Float) -> NodeId {
+        self.push(Node::Input { value })
+    }
+
+    /// Runs an operation on two nodes and adds its result as a node.
+    pub fn apply(&mut self, operation: Operation, a: NodeId, b: NodeId) -> NodeId {
+        let value = operation.apply(self.value(a), self.value(b));
+        self.record(operation, a, b, value, 0)
+    }
+
+    /// Adds an operation node with an already known result and flags.
+    pub fn record(
+        &mut self,
+        operation: Operation,
+        a: NodeId,
+        b: NodeId,
+        value: Ps2Float,
+        flags: u8,
+    ) -> NodeId {
+        assert!(a.0 < self.nodes.len() && b.0 < self.nodes.len(), "Operands must exist");
+        self.push(Node::Operation { operation, a, b, value, flags })
+    }
+
+    /// Highlights a node, such as the first one that diverged.
+    pub fn highlight(&mut self, id: NodeId) {
+        self.highlighted[id.0] = true;
+    }
+
+    /// Returns the node with the given id.
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    /// Returns every node, in the order they were added.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the value of the node with the given id.
+    pub fn value(&self, id: NodeId) -> Ps2Float {
+        match self.nodes[id.0] {
+            Node::Input { value } | Node::Operation { value, .. } => value,
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Exports the graph as Graphviz DOT.
+    ///
+    /// Inputs are ellipses and operations are boxes labelled with their index,
+    /// result, and non-zero flags. Highlighted nodes are red.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph ops {\n    node [fontname=\"monospace\"];\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let value = self.value(NodeId(i));
+            let (shape, mut label) = match node {
+                Node::Input { .. } => ("ellipse", "input".to_string()),
+                Node::Operation { operation, .. } => ("box", format!("#{} {}", i, operation)),
+            };
+            write!(label, "\\n0x{:08X} ({})", value.as_u32(), value).unwrap();
+            if let Node::Operation { flags, .. } = node {
+                if *flags != 0 {
+                    write!(label, "\\nflags 0x{:02X}", flags).unwrap();
+                }
+            }
+
+            let color = if self.highlighted[i] { ", color=red, fontcolor=red" } else { "" };
+            writeln!(dot, "    n{} [shape={}, label=\"{}\"{}];", i, shape, label, color).unwrap();
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Node::Operation { a, b, .. } = node {
+                writeln!(dot, "    n{} -> n{} [label=\"a\"];", a.0, i).unwrap();
+                writeln!(dot, "    n{} -> n{} [label=\"b\"];", b.0, i).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Adds a node and returns its id.
+    fn push(&mut self, node: Node) -> NodeId {
+        self.nodes.push(node);
+        self.highlighted.push(false);
+        NodeId(self.nodes.len() - 1)
+    }
+}
@@ -0,0 +1,117 @@
+//! Converting depth values to the GS Z-buffer formats.
+//!
+//! Depth reaches the GS the way the SDK's VU microcode sends it: the float `z`
+//! is converted with an FTOI instruction, the word is packed by the GIF into
+//! XYZ2 or XYZF2, and the GS clamps Z to the largest value of the Z-buffer
+//! format before testing and writing it. The conversions here follow those
+//! steps, so depth precision can be studied and GS dumps compared without an
+//! emulator.
+
+use crate::vector::float_to_fixed;
+use crate::Ps2Float;
+
+/// The format of a GS Z-buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DepthFormat {
+    /// PSMZ32, 32-bit depth.
+    Z32,
+    /// PSMZ24, 24-bit depth.
+    Z24,
+    /// PSMZ16, 16-bit depth.
+    Z16,
+}
+
+/// The GIF PACKED register a vertex is sent through.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VertexRegister {
+    /// XYZ2, taking all 32 bits of the `z` word as Z.
+    Xyz2,
+    /// XYZF2, taking bits 4 to 27 of the `z` word as Z, so the 4 fraction bits
+    /// of FTOI4 are dropped.
+    Xyzf2,
+}
+
+impl DepthFormat {
+    /// Returns the number of bits of a Z value.
+    pub fn bits(self) -> u32 {
+        match self {
+            DepthFormat::Z32 => 32,
+            DepthFormat::Z24 => 24,
+            DepthFormat::Z16 => 16,
+        }
+    }
+
+    /// Returns the largest Z value, which larger values are clamped to.
+    pub fn max_value(self) -> u32 {
+        u32::MAX >> (32 - self.bits())
+    }
+}
+
+impl VertexRegister {
+    /// Returns the Z field the GIF packs from the `z` word of a vertex.
+    pub fn z(self, word: u32) -> u32 {
+        match self {
+            VertexRegister::Xyz2 => word,
+            VertexRegister::Xyzf2 => (word >> 4) & 0xFFFFFF,
+        }
+    }
+}
+
+/// Converts a depth value to the Z a GS Z-buffer stores.
+///
+/// The depth is converted to fixed point like FTOI0/4/12/15, truncating toward
+/// zero and saturating. The GIF sends the bits of the result unchanged, so a
+/// negative depth reaches the GS as a large unsigned Z. The GS then clamps Z
+/// to the largest value of the format.
+///
+/// # Arguments
+///
+/// * `depth` - The `z` of the vertex, already scaled to the depth range.
+/// * `fraction_bits` - The fraction bits of the FTOI instruction converting it.
+/// * `register` - The register the vertex is packed into.
+/// * `format` - The format of the Z-buffer.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::gs::{depth_to_z, DepthFormat, VertexRegister};
+/// use ps2_floating_point::Ps2Float;
+///
+/// let depth = Ps2Float::new(0x4B000001); // 8388609.0
+///
+/// assert_eq!(depth_to_z(depth, 4, VertexRegister::Xyzf2, DepthFormat::Z24), 0x800001);
+/// assert_eq!(depth_to_z(depth, 4, VertexRegister::Xyz2, DepthFormat::Z24), 0xFFFFFF);
+/// ```
+pub fn depth_to_z(
+    depth: Ps2Float,
+    fraction_bits: u8,
+    register: VertexRegister,
+    format: DepthFormat,
+) -> u32 {
+    let word = float_to_fixed(depth, fraction_bits) as u32;
+    register.z(word).min(format.max_value())
+}
+
+/// Converts a depth value to a 24-bit Z, through FTOI0 and XYZ2.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::gs::to_z24;
+/// use ps2_floating_point::Ps2Float;
+///
+/// assert_eq!(to_z24(Ps2Float::new(0x4B7FFFFF)), 0xFFFFFF); // 16777215.0
+/// assert_eq!(to_z24(Ps2Float::new(0x4B800000)), 0xFFFFFF); // Clamped
+/// assert_eq!(to_z24(Ps2Float::new(0x3FFFFFFF)), 1); // Truncated
+/// ```
+pub fn to_z24(depth: Ps2Float) -> u32 {
+    depth_to_z(depth, 0, VertexRegister::Xyz2, DepthFormat::Z24)
+}
+
+/// Converts a depth value to a 32-bit Z, through FTOI0 and XYZ2.
+///
+/// FTOI0 saturates at `0x7FFFFFFF`, so larger Z values can't be reached from
+/// positive depths.
+pub fn to_z32(depth: Ps2Float) -> u32 {
+    depth_to_z(depth, 0, VertexRegister::Xyz2, DepthFormat::Z32)
+}
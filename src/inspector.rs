@@ -0,0 +1,193 @@
+//! egui widgets inspecting and editing PS2 floats and VU registers.
+//!
+//! Emulator frontends can drop these into their debugger windows to get a
+//! PS2-float-aware inspector, where INF and NaN bit patterns are shown as the
+//! big regular numbers the PS2 reads them as.
+
+use egui::{DragValue, Grid, Response, Ui, Widget};
+
+use crate::clip::ClipFlags;
+use crate::vu::{MacFlags, StatusFlags, VuRegister, VuRegisterFile};
+use crate::{Ps2Float, Ps2Vec4};
+
+/// A widget showing the bits, fields, decimal value and classification of a
+/// PS2 float, and editing it through its bits or fields.
+///
+/// The response is marked as changed when the float was edited.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::inspector::Ps2FloatInspector;
+/// use ps2_floating_point::Ps2Float;
+///
+/// fn show(ui: &mut egui::Ui, value: &mut Ps2Float) {
+///     if ui.add(Ps2FloatInspector::new(value)).changed() {
+///         println!("edited to 0x{:08X}", value.as_u32());
+///     }
+/// }
+/// ```
+pub struct Ps2FloatInspector<'a> {
+    value: &'a mut Ps2Float,
+}
+
+/// A widget showing a VU register file as a table, editing the registers in
+/// place.
+///
+/// Vector lanes are edited as hex bits with their decimal value on hover. VF0
+/// is hardwired and R is only shown, like on the hardware.
+///
+/// The response is marked as changed when any register was edited.
+pub struct VuRegisterInspector<'a> {
+    registers: &'a mut VuRegisterFile,
+}
+
+impl<'a> Ps2FloatInspector<'a> {
+    /// Creates a new inspector editing the float.
+    pub fn new(value: &'a mut Ps2Float) -> Self {
+        Self { value }
+    }
+}
+
+impl Widget for Ps2FloatInspector<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let before = *self.value;
+        let mut bits = before.as_u32();
+        let (mut sign, mut exponent, mut mantissa) =
+            (before.sign(), before.exponent(), before.mantissa());
+
+        let mut response = ui
+            .vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.add(hex(&mut bits, 8));
+                    ui.monospace(before.breakdown().decimal());
+                    ui.label(before.classify().description());
+                });
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut sign, "sign");
+                    ui.label("exponent");
+                    ui.add(DragValue::new(&mut exponent));
+                    ui.label("mantissa");
+                    ui.add(hex(&mut mantissa, 6).range(0..=0x7FFFFF));
+                });
+            })
+            .response;
+
+        let edited = if bits != before.as_u32() {
+            Ps2Float::new(bits)
+        } else {
+            Ps2Float::from_params(sign, exponent, mantissa)
+        };
+        if edited != before {
+            *self.value = edited;
+            response.mark_changed();
+        }
+
+        response
+    }
+}
+
+impl<'a> VuRegisterInspector<'a> {
+    /// Creates a new inspector editing the register file.
+    pub fn new(registers: &'a mut VuRegisterFile) -> Self {
+        Self { registers }
+    }
+}
+
+impl Widget for VuRegisterInspector<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let registers = self.registers;
+        let mut changed = false;
+
+        let mut response = Grid::new(ui.next_auto_id())
+            .striped(true)
+            .show(ui, |ui| {
+                for index in 0..32 {
+                    let mut vector = registers.vf(index);
+                    changed |= vector_row(ui, VuRegister::Vf(index as u8), &mut vector, index != 0);
+                    registers.set_vf(index, vector);
+                }
+                changed |= vector_row(ui, VuRegister::Acc, &mut registers.acc, true);
+
+                for (register, value) in [
+                    (VuRegister::Q, &mut registers.q),
+                    (VuRegister::P, &mut registers.p),
+                    (VuRegister::I, &mut registers.i),
+                ] {
+                    ui.monospace(register.to_string());
+                    changed |= float_cell(ui, value, true);
+                    ui.end_row();
+                }
+
+                let mut r = registers.r.rget();
+                ui.monospace(VuRegister::R.to_string());
+                float_cell(ui, &mut r, false);
+                ui.end_row();
+
+                for index in 1..16 {
+                    let mut value = registers.vi(index);
+                    ui.monospace(VuRegister::Vi(index as u8).to_string());
+                    changed |= ui.add(hex(&mut value, 4)).changed();
+                    ui.end_row();
+                    registers.set_vi(index, value);
+                }
+
+                let mut mac = registers.mac_flags.bits();
+                ui.monospace(VuRegister::Mac.to_string());
+                changed |= ui.add(hex(&mut mac, 4)).changed();
+                ui.end_row();
+                registers.mac_flags = MacFlags::from_bits(mac);
+
+                let mut status = registers.status_flags.bits();
+                ui.monospace(VuRegister::Status.to_string());
+                changed |= ui.add(hex(&mut status, 3).range(0..=0xFFF)).changed();
+                ui.end_row();
+                registers.status_flags = StatusFlags::from_bits(status);
+
+                let mut clip = registers.clip_flags.bits();
+                ui.monospace(VuRegister::Clip.to_string());
+                changed |= ui.add(hex(&mut clip, 6).range(0..=0xFFFFFF)).changed();
+                ui.end_row();
+                registers.clip_flags = ClipFlags::from_bits(clip);
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+
+        response
+    }
+}
+
+/// Adds a row editing the lanes of a vector register, returning if it changed.
+fn vector_row(ui: &mut Ui, register: VuRegister, vector: &mut Ps2Vec4, enabled: bool) -> bool {
+    ui.monospace(register.to_string());
+
+    let mut lanes = vector.lanes();
+    let mut changed = false;
+    for lane in &mut lanes {
+        changed |= float_cell(ui, lane, enabled);
+    }
+    ui.end_row();
+
+    *vector = Ps2Vec4::from_lanes(lanes);
+    changed
+}
+
+/// Adds a cell editing the bits of a float, returning if it changed.
+fn float_cell(ui: &mut Ui, value: &mut Ps2Float, enabled: bool) -> bool {
+    let mut bits = value.as_u32();
+    let changed = ui
+        .add_enabled(enabled, hex(&mut bits, 8))
+        .on_hover_text(value.breakdown().decimal())
+        .changed();
+
+    *value = Ps2Float::new(bits);
+    changed
+}
+
+/// Returns a drag value showing an integer as uppercase hex digits.
+fn hex<Num: egui::emath::Numeric>(value: &mut Num, digits: usize) -> DragValue<'_> {
+    DragValue::new(value).hexadecimal(digits, false, true).prefix("0x")
+}
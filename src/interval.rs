@@ -0,0 +1,141 @@
+//! Interval arithmetic bounding the drift between PS2 and IEEE 754 results.
+
+use std::fmt::{Display, Formatter};
+
+use crate::Ps2Float;
+
+/// An interval `[lo, hi]` of PS2 floats propagated through PS2 operations.
+///
+/// Every operation bounds both the PS2 result and the exact result for any
+/// operands inside the input intervals. IEEE 754 results round the exact result
+/// to nearest, so they are bounded as well, and a whole computation can be
+/// bounded without simulating every input.
+///
+/// Results past Fmax saturate to +/- Fmax, where IEEE 754 would overflow to
+/// infinity, so only finite IEEE 754 results are bounded.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{Ps2Float, Ps2Interval};
+///
+/// let one = Ps2Interval::point(Ps2Float::new(0x3F800000));
+/// let tenth = Ps2Interval::point(Ps2Float::new(0x3DCCCCCD));
+///
+/// let difference = one.sub(&tenth);
+///
+/// assert!(difference.contains(0.9));
+/// assert!(difference.contains_f32(1.0f32 - 0.1f32));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2Interval {
+    lo: Ps2Float,
+    hi: Ps2Float,
+}
+
+impl Ps2Interval {
+    /// The number of ULPs every computed bound is moved outward.
+    ///
+    /// Truncating the aligned mantissa and then the result is up to 2 ULPs away
+    /// from the exact result, and rounding to nearest is up to half a ULP away.
+    const OUTWARD_ULPS: i32 = 3;
+
+    /// Creates a new interval between two floats, in either order.
+    pub fn new(a: Ps2Float, b: Ps2Float) -> Self {
+        Self { lo: a.min(b), hi: a.max(b) }
+    }
+
+    /// Creates a new interval containing a single float.
+    pub fn point(value: Ps2Float) -> Self {
+        Self { lo: value, hi: value }
+    }
+
+    /// Returns the lower bound.
+    pub fn lo(&self) -> Ps2Float {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    pub fn hi(&self) -> Ps2Float {
+        self.hi
+    }
+
+    /// Returns the number of ULPs between the bounds.
+    pub fn width_ulps(&self) -> u32 {
+        self.lo.ulp_distance(self.hi)
+    }
+
+    /// Returns if the real number lies inside the interval.
+    pub fn contains(&self, value: f64) -> bool {
+        self.lo.exact_value() <= value && value <= self.hi.exact_value()
+    }
+
+    /// Returns if the IEEE 754 float lies inside the interval.
+    ///
+    /// Infinity and NaN are never contained.
+    pub fn contains_f32(&self, value: f32) -> bool {
+        value.is_finite() && self.contains(value as f64)
+    }
+
+    /// Adds two intervals.
+    pub fn add(&self, addend: &Ps2Interval) -> Self {
+        Self::outward(self.lo.add(addend.lo), self.hi.add(addend.hi))
+    }
+
+    /// Subtracts two intervals.
+    pub fn sub(&self, subtrahend: &Ps2Interval) -> Self {
+        Self::outward(self.lo.sub(subtrahend.hi), self.hi.sub(subtrahend.lo))
+    }
+
+    /// Multiplies two intervals.
+    pub fn mul(&self, factor: &Ps2Interval) -> Self {
+        let products = [
+            self.lo.mul(factor.lo),
+            self.lo.mul(factor.hi),
+            self.hi.mul(factor.lo),
+            self.hi.mul(factor.hi),
+        ];
+
+        Self::outward(*products.iter().min().unwrap(), *products.iter().max().unwrap())
+    }
+
+    /// Divides two intervals.
+    ///
+    /// A divisor containing zero bounds the quotient by -Fmax and Fmax.
+    pub fn div(&self, divisor: &Ps2Interval) -> Self {
+        if divisor.lo.ordinal() <= 0 && divisor.hi.ordinal() >= 0 {
+            return Self { lo: Ps2Float::min(), hi: Ps2Float::max() };
+        }
+
+        let quotients = [
+            self.lo.div(divisor.lo),
+            self.lo.div(divisor.hi),
+            self.hi.div(divisor.lo),
+            self.hi.div(divisor.hi),
+        ];
+
+        Self::outward(*quotients.iter().min().unwrap(), *quotients.iter().max().unwrap())
+    }
+
+    /// Creates an interval with both bounds moved outward by
+    /// [`Ps2Interval::OUTWARD_ULPS`].
+    fn outward(lo: Ps2Float, hi: Ps2Float) -> Self {
+        Self {
+            lo: Ps2Float::from_ordinal(lo.ordinal().saturating_sub(Self::OUTWARD_ULPS)),
+            hi: Ps2Float::from_ordinal(hi.ordinal().saturating_add(Self::OUTWARD_ULPS)),
+        }
+    }
+}
+
+impl From<Ps2Float> for Ps2Interval {
+    fn from(value: Ps2Float) -> Self {
+        Self::point(value)
+    }
+}
+
+impl Display for Ps2Interval {
+    /// Formats the interval as `[lo, hi]`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
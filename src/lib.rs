@@ -3,9 +3,85 @@
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 
+use denormals::DenormalMode;
+use explain::{NormalizationStep, SpecialCase, Trace};
+use rounding::RoundingMode;
+
+mod accumulator;
+pub mod accuracy;
+pub mod analysis;
+pub mod batch;
+mod booth;
+mod breakdown;
+mod checked;
+pub mod clip;
+pub mod convert;
+pub mod denormals;
+mod derivation;
+pub mod divergence;
+mod dual;
+pub mod explain;
+mod float_ops;
+pub mod format;
+pub mod fpu;
+mod interval;
+mod macros;
+pub mod math;
+#[macro_use]
+mod no_panic;
+mod operation;
+mod parse;
+pub mod pcsx2;
+pub mod rounding;
+
+#[cfg(all(feature = "defmt", target_os = "none"))]
+mod defmt_format;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+mod fuzz;
+pub mod graph;
+pub mod gs;
+#[cfg(feature = "egui")]
+pub mod inspector;
+#[cfg(feature = "psp")]
+pub mod psp;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod sampling;
+pub mod savestate;
+pub mod shadow;
+pub mod stress;
+pub mod sum;
+pub mod test_vectors;
+pub mod trace;
+mod vector;
+#[cfg(all(kani, feature = "verification"))]
+mod verification;
+pub mod view;
+pub mod vu;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use accumulator::Ps2Accumulator;
+pub use breakdown::{classify_pair, Abnormal, Breakdown, Classification, PairClass};
+pub use checked::{DivideFlags, OperationFlags, Ps2FloatError};
+pub use dual::Ps2Dual;
+pub use float_ops::FloatOps;
+pub use interval::Ps2Interval;
+#[doc(hidden)]
+pub use macros::assert_ps2_ulp_eq_failed;
+pub use operation::{Operation, ParseOperationError};
+pub use parse::{Notation, ParsePs2FloatError};
+pub use vector::{Ps2Matrix, Ps2Vec4};
+
 /// A floating point number in the PS2's IEEE 754 variant format.
 ///
 /// See: https://www.gregorygaines.com/blog/emulating-ps2-floating-point-nums-ieee-754-diffs-part-1/
+///
+/// The `+`, `-`, `*`, `/` and unary `-` operators, on owned or borrowed
+/// floats, forward to the inherent methods of the same name, so `a + b`
+/// truncates exactly like `a.add(b)`.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct Ps2Float {
     sign: bool,
@@ -30,7 +106,7 @@ impl Ps2Float {
     /// # Returns
     ///
     /// A PS2 IEEE 754 variant float.
-    pub fn new(value: u32) -> Self {
+    pub const fn new(value: u32) -> Self {
         Self {
             sign: ((value >> 31) & 1) != 0,
             exponent: ((value >> 23) & 0xFF) as u8,
@@ -69,6 +145,98 @@ impl Ps2Float {
         Self::new(Self::MIN_FLOATING_POINT_VALUE)
     }
 
+    /// Creates the PS2 float the hardware DIV produces for a ratio of two
+    /// integers, such as an SDK constant like `1/3`.
+    ///
+    /// DIV truncates the quotient toward zero instead of rounding it, so the
+    /// result can be one ULP below the nearest float. Dividing by zero gives
+    /// +/- Fmax like DIV does, with the sign of the operands combined.
+    ///
+    /// # Arguments
+    ///
+    /// * `numerator` - The integer to divide.
+    /// * `denominator` - The integer to divide by.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float of the truncated quotient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// // 1/3 rounds up to 0x3EAAAAAB in IEEE 754 but truncates on the PS2.
+    /// assert_eq!(Ps2Float::from_ratio(1, 3).as_u32(), 0x3EAAAAAA);
+    /// assert_eq!(Ps2Float::from_ratio(-1, 0), Ps2Float::min());
+    /// ```
+    pub fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        let sign = (numerator < 0) != (denominator < 0);
+        let (numerator, denominator) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+
+        if denominator == 0 {
+            return if sign { Self::min() } else { Self::max() };
+        }
+        if numerator == 0 {
+            return Self::from_params(sign, 0, 0);
+        }
+
+        // Scale the numerator so the quotient has 24 or 25 significant bits, then
+        // by one bit less if it has 25. The integer division truncates like DIV.
+        let shift = 24 + numerator.leading_zeros() as i32 - denominator.leading_zeros() as i32;
+        let scale = |shift: i32| {
+            let (n, d) = (numerator as u128, denominator as u128);
+            if shift >= 0 {
+                (n << shift) / d
+            } else {
+                n / (d << -shift)
+            }
+        };
+
+        let mut quotient = scale(shift);
+        let mut exponent = 127 + 23 - shift;
+        if quotient >= 1 << 24 {
+            quotient = scale(shift - 1);
+            exponent += 1;
+        }
+
+        Self::from_params(sign, exponent as u8, quotient as u32 & 0x7FFFFF)
+    }
+
+    /// Creates a PS2 float from a sign, an unbiased exponent and a significand,
+    /// the inverse of [`Ps2Float::to_parts`].
+    ///
+    /// The value is `significand * 2^(exponent - 23)` with the sign applied, and
+    /// significands without the leading bit at bit 23 are normalized.
+    ///
+    /// # Returns
+    ///
+    /// The float, or `None` if the value isn't exactly representable: normalizing
+    /// would drop set bits or the exponent ends up outside -126 to 128.
+    pub fn from_parts_unbiased(sign: bool, exponent: i16, significand: u32) -> Option<Self> {
+        if significand == 0 {
+            return Some(Self::from_params(sign, 0, 0));
+        }
+
+        // Move the leading bit to bit 23, shifting right only if no set bit is lost.
+        let shift = 8 - significand.leading_zeros() as i32;
+        let significand = if shift > 0 {
+            if significand.trailing_zeros() < shift as u32 {
+                return None;
+            }
+            significand >> shift
+        } else {
+            significand << -shift
+        };
+
+        let biased = exponent as i32 + shift + 127;
+        if !(1..=255).contains(&biased) {
+            return None;
+        }
+
+        Some(Self::from_params(sign, biased as u8, significand & 0x7FFFFF))
+    }
+
     /// Returns the decimal representation of `self`.
     pub fn as_u32(&self) -> u32 {
         let mut result = 0u32;
@@ -77,6 +245,110 @@ impl Ps2Float {
         result |= self.mantissa;
         result
     }
+
+    /// Returns the sign bit of `self`, `true` if the float is negative.
+    pub fn sign(&self) -> bool {
+        self.sign
+    }
+
+    /// Returns the biased exponent of `self`.
+    pub fn exponent(&self) -> u8 {
+        self.exponent
+    }
+
+    /// Returns the mantissa of `self` without the implicit leading bit.
+    pub fn mantissa(&self) -> u32 {
+        self.mantissa
+    }
+
+    /// Returns the exponent of `self` without the bias, from -126 to 128 for
+    /// normalized floats and -127 for zero and denormalized floats.
+    pub fn unbiased_exponent(&self) -> i16 {
+        self.exponent as i16 - 127
+    }
+
+    /// Returns the mantissa of `self` with the implicit leading bit, 0 for zero
+    /// and denormalized floats since the PS2 treats them as zero.
+    pub fn significand(&self) -> u32 {
+        if self.is_denormalized() {
+            0
+        } else {
+            self.mantissa | 0x800000
+        }
+    }
+
+    /// Returns the sign, the unbiased exponent and the significand of `self`,
+    /// whose value is `significand * 2^(exponent - 23)` with the sign applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let value = Ps2Float::new(0xC0A00000); // -5.0
+    ///
+    /// assert_eq!(value.to_parts(), (true, 2, 0xA00000));
+    /// assert_eq!(Ps2Float::from_parts_unbiased(true, 2, 0xA00000), Some(value));
+    /// ```
+    pub fn to_parts(&self) -> (bool, i16, u32) {
+        (self.sign, self.unbiased_exponent(), self.significand())
+    }
+
+    /// Returns the exact value of `self`, treating exponent 255 as a regular
+    /// exponent like the PS2 does.
+    pub(crate) fn exact_value(&self) -> f64 {
+        let magnitude = if self.exponent == 0 {
+            self.mantissa as f64 * 2f64.powi(-149)
+        } else {
+            (self.mantissa | 0x800000) as f64 * 2f64.powi(self.exponent as i32 - 150)
+        };
+
+        if self.sign {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns the PS2 float nearest to the value, rounding ties to even.
+    ///
+    /// Values below the normalized range flush to zero and values past Fmax
+    /// saturate to Fmax, keeping their sign. NaN maps to zero.
+    pub(crate) fn nearest(value: f64) -> Ps2Float {
+        let sign = value.is_sign_negative();
+        if value.is_nan() || value == 0.0 {
+            return Self::from_params(sign, 0, 0);
+        }
+        if value.is_infinite() {
+            return if sign { Self::min() } else { Self::max() };
+        }
+
+        let bits = value.to_bits();
+        let mut exponent = ((bits >> 52) & 0x7FF) as i32 - 1023 + 127;
+        let significand = (bits & 0xFFFFFFFFFFFFF) | (1 << 52);
+
+        let mut rounded = significand >> 29;
+        let remainder = significand & 0x1FFFFFFF;
+        if remainder > 0x10000000 || (remainder == 0x10000000 && rounded & 1 == 1) {
+            rounded += 1;
+        }
+        if rounded == 1 << 24 {
+            rounded >>= 1;
+            exponent += 1;
+        }
+
+        if exponent < 1 {
+            Self::from_params(sign, 0, 0)
+        } else if exponent > 255 {
+            if sign {
+                Self::min()
+            } else {
+                Self::max()
+            }
+        } else {
+            Self::from_params(sign, exponent as u8, rounded as u32 & 0x7FFFFF)
+        }
+    }
 }
 
 /// Implementing adding and subtracting arithmetic operations on PS2 floats.
@@ -101,26 +373,40 @@ impl Ps2Float {
     /// # Returns
     ///
     /// A PS2 IEEE 754 variant float representing the sum of the two floats.
-    pub fn add(&self, addend: &Ps2Float) -> Self {
-        // Check if either number is denormalized because denormalized floats don't
-        // exist on the PS2 and truncated to zero during arithmetic operations.
-        if self.is_denormalized() || addend.is_denormalized() {
-            return Self::solve_demoralized_operation(self, addend, /* add= */ true);
-        }
-
-        // Check if abnormal operation between two NaN or Inf number.
-        if self.is_abnormal() && addend.is_abnormal() {
-            return Self::solve_abnormal_addition_or_subtraction_operation(
-                self, addend, /* add= */ true,
-            );
-        }
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn add(self, addend: Ps2Float) -> Self {
+        no_panic!("Ps2Float::add", self.add_traced(&addend, Trace::Off))
+    }
 
-        // Only add floats with the same sign, otherwise subtract.
-        if self.sign != addend.sign {
-            return self.sub(addend);
+    /// Adds two PS2 floats together, recording every step into `trace` if given.
+    // The arithmetic is inlined into the untraced entry points so the trace
+    // pushes, the only panicking paths, are dropped for the `no-panic` feature.
+    #[inline(always)]
+    pub(crate) fn add_traced(&self, addend: &Ps2Float, mut trace: Trace) -> Self {
+        match classify_pair(*self, *addend) {
+            // Denormalized floats don't exist on the PS2 and are truncated to zero
+            // during arithmetic operations.
+            PairClass::Denormalized => {
+                Self::trace_special_case(&mut trace, SpecialCase::DenormalizedOperand);
+                if let Some(result) = self.pass_through_denormals(addend, /* add= */ true) {
+                    return result;
+                }
+                return Self::solve_demoralized_operation(self, addend, /* add= */ true);
+            }
+            // Abnormal operation between two NaN or Inf numbers.
+            PairClass::Abnormal(a, b) => {
+                if let Some(result) = Self::solve_abnormal_addition_or_subtraction_operation(
+                    a, b, /* add= */ true,
+                ) {
+                    Self::trace_abnormal(&mut trace, self.sign == addend.sign);
+                    return result;
+                }
+            }
+            PairClass::Regular => {}
         }
 
-        self.do_add_or_sub(addend, /* add= */ true)
+        // Only add the magnitudes of floats with the same sign, otherwise subtract them.
+        self.do_add_or_sub(addend, /* add= */ self.sign == addend.sign, trace)
     }
 
     /// Subtracts two PS2 floats from each other.
@@ -135,110 +421,96 @@ impl Ps2Float {
     ///
     /// A PS2 IEEE 754 variant float representing the difference between the two
     /// floats.
-    pub fn sub(&self, subtrahend: &Ps2Float) -> Self {
-        // Check if either number is denormalized because denormalized floats don't
-        // exist on the PS2 and truncated to zero during arithmetic operations.
-        if self.is_denormalized() || subtrahend.is_denormalized() {
-            return Self::solve_demoralized_operation(self, subtrahend, /* add= */ false);
-        }
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn sub(self, subtrahend: Ps2Float) -> Self {
+        no_panic!("Ps2Float::sub", self.sub_traced(&subtrahend, Trace::Off))
+    }
 
-        // Check if abnormal operation between two NaN or Inf number.
-        if self.is_abnormal() && subtrahend.is_abnormal() {
-            return Self::solve_abnormal_addition_or_subtraction_operation(
-                self, subtrahend, /* add= */ false,
-            );
+    /// Subtracts two PS2 floats from each other, recording every step into
+    /// `trace` if given.
+    #[inline(always)]
+    pub(crate) fn sub_traced(&self, subtrahend: &Ps2Float, mut trace: Trace) -> Self {
+        match classify_pair(*self, *subtrahend) {
+            // Denormalized floats don't exist on the PS2 and are truncated to zero
+            // during arithmetic operations.
+            PairClass::Denormalized => {
+                Self::trace_special_case(&mut trace, SpecialCase::DenormalizedOperand);
+                if let Some(result) = self.pass_through_denormals(subtrahend, /* add= */ false) {
+                    return result;
+                }
+                return Self::solve_demoralized_operation(self, subtrahend, /* add= */ false);
+            }
+            // Abnormal operation between two NaN or Inf numbers.
+            PairClass::Abnormal(a, b) => {
+                if let Some(result) = Self::solve_abnormal_addition_or_subtraction_operation(
+                    a, b, /* add= */ false,
+                ) {
+                    Self::trace_abnormal(&mut trace, self.sign != subtrahend.sign);
+                    return result;
+                }
+            }
+            PairClass::Regular => {}
         }
 
         // Check if both numbers are equal, if so the result is zero.
         if self.cmp(subtrahend) == Ordering::Equal {
+            Self::trace_special_case(&mut trace, SpecialCase::EqualOperands);
             let mut result = Self::new(0);
             result.sign = Self::determine_subtraction_operation_sign(self, subtrahend);
             return result;
         }
 
-        self.do_add_or_sub(subtrahend, /* add= */ false)
+        // Only subtract the magnitudes of floats with the same sign, otherwise add them.
+        self.do_add_or_sub(subtrahend, /* add= */ self.sign != subtrahend.sign, trace)
     }
 
-    /// Solves an addition or subtraction operation between two abnormal floats.
-    fn solve_abnormal_addition_or_subtraction_operation(
-        a: &Ps2Float,
-        b: &Ps2Float,
-        add: bool,
-    ) -> Ps2Float {
-        let a_val = a.as_u32();
-        let b_val = b.as_u32();
-
-        if a_val == Self::MAX_FLOATING_POINT_VALUE && b_val == Self::MAX_FLOATING_POINT_VALUE {
-            // MAX + MAX = MAX
-            return if add {
-                Self::max()
-            } else {
-                // MAX - MAX = 0
-                Self::default()
-            };
-        }
-
-        if a_val == Self::MIN_FLOATING_POINT_VALUE && b_val == Self::MIN_FLOATING_POINT_VALUE {
-            // -MIN + -MIN = MAX
-            return if add {
-                Self::min()
-            } else {
-                // -MIN - -MIN = 0
-                Self::default()
-            };
-        }
-
-        if a_val == Self::MIN_FLOATING_POINT_VALUE && b_val == Self::MAX_FLOATING_POINT_VALUE {
-            // -MAX + MAX = MAX
-            return if add {
-                Self::max()
-            } else {
-                // -MAX - MAX = MIN
-                Self::min()
-            };
-        }
-
-        if a_val == Self::MAX_FLOATING_POINT_VALUE && b_val == Self::MIN_FLOATING_POINT_VALUE {
-            // MAX + -MAX = 0
-            return if add {
-                Self::default()
-            } else {
-                // MAX - -MAX = MIN
-                Self::max()
-            };
-        }
-
-        if a_val == Self::POSITIVE_INFINITY_VALUE && b_val == Self::POSITIVE_INFINITY_VALUE {
-            // INF + INF = MAX
-            return if add {
-                Self::max()
-            } else {
-                // INF - INF = 0
-                Self::default()
-            };
-        }
-
-        if a_val == Self::NEGATIVE_INFINITY_VALUE && b_val == Self::POSITIVE_INFINITY_VALUE {
-            // -INF + INF = 0
-            return if add {
-                Self::default()
-            } else {
-                // -INF - INF = -MAX
-                Self::min()
-            };
+    /// Records that a special case short-circuited the operation.
+    fn trace_special_case(trace: &mut Trace, special_case: SpecialCase) {
+        if let Trace::Full(trace) = trace {
+            trace.special_case = Some(special_case);
         }
+    }
 
-        if a_val == Self::NEGATIVE_INFINITY_VALUE && b_val == Self::NEGATIVE_INFINITY_VALUE {
-            // -INF + -INF = min
-            return if add {
-                Self::min()
-            } else {
-                // -INF - -INF = 0
-                Self::default()
-            };
+    /// Records that the abnormal table gave the result, which overflowed if
+    /// the magnitudes were added.
+    fn trace_abnormal(trace: &mut Trace, adds_magnitudes: bool) {
+        match trace {
+            Trace::Off => {}
+            Trace::Overflow(overflow) => **overflow = adds_magnitudes,
+            Trace::Full(trace) => trace.special_case = Some(SpecialCase::AbnormalOperands),
         }
+    }
 
-        panic!("Unhandled abnormal floating point operation");
+    /// Solves an addition or subtraction operation between two abnormal floats.
+    ///
+    /// Returns `None` if the combination has no special case, in which case the
+    /// abnormal floats behave like ordinary big numbers.
+    fn solve_abnormal_addition_or_subtraction_operation(
+        a: Abnormal,
+        b: Abnormal,
+        add: bool,
+    ) -> Option<Ps2Float> {
+        use Abnormal::*;
+
+        let (sum, difference) = match (a, b) {
+            // MAX + MAX = MAX, MAX - MAX = 0
+            (Max, Max) => (Self::max(), Self::default()),
+            // -MAX + -MAX = MIN, -MAX - -MAX = 0
+            (Min, Min) => (Self::min(), Self::default()),
+            // -MAX + MAX = MAX, -MAX - MAX = MIN
+            (Min, Max) => (Self::max(), Self::min()),
+            // MAX + -MAX = 0, MAX - -MAX = MAX
+            (Max, Min) => (Self::default(), Self::max()),
+            // INF + INF = MAX, INF - INF = 0
+            (PositiveInfinity, PositiveInfinity) => (Self::max(), Self::default()),
+            // -INF + INF = 0, -INF - INF = MIN
+            (NegativeInfinity, PositiveInfinity) => (Self::default(), Self::min()),
+            // -INF + -INF = MIN, -INF - -INF = 0
+            (NegativeInfinity, NegativeInfinity) => (Self::min(), Self::default()),
+            _ => return None,
+        };
+
+        Some(if add { sum } else { difference })
     }
 
     /// Internal implementation of adding or subtracts two PS2 floats.
@@ -251,12 +523,14 @@ impl Ps2Float {
     /// # Arguments
     ///
     /// * `other` - The other float to add or subtract.
-    /// * `add` - Adds if true, otherwise subtract.
+    /// * `add` - Adds the magnitudes if true, otherwise subtract them.
+    /// * `trace` - Records every step if given.
     ///
     /// # Returns
     ///
     /// A [`Ps2Float`] representing the sum or difference between two floats.
-    fn do_add_or_sub(&self, other: &Ps2Float, add: bool) -> Ps2Float {
+    #[inline(always)]
+    fn do_add_or_sub(&self, other: &Ps2Float, add: bool, mut trace: Trace) -> Ps2Float {
         // Find the absolute value of the exponent difference.
         let exp_diff = self.exponent.abs_diff(other.exponent);
 
@@ -266,60 +540,103 @@ impl Ps2Float {
 
         let mut result = Self::default();
 
-        // Align the exponents.
+        // Align the exponents, mantissas shifted past the register width become zero.
+        let truncated_bits;
         if self.exponent >= other.exponent {
-            other_mantissa = other_mantissa.wrapping_shr(exp_diff as u32);
+            truncated_bits = other_mantissa & Self::shifted_out_mask(exp_diff);
+            other_mantissa = other_mantissa.checked_shr(exp_diff as u32).unwrap_or(0);
             result.exponent = self.exponent;
         } else {
-            self_mantissa = self_mantissa.wrapping_shr(exp_diff as u32);
+            truncated_bits = self_mantissa & Self::shifted_out_mask(exp_diff);
+            self_mantissa = self_mantissa.checked_shr(exp_diff as u32).unwrap_or(0);
             result.exponent = other.exponent;
         }
 
         if add {
-            result.mantissa = self_mantissa.wrapping_add(other_mantissa);
-            // Both numbers have the same sign.
+            result.mantissa = self_mantissa + other_mantissa;
+            // Both magnitudes are added so the sign is kept.
+            result.sign = self.sign;
+        } else if self_mantissa >= other_mantissa {
+            // Subtract the smaller magnitude from the bigger one and take the sign of the
+            // bigger one.
+            result.mantissa = self_mantissa - other_mantissa;
             result.sign = self.sign;
         } else {
-            // Subtract
-            result.mantissa = self_mantissa.wrapping_sub(other_mantissa);
-            // Take the sign of the bigger mantissa.
-            result.sign = Self::determine_subtraction_operation_sign(self, other);
+            result.mantissa = other_mantissa - self_mantissa;
+            result.sign = !self.sign;
+        }
+
+        if let Trace::Full(trace) = &mut trace {
+            trace.magnitude_add = add;
+            trace.alignment_shift = exp_diff as u32;
+            trace.aligned_mantissas = (self_mantissa, other_mantissa);
+            trace.truncated_bits = truncated_bits;
+            trace.raw_mantissa = result.mantissa;
+        }
+
+        // Both magnitudes cancelled each other out.
+        if result.mantissa == 0 {
+            Self::trace_special_case(&mut trace, SpecialCase::Cancellation);
+            return Self::default();
+        }
+
+        if rounding::rounding_mode() == RoundingMode::NearestEven {
+            return self.add_or_sub_nearest_even(other, add, trace);
         }
 
         // Normalize the result if needed.
-        if result.mantissa > 0 {
-            let mut leading_bit_position = Self::get_most_significant_bit_position(result.mantissa);
-            while leading_bit_position != Self::IMPLICIT_LEADING_BIT_POS {
-                match leading_bit_position.cmp(&Self::IMPLICIT_LEADING_BIT_POS) {
-                    Ordering::Greater => {
-                        result.mantissa = result.mantissa.wrapping_shr(1);
-
-                        // Check for exponent overflow, if so return +/- max value depending on the
-                        // sign.
-                        let checked_exponent_increment = result.exponent.checked_add(1);
-                        match checked_exponent_increment {
-                            None => {
-                                return if result.sign { Self::min() } else { Self::max() };
-                            }
-                            Some(res) => result.exponent = res,
+        let mut leading_bit_position = Self::get_most_significant_bit_position(result.mantissa);
+        while leading_bit_position != Self::IMPLICIT_LEADING_BIT_POS {
+            match leading_bit_position.cmp(&Self::IMPLICIT_LEADING_BIT_POS) {
+                Ordering::Greater => {
+                    result.mantissa >>= 1;
+
+                    // Check for exponent overflow, if so return +/- max value depending on the
+                    // sign.
+                    let checked_exponent_increment = result.exponent.checked_add(1);
+                    match checked_exponent_increment {
+                        None => {
+                            Self::trace_normalization(&mut trace, NormalizationStep::Overflow);
+                            return if result.sign { Self::min() } else { Self::max() };
                         }
-                        leading_bit_position -= 1;
+                        Some(res) => result.exponent = res,
                     }
-                    Ordering::Less => {
-                        result.mantissa = result.mantissa.wrapping_shl(1);
-
-                        // Check for exponent underflow, if so the result is a denormalized float
-                        // which doesn't exist so return +/- 0 depending on
-                        // the sign.
-                        let checked_exponent_decrement = result.exponent.checked_sub(1);
-                        match checked_exponent_decrement {
-                            None => return Self::from_params(result.sign, 0, 0),
-                            Some(res) => result.exponent = res,
+                    Self::trace_normalization(
+                        &mut trace,
+                        NormalizationStep::ShiftRight {
+                            mantissa: result.mantissa,
+                            exponent: result.exponent,
+                        },
+                    );
+                    leading_bit_position -= 1;
+                }
+                Ordering::Less => {
+                    result.mantissa <<= 1;
+
+                    // Check for exponent underflow, if so the result is a denormalized float
+                    // which doesn't exist so return +/- 0 depending on
+                    // the sign.
+                    let checked_exponent_decrement = result.exponent.checked_sub(1);
+                    match checked_exponent_decrement {
+                        None | Some(0) => {
+                            Self::trace_normalization(&mut trace, NormalizationStep::Underflow);
+                            if denormals::denormal_mode() == DenormalMode::PassThrough {
+                                return self.add_or_sub_gradual(other, add);
+                            }
+                            return Self::from_params(result.sign, 0, 0);
                         }
-                        leading_bit_position += 1;
+                        Some(res) => result.exponent = res,
                     }
-                    Ordering::Equal => {}
+                    Self::trace_normalization(
+                        &mut trace,
+                        NormalizationStep::ShiftLeft {
+                            mantissa: result.mantissa,
+                            exponent: result.exponent,
+                        },
+                    );
+                    leading_bit_position += 1;
                 }
+                Ordering::Equal => {}
             }
         }
 
@@ -329,19 +646,53 @@ impl Ps2Float {
         result.round_towards_zero()
     }
 
+    /// Adds or subtracts the magnitudes of two PS2 floats like
+    /// [`Ps2Float::do_add_or_sub`], but rounds to nearest even instead of
+    /// truncating.
+    ///
+    /// The exact result of two floats fits in an `f64` unless the smaller one is
+    /// far below the rounding point of the bigger one, so rounding it again
+    /// never lands on the wrong side of a tie.
+    #[inline(always)]
+    fn add_or_sub_nearest_even(&self, other: &Ps2Float, add: bool, mut trace: Trace) -> Ps2Float {
+        let (a, b) = (self.exact_value().abs(), other.exact_value().abs());
+        let magnitude = if add { a + b } else { a - b };
+        let result = Self::nearest(if self.sign { -magnitude } else { magnitude });
+
+        if result.is_zero() {
+            Self::trace_normalization(&mut trace, NormalizationStep::Underflow);
+        } else if result.is_abnormal() && magnitude.abs() > Self::max().exact_value() {
+            Self::trace_normalization(&mut trace, NormalizationStep::Overflow);
+        }
+
+        result
+    }
+
+    /// Records a normalization step of the result mantissa.
+    fn trace_normalization(trace: &mut Trace, step: NormalizationStep) {
+        match trace {
+            Trace::Off => {}
+            Trace::Overflow(overflow) => **overflow |= step == NormalizationStep::Overflow,
+            Trace::Full(trace) => trace.normalization.push(step),
+        }
+    }
+
+    /// Returns the mask of the low bits lost when shifting right by `shift`.
+    fn shifted_out_mask(shift: u8) -> u32 {
+        1u32.checked_shl(shift as u32).map_or(u32::MAX, |bit| bit - 1)
+    }
+
     /// Solves an addition or subtraction operation between two denormalized
     /// floats.
     fn solve_demoralized_operation(a: &Ps2Float, b: &Ps2Float, add: bool) -> Ps2Float {
-        let mut result;
-        if a.is_denormalized() && !b.is_denormalized() {
-            result = *b;
-        } else if !a.is_denormalized() && b.is_denormalized() {
-            result = *a;
-        } else if a.is_denormalized() && b.is_denormalized() {
-            result = Self::default();
+        // At least one float is denormalized, the result is the other one or zero.
+        let mut result = if !a.is_denormalized() {
+            *a
+        } else if !b.is_denormalized() {
+            *b
         } else {
-            panic!("Both numbers are not denormalized");
-        }
+            Self::default()
+        };
 
         if add {
             result.sign = Self::determine_addition_operation_sign(a, b);
@@ -354,14 +705,9 @@ impl Ps2Float {
 
     /// Determines the sign of an addition operation.
     fn determine_addition_operation_sign(a: &Ps2Float, b: &Ps2Float) -> bool {
+        // The sum of two zeros is only negative if both are.
         if a.is_zero() && b.is_zero() {
-            if !a.sign || !b.sign {
-                return false;
-            } else if a.sign && b.sign {
-                return true;
-            } else {
-                panic!("Unhandled addition operation flags");
-            }
+            return a.sign && b.sign;
         }
 
         a.sign
@@ -369,14 +715,9 @@ impl Ps2Float {
 
     /// Determines the sign of an subtraction operation.
     fn determine_subtraction_operation_sign(a: &Ps2Float, b: &Ps2Float) -> bool {
+        // The difference of two zeros is only negative for -0 - +0.
         if a.is_zero() && b.is_zero() {
-            if !a.sign || b.sign {
-                return false;
-            } else if a.sign && !b.sign {
-                return true;
-            } else {
-                panic!("Unhandled subtraction operation flags");
-            }
+            return a.sign && !b.sign;
         }
 
         // Flip the sign of the second number aka Keep change change.
@@ -439,14 +780,626 @@ impl Ps2Float {
     }
 }
 
-/// Implementing multiplying and dividing arithmetic operations on PS2 floats.
+/// Implementing multiplying, dividing and square root arithmetic operations on
+/// PS2 floats.
 impl Ps2Float {
-    pub fn mul(&self, _factor: &Ps2Float) -> Ps2Float {
-        unimplemented!("TODO Add multiplication implementation")
+    /// Multiplies two PS2 floats together.
+    ///
+    /// The mantissas are multiplied by the hardware's Booth multiplier, which
+    /// can land one ULP below the truncated exact product, and the product is
+    /// truncated. Products past Fmax saturate to +/- Fmax and products below
+    /// the normalized range flush to zero. Denormalized operands are treated as
+    /// zero, and the sign of the product is always the XOR of the signs, zeros
+    /// included.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The float to multiply `self` by.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing the product of the two floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let a = Ps2Float::new(0x3F800001); // 1.0000001
+    ///
+    /// // 1 + 2^-22 + 2^-46 truncates to 1 + 2^-22.
+    /// assert_eq!(a.mul(a).as_u32(), 0x3F800002);
+    /// assert_eq!(Ps2Float::max().mul(Ps2Float::new(0xC0000000)), Ps2Float::min());
+    /// ```
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn mul(self, factor: Ps2Float) -> Ps2Float {
+        no_panic!("Ps2Float::mul", self.do_mul(&factor))
     }
 
-    pub fn div(&self, _factor: &Ps2Float) -> Ps2Float {
-        unimplemented!("TODO Add division implementation")
+    #[inline(always)]
+    fn do_mul(&self, factor: &Ps2Float) -> Ps2Float {
+        let sign = self.sign != factor.sign;
+
+        // Denormalized floats don't exist on the PS2 and are truncated to zero.
+        if self.is_denormalized() || factor.is_denormalized() {
+            return Self::from_params(sign, 0, 0);
+        }
+
+        if rounding::rounding_mode() == RoundingMode::NearestEven {
+            // The product of two mantissas is exact in an `f64`.
+            return Self::nearest(self.exact_value() * factor.exact_value());
+        }
+
+        let (exponent, mantissa) = self.truncated_product(factor);
+
+        if exponent > 255 {
+            if sign {
+                Self::min()
+            } else {
+                Self::max()
+            }
+        } else if exponent < 1 {
+            Self::from_params(sign, 0, 0)
+        } else {
+            Self::from_params(sign, exponent as u8, mantissa & 0x7FFFFF)
+        }
+    }
+
+    /// Returns the biased exponent and significand of the product of two
+    /// normalized floats as the Booth multiplier truncates it, before it's
+    /// saturated or flushed.
+    #[inline(always)]
+    fn truncated_product(&self, factor: &Ps2Float) -> (i32, u32) {
+        let mut exponent = self.exponent as i32 + factor.exponent as i32 - 127;
+        let product = booth::mul_mantissa(self.mantissa | 0x800000, factor.mantissa | 0x800000);
+        let mut mantissa = (product >> 23) as u32;
+
+        if mantissa > 0xFFFFFF {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        (exponent, mantissa)
+    }
+
+    /// Divides two PS2 floats.
+    ///
+    /// The quotient is truncated like the hardware divider, agreeing with
+    /// [`Ps2Float::from_ratio`]. There are no exceptions: dividing by zero,
+    /// 0/0 included, gives +/- Fmax, and the VU raises the divide and invalid
+    /// flags for it instead. Quotients past Fmax saturate to +/- Fmax and
+    /// quotients below the normalized range flush to zero. Denormalized
+    /// operands are treated as zero, and the sign of the quotient is always the
+    /// XOR of the signs.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The float to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing the quotient of the two
+    /// floats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let one = Ps2Float::new(0x3F800000);
+    ///
+    /// assert_eq!(one.div(Ps2Float::new(0x40400000)).as_u32(), 0x3EAAAAAA); // 1/3
+    /// assert_eq!(one.div(Ps2Float::new(0x80000000)), Ps2Float::min());
+    /// assert_eq!(Ps2Float::new(0).div(Ps2Float::new(0)), Ps2Float::max());
+    /// ```
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn div(self, divisor: Ps2Float) -> Ps2Float {
+        no_panic!("Ps2Float::div", self.do_div(&divisor))
+    }
+
+    #[inline(always)]
+    fn do_div(&self, divisor: &Ps2Float) -> Ps2Float {
+        let sign = self.sign != divisor.sign;
+
+        // Dividing by zero or a denormalized float, which is zero on the PS2,
+        // saturates.
+        if divisor.is_denormalized() {
+            return if sign { Self::min() } else { Self::max() };
+        }
+        if self.is_denormalized() {
+            return Self::from_params(sign, 0, 0);
+        }
+
+        if rounding::rounding_mode() == RoundingMode::NearestEven {
+            // A quotient of two mantissas is never close enough to a tie for the
+            // `f64` rounding to matter.
+            return Self::nearest(self.exact_value() / divisor.exact_value());
+        }
+
+        let dividend_mantissa = self.mantissa | 0x800000;
+        let divisor_mantissa = divisor.mantissa | 0x800000;
+        let mut exponent = self.exponent as i32 - divisor.exponent as i32 + 127;
+
+        // Scale the dividend so the truncated quotient has 24 bits.
+        let shift = if dividend_mantissa < divisor_mantissa {
+            exponent -= 1;
+            24
+        } else {
+            23
+        };
+        let mantissa = (((dividend_mantissa as u64) << shift) / divisor_mantissa as u64) as u32;
+
+        if exponent > 255 {
+            if sign {
+                Self::min()
+            } else {
+                Self::max()
+            }
+        } else if exponent < 1 {
+            Self::from_params(sign, 0, 0)
+        } else {
+            Self::from_params(sign, exponent as u8, mantissa & 0x7FFFFF)
+        }
+    }
+
+    /// Returns the square root of a PS2 float, like SQRT.S.
+    ///
+    /// Negative floats take the square root of their magnitude, and the VU
+    /// raises the invalid flag for them instead. Zero returns zero with the sign
+    /// of `self`, and denormalized floats are treated as zero. The root is
+    /// truncated rather than rounded to nearest, and always fits in the
+    /// normalized range.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing the square root of the
+    /// magnitude of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0x40000000).sqrt().as_u32(), 0x3FB504F3); // sqrt(2)
+    /// assert_eq!(Ps2Float::new(0xC0800000).sqrt().as_u32(), 0x40000000); // sqrt(|-4|)
+    /// assert_eq!(Ps2Float::new(0x80000000).sqrt().as_u32(), 0x80000000);
+    /// ```
+    pub fn sqrt(self) -> Ps2Float {
+        no_panic!("Ps2Float::sqrt", self.do_sqrt())
+    }
+
+    #[inline(always)]
+    fn do_sqrt(&self) -> Ps2Float {
+        if self.is_denormalized() {
+            return Self::from_params(self.sign, 0, 0);
+        }
+
+        if rounding::rounding_mode() == RoundingMode::NearestEven {
+            // Rounding the `f64` root again never lands on the wrong side of a tie.
+            return Self::nearest(self.exact_value().abs().sqrt());
+        }
+
+        // Make the unbiased exponent even, doubling the mantissa if it's odd, so
+        // halving it is exact.
+        let mut exponent = self.exponent as i32 - 127;
+        let mut mantissa = (self.mantissa | 0x800000) as u64;
+        if exponent & 1 != 0 {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+
+        // The root of a 47 or 48-bit value has the 24 bits of a mantissa.
+        let root = (mantissa << 23).isqrt() as u32;
+        Self::from_params(false, (exponent / 2 + 127) as u8, root & 0x7FFFFF)
+    }
+
+    /// Divides `self` by the square root of a PS2 float, like RSQRT.S.
+    ///
+    /// The square root of the magnitude of `divisor` is taken and truncated
+    /// like [`Ps2Float::sqrt`], then `self` is divided by it and the quotient
+    /// truncated like [`Ps2Float::div`]. A zero or denormalized `divisor` gives
+    /// +/- Fmax with the sign of `self`, and the VU raises the divide flag for
+    /// it and the invalid flag for a negative `divisor` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The float whose square root to divide `self` by.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing `self / sqrt(|divisor|)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let one = Ps2Float::new(0x3F800000);
+    ///
+    /// assert_eq!(one.rsqrt(Ps2Float::new(0xC0800000)).as_u32(), 0x3F000000); // 1/sqrt(|-4|)
+    /// assert_eq!(Ps2Float::new(0xBF800000).rsqrt(Ps2Float::new(0)), Ps2Float::min());
+    /// ```
+    pub fn rsqrt(self, divisor: Ps2Float) -> Ps2Float {
+        self.div(divisor.abs().sqrt())
+    }
+
+    /// Multiplies a PS2 float by `2^n`, like `ldexp`, by moving its exponent.
+    ///
+    /// The mantissa is kept, so the result is the same as multiplying by a
+    /// power of two with [`Ps2Float::mul`] without running the multiplier.
+    /// Results past Fmax saturate to +/- Fmax and results below the normalized
+    /// range flush to zero. Denormalized floats are treated as zero and give
+    /// zero with the sign of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power of two to scale `self` by.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing `self * 2^n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let three = Ps2Float::new(0x40400000);
+    ///
+    /// assert_eq!(three.scale_by_pow2(-2).as_u32(), 0x3F400000); // 0.75
+    /// assert_eq!(three.scale_by_pow2(200), Ps2Float::max());
+    /// assert_eq!(three.scale_by_pow2(-200).as_u32(), 0x00000000);
+    /// ```
+    pub fn scale_by_pow2(self, n: i32) -> Ps2Float {
+        if self.is_denormalized() {
+            return Self::from_params(self.sign, 0, 0);
+        }
+
+        let exponent = self.exponent as i64 + n as i64;
+        if exponent > 255 {
+            if self.sign {
+                Self::min()
+            } else {
+                Self::max()
+            }
+        } else if exponent < 1 {
+            Self::from_params(self.sign, 0, 0)
+        } else {
+            Self::from_params(self.sign, exponent as u8, self.mantissa)
+        }
+    }
+}
+
+/// Implementing the multiply-accumulate operations of the EE FPU.
+impl Ps2Float {
+    /// Multiplies two PS2 floats and adds the product to an accumulator, like
+    /// MADD.S.
+    ///
+    /// The hardware doesn't fuse the operations: the product is truncated like
+    /// [`Ps2Float::mul`], saturating or flushing to zero, and only then added
+    /// to `acc` and truncated again like [`Ps2Float::add`]. Chained transforms
+    /// drift from a fused multiply-add by the bits the first truncation drops.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The float to multiply `self` by.
+    /// * `acc` - The accumulator to add the product to.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing `acc + self * factor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let a = Ps2Float::new(0x3F800001); // 1.0000001
+    /// let acc = Ps2Float::new(0xBF800000); // -1.0
+    ///
+    /// // The 2^-46 of the exact product is gone before the add.
+    /// assert_eq!(a.madd(a, acc).as_u32(), 0x34800000); // 2^-22
+    /// ```
+    pub fn madd(self, factor: Ps2Float, acc: Ps2Float) -> Ps2Float {
+        no_panic!("Ps2Float::madd", acc.add_traced(&self.do_mul(&factor), Trace::Off))
+    }
+
+    /// Multiplies two PS2 floats and subtracts the product from an
+    /// accumulator, like MSUB.S.
+    ///
+    /// The product is truncated before the subtraction like in
+    /// [`Ps2Float::madd`].
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The float to multiply `self` by.
+    /// * `acc` - The accumulator to subtract the product from.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing `acc - self * factor`.
+    pub fn msub(self, factor: Ps2Float, acc: Ps2Float) -> Ps2Float {
+        no_panic!("Ps2Float::msub", acc.sub_traced(&self.do_mul(&factor), Trace::Off))
+    }
+}
+
+/// Implementing the sign operations of the EE FPU.
+impl Ps2Float {
+    /// Returns the float with its sign cleared, like ABS.S.
+    ///
+    /// Only the sign bit changes, so denormalized floats, Fmax and the IEEE 754
+    /// infinity and NaN patterns keep their exponent and mantissa and no flag
+    /// is raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0xBF800000).abs().as_u32(), 0x3F800000);
+    /// assert_eq!(Ps2Float::min().abs(), Ps2Float::max());
+    /// assert_eq!(Ps2Float::new(0x80000001).abs().as_u32(), 0x00000001);
+    /// ```
+    pub fn abs(self) -> Ps2Float {
+        Self::from_params(false, self.exponent, self.mantissa)
+    }
+
+    /// Returns the float with its sign flipped, like NEG.S.
+    ///
+    /// Like [`Ps2Float::abs`], only the sign bit changes and no flag is
+    /// raised, zeros and denormalized floats included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0x3F800000).neg().as_u32(), 0xBF800000);
+    /// assert_eq!(Ps2Float::new(0).neg().as_u32(), 0x80000000);
+    /// assert_eq!(-Ps2Float::max(), Ps2Float::min());
+    /// ```
+    #[allow(clippy::should_implement_trait)] // The operator trait forwards here.
+    pub fn neg(self) -> Ps2Float {
+        Self::from_params(!self.sign, self.exponent, self.mantissa)
+    }
+
+    /// Returns the float with the sign of another float.
+    ///
+    /// Like [`Ps2Float::abs`], only the sign bit changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign` - The float whose sign to take.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let two = Ps2Float::new(0x40000000);
+    ///
+    /// assert_eq!(two.copysign(Ps2Float::new(0x80000000)).as_u32(), 0xC0000000);
+    /// assert_eq!(two.copysign(Ps2Float::new(0x3F800000)), two);
+    /// ```
+    pub fn copysign(self, sign: Ps2Float) -> Ps2Float {
+        Self::from_params(sign.sign, self.exponent, self.mantissa)
+    }
+
+    /// Returns 1.0 with the sign of the float, like [`f32::signum`].
+    ///
+    /// Only the sign bit is read, so zeros and denormalized floats give +/- 1.0
+    /// and there's no NaN to return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// assert_eq!(Ps2Float::new(0xC2C80000).signum().as_u32(), 0xBF800000); // -100
+    /// assert_eq!(Ps2Float::max().signum().as_u32(), 0x3F800000);
+    /// assert_eq!(Ps2Float::new(0x80000000).signum().as_u32(), 0xBF800000);
+    /// ```
+    pub fn signum(self) -> Ps2Float {
+        Self::from_params(self.sign, 127, 0)
+    }
+}
+
+/// Implementing the MAX.S and MIN.S operations of the EE FPU.
+impl Ps2Float {
+    /// Returns the larger of two floats, like MAX.S and the VU's MAX.
+    ///
+    /// The hardware compares the bits as sign-magnitude integers, so Fmax is
+    /// just the largest number, the IEEE 754 infinity and NaN patterns are
+    /// ordinary numbers below it and +0 is larger than -0. No flag is raised.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The float to compare `self` with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let nan = Ps2Float::new(0x7FC00000);
+    ///
+    /// assert_eq!(nan.max_of(Ps2Float::max()), Ps2Float::max());
+    /// assert_eq!(nan.max_of(Ps2Float::new(0x3F800000)), nan);
+    /// assert_eq!(Ps2Float::new(0x80000000).max_of(Ps2Float::new(0)).as_u32(), 0x00000000);
+    /// ```
+    pub fn max_of(self, other: Ps2Float) -> Ps2Float {
+        let (a, b) = (self.as_u32() as i32, other.as_u32() as i32);
+        let max = if a < 0 && b < 0 { a.min(b) } else { a.max(b) };
+        Self::new(max as u32)
+    }
+
+    /// Returns the smaller of two floats, like MIN.S and the VU's MINI.
+    ///
+    /// The floats are compared like in [`Ps2Float::max_of`], so -0 is smaller
+    /// than +0. No flag is raised.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The float to compare `self` with.
+    pub fn min_of(self, other: Ps2Float) -> Ps2Float {
+        let (a, b) = (self.as_u32() as i32, other.as_u32() as i32);
+        let min = if a < 0 && b < 0 { a.max(b) } else { a.min(b) };
+        Self::new(min as u32)
+    }
+}
+
+/// Reporting whether arithmetic operations overflowed.
+impl Ps2Float {
+    /// Adds two PS2 floats together, also returning if the sum overflowed and
+    /// saturated to +/- Fmax.
+    ///
+    /// Only the overflow flag is reported, for callers that don't need the
+    /// other flags of [`Ps2Float::add_explained`]. Sums of two abnormal floats
+    /// come from a table, and overflow when they add the magnitudes, like
+    /// MAX + MAX.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let big = Ps2Float::new(0x7FFFFFFE);
+    ///
+    /// assert_eq!(big.overflowing_add(big), (Ps2Float::max(), true));
+    /// assert_eq!(big.overflowing_add(Ps2Float::new(0x3F800000)), (big, false));
+    /// ```
+    pub fn overflowing_add(self, addend: Ps2Float) -> (Ps2Float, bool) {
+        let mut overflow = false;
+        let result = self.add_traced(&addend, Trace::Overflow(&mut overflow));
+        (result, overflow)
+    }
+
+    /// Subtracts two PS2 floats from each other, also returning if the
+    /// difference overflowed and saturated to +/- Fmax.
+    pub fn overflowing_sub(self, subtrahend: Ps2Float) -> (Ps2Float, bool) {
+        let mut overflow = false;
+        let result = self.sub_traced(&subtrahend, Trace::Overflow(&mut overflow));
+        (result, overflow)
+    }
+
+    /// Multiplies two PS2 floats, also returning if the product overflowed and
+    /// saturated to +/- Fmax.
+    ///
+    /// The product overflows when its exponent exceeds 255, after the Booth
+    /// multiplier's truncation decides whether the significand carried.
+    /// Denormalized operands are zero and never overflow.
+    pub fn overflowing_mul(self, factor: Ps2Float) -> (Ps2Float, bool) {
+        let overflow = self.product_exponent(&factor).is_some_and(|exponent| exponent > 255);
+        (self.mul(factor), overflow)
+    }
+
+    /// Divides two PS2 floats, also returning if the quotient overflowed and
+    /// saturated to +/- Fmax.
+    ///
+    /// The quotient overflows when its exponent exceeds 255. Dividing by zero
+    /// raises the divide flag rather than overflow, so it isn't reported.
+    pub fn overflowing_div(self, divisor: Ps2Float) -> (Ps2Float, bool) {
+        let overflow = self.quotient_exponent(&divisor).is_some_and(|exponent| exponent > 255);
+        (self.div(divisor), overflow)
+    }
+
+    /// Returns the biased exponent of the product before it's saturated or
+    /// flushed, or `None` if an operand is zero or denormalized.
+    fn product_exponent(&self, factor: &Ps2Float) -> Option<i32> {
+        if self.is_denormalized() || factor.is_denormalized() {
+            return None;
+        }
+
+        Some(self.truncated_product(factor).0)
+    }
+
+    /// Returns the biased exponent of the quotient before it's saturated or
+    /// flushed, or `None` if an operand is zero or denormalized.
+    fn quotient_exponent(&self, divisor: &Ps2Float) -> Option<i32> {
+        if self.is_denormalized() || divisor.is_denormalized() {
+            return None;
+        }
+
+        let borrow = (self.significand() < divisor.significand()) as i32;
+        Some(self.exponent as i32 - divisor.exponent as i32 + 127 - borrow)
+    }
+}
+
+/// Walking through the representable PS2 floats in order.
+impl Ps2Float {
+    /// The smallest encoding of a normalized float magnitude.
+    const MIN_NORMALIZED_VALUE: u32 = 0x800000;
+
+    /// Returns the position of `self` among the representable PS2 floats,
+    /// counting from zero.
+    ///
+    /// Denormalized floats don't exist on the PS2, so they share the position of
+    /// zero and the smallest normalized float sits right next to it.
+    pub(crate) fn ordinal(&self) -> i32 {
+        let magnitude = self.as_u32() & 0x7FFFFFFF;
+        let ordinal = magnitude.saturating_sub(Self::MIN_NORMALIZED_VALUE - 1) as i32;
+
+        if self.sign {
+            -ordinal
+        } else {
+            ordinal
+        }
+    }
+
+    /// Returns the representable PS2 float at the given position counting from
+    /// zero, the inverse of [`Ps2Float::ordinal`].
+    ///
+    /// Positions past the ends of the range saturate to +/- MAX.
+    pub(crate) fn from_ordinal(ordinal: i32) -> Ps2Float {
+        let max_ordinal = Self::max().ordinal();
+        let magnitude = ordinal.unsigned_abs().min(max_ordinal as u32);
+        let sign = if ordinal < 0 { 0x80000000 } else { 0 };
+
+        if magnitude == 0 {
+            return Self::new(0);
+        }
+
+        Self::new(sign | (magnitude + Self::MIN_NORMALIZED_VALUE - 1))
+    }
+
+    /// Returns the number of representable PS2 floats between `self` and
+    /// `other`, also known as the distance in units in the last place (ULPs).
+    ///
+    /// Denormalized floats are treated as zero, and +0 and -0 are 0 ULPs apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The float to measure the distance to.
+    ///
+    /// # Returns
+    ///
+    /// The ULP distance between the two floats.
+    pub fn ulp_distance(self, other: Ps2Float) -> u32 {
+        (self.ordinal() as i64 - other.ordinal() as i64).unsigned_abs() as u32
+    }
+
+    /// Returns the representable PS2 float one step from `self` toward
+    /// `target`.
+    ///
+    /// Denormalized floats don't exist on the PS2, so stepping away from zero
+    /// jumps straight to the smallest normalized float and stepping toward zero
+    /// from it lands on +0.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The float to step toward.
+    ///
+    /// # Returns
+    ///
+    /// The next float toward `target`, or `target` if it's 0 ULPs away.
+    pub fn next_toward(self, target: Ps2Float) -> Ps2Float {
+        let (ordinal, target_ordinal) = (self.ordinal(), target.ordinal());
+
+        match ordinal.cmp(&target_ordinal) {
+            Ordering::Less => Self::from_ordinal(ordinal + 1),
+            Ordering::Greater => Self::from_ordinal(ordinal - 1),
+            Ordering::Equal => target,
+        }
     }
 }
 
@@ -504,3 +1457,75 @@ impl Ord for Ps2Float {
         self_two_complement_val.cmp(&other_two_complement_val)
     }
 }
+
+/// Implements an arithmetic operator trait and its assigning counterpart for
+/// every combination of owned and borrowed PS2 floats, forwarding to the
+/// inherent method.
+macro_rules! impl_ps2_float_operator {
+    ($operator:ident, $method:ident, $assign_operator:ident, $assign_method:ident) => {
+        impl std::ops::$operator for Ps2Float {
+            type Output = Ps2Float;
+
+            fn $method(self, rhs: Ps2Float) -> Ps2Float {
+                Ps2Float::$method(self, rhs)
+            }
+        }
+
+        impl std::ops::$operator<&Ps2Float> for Ps2Float {
+            type Output = Ps2Float;
+
+            fn $method(self, rhs: &Ps2Float) -> Ps2Float {
+                Ps2Float::$method(self, *rhs)
+            }
+        }
+
+        impl std::ops::$operator<Ps2Float> for &Ps2Float {
+            type Output = Ps2Float;
+
+            fn $method(self, rhs: Ps2Float) -> Ps2Float {
+                Ps2Float::$method(*self, rhs)
+            }
+        }
+
+        impl std::ops::$operator<&Ps2Float> for &Ps2Float {
+            type Output = Ps2Float;
+
+            fn $method(self, rhs: &Ps2Float) -> Ps2Float {
+                Ps2Float::$method(*self, *rhs)
+            }
+        }
+
+        impl std::ops::$assign_operator for Ps2Float {
+            fn $assign_method(&mut self, rhs: Ps2Float) {
+                *self = Ps2Float::$method(*self, rhs);
+            }
+        }
+
+        impl std::ops::$assign_operator<&Ps2Float> for Ps2Float {
+            fn $assign_method(&mut self, rhs: &Ps2Float) {
+                *self = Ps2Float::$method(*self, *rhs);
+            }
+        }
+    };
+}
+
+impl_ps2_float_operator!(Add, add, AddAssign, add_assign);
+impl_ps2_float_operator!(Sub, sub, SubAssign, sub_assign);
+impl_ps2_float_operator!(Mul, mul, MulAssign, mul_assign);
+impl_ps2_float_operator!(Div, div, DivAssign, div_assign);
+
+impl std::ops::Neg for Ps2Float {
+    type Output = Ps2Float;
+
+    fn neg(self) -> Ps2Float {
+        Ps2Float::neg(self)
+    }
+}
+
+impl std::ops::Neg for &Ps2Float {
+    type Output = Ps2Float;
+
+    fn neg(self) -> Ps2Float {
+        Ps2Float::neg(*self)
+    }
+}
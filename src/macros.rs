@@ -0,0 +1,146 @@
+//! Macros for working with PS2 floats.
+
+use std::fmt::Arguments;
+
+use crate::Ps2Float;
+
+/// Asserts that two PS2 floats are within the given number of ULPs of each
+/// other.
+///
+/// On failure both floats are printed as hex bits and decimal values, along
+/// with their ULP distance. See [`Ps2Float::ulp_distance`] for how the distance
+/// is measured.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{assert_ps2_ulp_eq, Ps2Float};
+///
+/// let a = Ps2Float::new(0x3F800000); // 1.00
+/// let b = Ps2Float::new(0x3F800001); // 1.00 + 1 ULP
+///
+/// assert_ps2_ulp_eq!(a, b, 1);
+/// assert_ps2_ulp_eq!(a, b, 1, "while adding {} and {}", a, b);
+/// ```
+#[macro_export]
+macro_rules! assert_ps2_ulp_eq {
+    ($left:expr, $right:expr, $max_ulps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let max_ulps: u32 = $max_ulps;
+                if left.ulp_distance(*right) > max_ulps {
+                    $crate::assert_ps2_ulp_eq_failed(left, right, max_ulps, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $max_ulps:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let max_ulps: u32 = $max_ulps;
+                if left.ulp_distance(*right) > max_ulps {
+                    $crate::assert_ps2_ulp_eq_failed(
+                        left,
+                        right,
+                        max_ulps,
+                        Some(format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Evaluates an arithmetic expression with PS2 semantics.
+///
+/// The expression is made of `+`, `-`, `*` and `/`, parentheses and operands.
+/// Operators keep Rust's precedence and evaluate left to right, so every
+/// operation rounds exactly where the hardware would, e.g. `a + b + c` is
+/// `(a + b) + c`. Operands are expressions evaluating to [`Ps2Float`], and
+/// number literals which are converted to the nearest `f32` first.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::{ps2_expr, Ps2Float};
+///
+/// let a = Ps2Float::new(0x3F800000); // 1.00
+/// let b = Ps2Float::new(0x3DCCCCCD); // 0.10
+///
+/// let result = ps2_expr!((a + b) - 0.25 + a);
+///
+/// assert_eq!(result, ((a + b) - Ps2Float::new(0x3E800000)) + a);
+/// ```
+#[macro_export]
+macro_rules! ps2_expr {
+    ($($tokens:tt)+) => {
+        $crate::__ps2_expr!(@operand [] [] $($tokens)+)
+    };
+}
+
+/// Splits the tokens of [`ps2_expr!`] into operands and operators, with the
+/// finished tokens in the first brackets and the current operand in the
+/// second.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ps2_expr {
+    (@operand [$($out:tt)*] [$($operand:tt)+]) => {{
+        let value: $crate::Ps2Float = $($out)* ($($operand)+);
+        value
+    }};
+    (@operand [$($out:tt)*] [] - $literal:literal $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)*] [$crate::__ps2_expr!(@literal -$literal)] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [] - $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)*] [-] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [] $literal:literal $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)*] [$crate::__ps2_expr!(@literal $literal)] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [] ($($inner:tt)+) $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)*] [$crate::ps2_expr!($($inner)+)] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [$($operand:tt)+] + $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)* ($($operand)+) +] [] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [$($operand:tt)+] - $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)* ($($operand)+) -] [] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [$($operand:tt)+] * $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)* ($($operand)+) *] [] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [$($operand:tt)+] / $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)* ($($operand)+) /] [] $($rest)*)
+    };
+    (@operand [$($out:tt)*] [$($operand:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__ps2_expr!(@operand [$($out)*] [$($operand)* $token] $($rest)*)
+    };
+    (@literal $($literal:tt)+) => {
+        $crate::Ps2Float::new((($($literal)+) as f32).to_bits())
+    };
+}
+
+/// Panics with the failure message of [`assert_ps2_ulp_eq!`].
+#[track_caller]
+pub fn assert_ps2_ulp_eq_failed(
+    left: &Ps2Float,
+    right: &Ps2Float,
+    max_ulps: u32,
+    args: Option<Arguments<'_>>,
+) -> ! {
+    let message = match args {
+        Some(args) => format!(": {}", args),
+        None => String::new(),
+    };
+
+    panic!(
+        "assertion `left ~= right` failed{}\n  left: 0x{:08X} ({})\n right: 0x{:08X} ({})\n  ulps: {} (max {})",
+        message,
+        left.as_u32(),
+        left,
+        right.as_u32(),
+        right,
+        left.ulp_distance(*right),
+        max_ulps
+    )
+}
@@ -0,0 +1,195 @@
+//! `atanf` and `atan2f` as shipped in the PS2 SDK's fdlibm-derived libm.
+//!
+//! The branches compare raw bits like the C code, so exponent 255 patterns
+//! take the NaN and infinity branches even though the PS2 treats them as
+//! regular numbers.
+
+use super::{horner, magnitude_bits, HALF, ONE};
+use crate::Ps2Float;
+
+const ATAN_HI: [u32; 4] = [
+    0x3EED6338, // 4.6364760399e-01, atan(0.5) hi
+    0x3F490FDA, // 7.8539812565e-01, atan(1.0) hi
+    0x3F7B985E, // 9.8279368877e-01, atan(1.5) hi
+    0x3FC90FDA, // 1.5707962513e+00, atan(inf) hi
+];
+
+const ATAN_LO: [u32; 4] = [
+    0x31AC3769, // 5.0121582440e-09, atan(0.5) lo
+    0x33222168, // 3.7748947079e-08, atan(1.0) lo
+    0x33140FB4, // 3.4473217170e-08, atan(1.5) lo
+    0x33A22168, // 7.5497894159e-08, atan(inf) lo
+];
+
+const AT: [u32; 11] = [
+    0x3EAAAAAB, // 3.3333334327e-01
+    0xBE4CCCCD, // -2.0000000298e-01
+    0x3E124925, // 1.4285714924e-01
+    0xBDE38E38, // -1.1111110449e-01
+    0x3DBA2E6E, // 9.0908870101e-02
+    0xBD9D8795, // -7.6918758452e-02
+    0x3D886B35, // 6.6610731184e-02
+    0xBD6EF16B, // -5.8335702866e-02
+    0x3D4BDA59, // 4.9768779427e-02
+    0xBD15A221, // -3.6531571299e-02
+    0x3C8569D7, // 1.6285819933e-02
+];
+
+const TINY: u32 = 0x0DA24260; // 1.0e-30
+const PI_O_4: u32 = 0x3F490FDB; // 7.8539818525e-01
+const PI_O_2: u32 = 0x3FC90FDB; // 1.5707963705e+00
+const PI: u32 = 0x40490FDB; // 3.1415927410e+00
+const PI_LO: u32 = 0xB3BBBD2E; // -8.7422776573e-08
+const THREE_PI_O_4: u32 = 0x4016CBE4; // 3.0 * PI_O_4, folded by the compiler
+const ONE_AND_HALF: u32 = 0x3FC00000; // 1.5
+const TWO: u32 = 0x40000000; // 2.0
+
+/// The `ix` of infinity, anything above is NaN to the C code.
+const INFINITY_BITS: u32 = 0x7F800000;
+
+/// Computes the arc tangent of `x` like the SDK's `atanf`.
+///
+/// # Arguments
+///
+/// * `x` - The tangent.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `atan(x)` in `[-pi/2, pi/2]`.
+pub fn atanf(x: Ps2Float) -> Ps2Float {
+    let f = Ps2Float::new;
+    let one = f(ONE);
+    let ix = magnitude_bits(x);
+    let apply_sign = |z: Ps2Float| if x.sign() { z.neg() } else { z };
+
+    if ix >= 0x50800000 {
+        // |x| >= 2^34
+        if ix > INFINITY_BITS {
+            return x.add(x);
+        }
+        return apply_sign(f(ATAN_HI[3]).add(f(ATAN_LO[3])));
+    }
+
+    let (id, t) = if ix < 0x3EE00000 {
+        // |x| < 0.4375
+        if ix < 0x31000000 {
+            // |x| < 2^-29
+            return x;
+        }
+        (None, x)
+    } else {
+        let t = f(ix);
+        if ix < 0x3F980000 {
+            // |x| < 1.1875
+            if ix < 0x3F300000 {
+                // 7/16 <= |x| < 11/16
+                (Some(0), f(TWO).mul(t).sub(one).div(f(TWO).add(t)))
+            } else {
+                // 11/16 <= |x| < 19/16
+                (Some(1), t.sub(one).div(t.add(one)))
+            }
+        } else if ix < 0x401C0000 {
+            // |x| < 2.4375
+            (Some(2), t.sub(f(ONE_AND_HALF)).div(one.add(f(ONE_AND_HALF).mul(t))))
+        } else {
+            // 2.4375 <= |x| < 2^34
+            (Some(3), one.neg().div(t))
+        }
+    };
+
+    let z = t.mul(t);
+    let w = z.mul(z);
+    let s1 = z.mul(horner(w, &[AT[0], AT[2], AT[4], AT[6], AT[8], AT[10]]));
+    let s2 = w.mul(horner(w, &[AT[1], AT[3], AT[5], AT[7], AT[9]]));
+
+    match id {
+        None => t.sub(t.mul(s1.add(s2))),
+        Some(id) => {
+            let tail = t.mul(s1.add(s2)).sub(f(ATAN_LO[id])).sub(t);
+            apply_sign(f(ATAN_HI[id]).sub(tail))
+        }
+    }
+}
+
+/// Computes the angle of the point `(x, y)` like the SDK's `atan2f`.
+///
+/// # Arguments
+///
+/// * `y` - The y coordinate.
+/// * `x` - The x coordinate.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `atan2(y, x)` in `[-pi, pi]`.
+pub fn atan2f(y: Ps2Float, x: Ps2Float) -> Ps2Float {
+    let f = Ps2Float::new;
+    let tiny = f(TINY);
+    let ix = magnitude_bits(x);
+    let iy = magnitude_bits(y);
+
+    if ix > INFINITY_BITS || iy > INFINITY_BITS {
+        return x.add(y);
+    }
+    if x.as_u32() == ONE {
+        return atanf(y);
+    }
+
+    // 2 * sign(x) + sign(y)
+    let m = (y.sign() as u8) | ((x.sign() as u8) << 1);
+    let signed = |z: Ps2Float, negative: bool| {
+        if negative {
+            z.neg().sub(tiny)
+        } else {
+            z.add(tiny)
+        }
+    };
+
+    if iy == 0 {
+        return match m {
+            0 | 1 => y,
+            2 => f(PI).add(tiny),
+            _ => f(PI).neg().sub(tiny),
+        };
+    }
+    if ix == 0 {
+        return signed(f(PI_O_2), y.sign());
+    }
+    if ix == INFINITY_BITS {
+        return if iy == INFINITY_BITS {
+            match m {
+                0 => f(PI_O_4).add(tiny),
+                1 => f(PI_O_4).neg().sub(tiny),
+                2 => f(THREE_PI_O_4).add(tiny),
+                _ => f(THREE_PI_O_4).neg().sub(tiny),
+            }
+        } else {
+            match m {
+                0 => f(0),
+                1 => f(0x80000000),
+                2 => f(PI).add(tiny),
+                _ => f(PI).neg().sub(tiny),
+            }
+        };
+    }
+    if iy == INFINITY_BITS {
+        return signed(f(PI_O_2), y.sign());
+    }
+
+    let k = (iy as i32 - ix as i32) >> 23;
+    let z = if k > 60 {
+        // |y / x| > 2^60
+        f(PI_O_2).add(f(HALF).mul(f(PI_LO)))
+    } else if x.sign() && k < -60 {
+        // |y| / x < -2^60
+        f(0)
+    } else {
+        atanf(f(magnitude_bits(y.div(x))))
+    };
+
+    match m {
+        0 => z,
+        1 => z.neg(),
+        2 => f(PI).sub(z.sub(f(PI_LO))),
+        _ => z.sub(f(PI_LO)).sub(f(PI)),
+    }
+}
@@ -0,0 +1,115 @@
+//! Bounding volume tests computed with PS2 float operations.
+//!
+//! Collision code accepts or rejects a pair from truncated distances, so the
+//! decisions near the boundary follow the console, not IEEE 754.
+
+use crate::{Ps2Float, Ps2Vec4};
+
+/// A bounding sphere, the `w` lane of the center is ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sphere {
+    pub center: Ps2Vec4,
+    pub radius: Ps2Float,
+}
+
+/// An axis-aligned bounding box, the `w` lanes are ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Aabb {
+    pub min: Ps2Vec4,
+    pub max: Ps2Vec4,
+}
+
+/// Returns the squared distance between two points, see [`Ps2Vec4::dot3`].
+pub fn distance_squared(a: &Ps2Vec4, b: &Ps2Vec4) -> Ps2Float {
+    let d = a.sub(b);
+    d.dot3(&d)
+}
+
+/// Returns the distance between two points, the square root of
+/// [`distance_squared`].
+pub fn distance(a: &Ps2Vec4, b: &Ps2Vec4) -> Ps2Float {
+    distance_squared(a, b).sqrt()
+}
+
+/// Returns `sqrt(a^2 + b^2)` the way the SDK computes it: both squares, one
+/// add and a SQRT.S, without the scaling IEEE 754 `hypot` does to avoid
+/// overflow, so squares past Fmax saturate like on the console.
+pub fn hypot(a: Ps2Float, b: Ps2Float) -> Ps2Float {
+    a.mul(a).add(b.mul(b)).sqrt()
+}
+
+impl Sphere {
+    /// Creates a new sphere.
+    pub fn new(center: Ps2Vec4, radius: Ps2Float) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns if the point is inside or on the sphere.
+    pub fn contains_point(&self, point: &Ps2Vec4) -> bool {
+        distance_squared(&self.center, point) <= self.radius.mul(self.radius)
+    }
+
+    /// Returns if two spheres overlap or touch, comparing the squared distance
+    /// with the squared sum of the radii.
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        let radii = self.radius.add(other.radius);
+        distance_squared(&self.center, &other.center) <= radii.mul(radii)
+    }
+
+    /// Returns the distance between the surfaces of two spheres, negative if
+    /// they overlap.
+    pub fn distance_to_sphere(&self, other: &Sphere) -> Ps2Float {
+        distance(&self.center, &other.center).sub(self.radius).sub(other.radius)
+    }
+}
+
+impl Aabb {
+    /// Creates a new box from two opposite corners, in either order.
+    pub fn new(a: Ps2Vec4, b: Ps2Vec4) -> Self {
+        let min = Ps2Vec4::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z), a.w.min(b.w));
+        let max = Ps2Vec4::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z), a.w.max(b.w));
+        Self { min, max }
+    }
+
+    /// Returns if the point is inside or on the box.
+    pub fn contains_point(&self, point: &Ps2Vec4) -> bool {
+        (0..3).all(|i| {
+            let (min, max, p) = (self.min.lanes()[i], self.max.lanes()[i], point.lanes()[i]);
+            min <= p && p <= max
+        })
+    }
+
+    /// Returns if two boxes overlap or touch.
+    pub fn intersects_aabb(&self, other: &Aabb) -> bool {
+        (0..3).all(|i| {
+            self.min.lanes()[i] <= other.max.lanes()[i]
+                && other.min.lanes()[i] <= self.max.lanes()[i]
+        })
+    }
+
+    /// Returns the squared distance from the point to the box, zero if inside.
+    ///
+    /// The distance along each axis outside the box is squared and summed as
+    /// `(x + y) + z`.
+    pub fn distance_squared_to_point(&self, point: &Ps2Vec4) -> Ps2Float {
+        let zero = Ps2Float::new(0);
+        let outside = |i: usize| {
+            let (min, max, p) = (self.min.lanes()[i], self.max.lanes()[i], point.lanes()[i]);
+            if p < min {
+                min.sub(p)
+            } else if p > max {
+                p.sub(max)
+            } else {
+                zero
+            }
+        };
+
+        let d = Ps2Vec4::new(outside(0), outside(1), outside(2), zero);
+        d.dot3(&d)
+    }
+
+    /// Returns if the sphere overlaps or touches the box.
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.distance_squared_to_point(&sphere.center) <= sphere.radius.mul(sphere.radius)
+    }
+}
@@ -0,0 +1,286 @@
+//! `expf` and `logf` as shipped in the PS2 SDK's fdlibm-derived libm, the
+//! VU's EEXP approximation, and `powf` composed from them.
+//!
+//! Like the C code, the branches compare raw bits and exponents are adjusted
+//! with integer adds on the bits.
+
+use super::{horner, magnitude_bits, HALF, ONE};
+use crate::Ps2Float;
+
+const TWO: u32 = 0x40000000; // 2.0
+const HUGE: u32 = 0x7149F2CA; // 1.0e+30
+const TWO_M100: u32 = 0x0D800000; // 2^-100
+const LN2_HI: u32 = 0x3F317180; // 6.9313812256e-01
+const LN2_LO: u32 = 0x3717F7D1; // 9.0580006145e-06
+const INV_LN2: u32 = 0x3FB8AA3B; // 1.4426950216e+00
+const TWO25: u32 = 0x4C000000; // 2^25
+const THIRD: u32 = 0x3EAAAAAB; // 0.33333333333333333
+
+const P: [u32; 5] = [
+    0x3E2AAAAB, // 1.6666667163e-01
+    0xBB360B61, // -2.7777778450e-03
+    0x388AB355, // 6.6137559770e-05
+    0xB5DDEA0E, // -1.6533901999e-06
+    0x3331BB4C, // 4.1381369442e-08
+];
+
+const LG: [u32; 7] = [
+    0x3F2AAAAB, // 6.6666668653e-01
+    0x3ECCCCCD, // 4.0000000596e-01
+    0x3E924925, // 2.8571429849e-01
+    0x3E638E29, // 2.2222198546e-01
+    0x3E3A3325, // 1.8183572590e-01
+    0x3E1CD04F, // 1.5313838422e-01
+    0x3E178897, // 1.4798198640e-01
+];
+
+/// The EEXP polynomial coefficients of `x` to `x^6`.
+const EEXP: [u32; 6] = [
+    0x3E7FFFA8, // 0.249998688697815
+    0x3D0007F4, // 0.031257584691048
+    0x3B29D3FF, // 0.002591371303424
+    0x3933E553, // 0.000171562001924
+    0x36B63510, // 0.000005430199963
+    0x353961AC, // 0.000000690600018
+];
+
+/// The `ix` of infinity, anything above is NaN to the C code.
+const INFINITY_BITS: u32 = 0x7F800000;
+/// The bits of the largest `x` whose `exp(x)` doesn't overflow.
+const LOG_MAX_BITS: u32 = 0x42B17217;
+/// The `ix` of the smallest `x` whose `exp(x)` doesn't underflow.
+const LOG_MIN_BITS: u32 = 0x42CFF1B5;
+
+/// Computes `e^x` like the SDK's `expf`.
+///
+/// # Arguments
+///
+/// * `x` - The exponent.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `e^x`.
+pub fn expf(x: Ps2Float) -> Ps2Float {
+    let f = Ps2Float::new;
+    let one = f(ONE);
+    let hx = magnitude_bits(x);
+    let negative = x.sign();
+
+    if hx > INFINITY_BITS {
+        return x.add(x);
+    }
+    if hx == INFINITY_BITS {
+        return if negative { f(0) } else { x };
+    }
+    if !negative && hx > LOG_MAX_BITS {
+        return f(HUGE).mul(f(HUGE));
+    }
+    if negative && hx > LOG_MIN_BITS {
+        return f(TWO_M100).mul(f(TWO_M100));
+    }
+
+    let (k, hi, lo, x) = if hx > 0x3EB17218 {
+        // |x| > 0.5 ln2
+        let (k, hi, lo) = if hx < 0x3F851592 {
+            // |x| < 1.5 ln2
+            if negative {
+                (-1, x.sub(f(LN2_HI).neg()), f(LN2_LO).neg())
+            } else {
+                (1, x.sub(f(LN2_HI)), f(LN2_LO))
+            }
+        } else {
+            let half = if negative { f(HALF).neg() } else { f(HALF) };
+            let k = f(INV_LN2).mul(x).add(half).to_i32_cvtw();
+            let t = Ps2Float::from_i32_cvtsw(k);
+            (k, x.sub(t.mul(f(LN2_HI))), t.mul(f(LN2_LO)))
+        };
+        (k, hi, lo, hi.sub(lo))
+    } else if hx < 0x31800000 {
+        // |x| < 2^-28
+        return one.add(x);
+    } else {
+        (0, f(0), f(0), x)
+    };
+
+    let t = x.mul(x);
+    let c = x.sub(t.mul(horner(t, &P)));
+    if k == 0 {
+        return one.sub(x.mul(c).div(c.sub(f(TWO))).sub(x));
+    }
+
+    let y = one.sub(lo.sub(x.mul(c).div(f(TWO).sub(c))).sub(hi));
+    if k >= -125 {
+        f((y.as_u32() as i32).wrapping_add(k << 23) as u32)
+    } else {
+        f((y.as_u32() as i32).wrapping_add((k + 100) << 23) as u32).mul(f(TWO_M100))
+    }
+}
+
+/// Computes the natural logarithm of `x` like the SDK's `logf`.
+///
+/// # Arguments
+///
+/// * `x` - The operand.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `ln(x)`.
+pub fn logf(x: Ps2Float) -> Ps2Float {
+    let f = Ps2Float::new;
+    let zero = f(0);
+    let ix = magnitude_bits(x);
+
+    if ix == 0 {
+        return f(TWO25).neg().div(zero);
+    }
+    if x.sign() {
+        return x.sub(x).div(zero);
+    }
+    if ix >= INFINITY_BITS {
+        return x.add(x);
+    }
+
+    let (mut k, mut x, mut ix) = (0, x, ix);
+    if ix < 0x00800000 {
+        // Subnormal, scaled by 2^25 which the PS2 flushes to zero
+        k -= 25;
+        x = x.mul(f(TWO25));
+        ix = x.as_u32();
+    }
+
+    k += (ix >> 23) as i32 - 127;
+    let ix = ix & 0x007FFFFF;
+    let i = (ix + (0x95F64 << 3)) & 0x800000;
+    // Normalize x or x / 2
+    let x = f(ix | (i ^ ONE));
+    k += (i >> 23) as i32;
+    // `f` in the C code
+    let frac = x.sub(f(ONE));
+    let ln2 = |dk: Ps2Float| (dk.mul(f(LN2_HI)), dk.mul(f(LN2_LO)));
+
+    if (0x007FFFFF & (15 + ix)) < 16 {
+        // |f| < 2^-20
+        if magnitude_bits(frac) == 0 {
+            if k == 0 {
+                return zero;
+            }
+            let (hi, lo) = ln2(Ps2Float::from_i32_cvtsw(k));
+            return hi.add(lo);
+        }
+
+        let r = frac.mul(frac).mul(f(HALF).sub(f(THIRD).mul(frac)));
+        if k == 0 {
+            return frac.sub(r);
+        }
+        let (hi, lo) = ln2(Ps2Float::from_i32_cvtsw(k));
+        return hi.sub(r.sub(lo).sub(frac));
+    }
+
+    let s = frac.div(f(TWO).add(frac));
+    let dk = Ps2Float::from_i32_cvtsw(k);
+    let z = s.mul(s);
+    let w = z.mul(z);
+    let t1 = w.mul(horner(w, &[LG[1], LG[3], LG[5]]));
+    let t2 = z.mul(horner(w, &[LG[0], LG[2], LG[4], LG[6]]));
+    let i = (ix as i32 - (0x6147A << 3)) | ((0x6B851 << 3) - ix as i32);
+    let r = t2.add(t1);
+
+    if i > 0 {
+        let hfsq = f(HALF).mul(frac).mul(frac);
+        if k == 0 {
+            return frac.sub(hfsq.sub(s.mul(hfsq.add(r))));
+        }
+        let (hi, lo) = ln2(dk);
+        hi.sub(hfsq.sub(s.mul(hfsq.add(r)).add(lo)).sub(frac))
+    } else {
+        if k == 0 {
+            return frac.sub(s.mul(frac.sub(r)));
+        }
+        let (hi, lo) = ln2(dk);
+        hi.sub(s.mul(frac.sub(r)).sub(lo).sub(frac))
+    }
+}
+
+/// Computes `e^-x` like the VU's EEXP instruction.
+///
+/// The polynomial `p = 1 + c1 x + c2 x^2 + ... + c6 x^6` is accumulated term
+/// by term, squared twice, and inverted, in the order PCSX2 models the EFU.
+///
+/// # Arguments
+///
+/// * `x` - The negated exponent.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float approximating `e^-x`.
+pub fn eexp(x: Ps2Float) -> Ps2Float {
+    let one = Ps2Float::new(ONE);
+
+    let mut p = Ps2Float::new(EEXP[0]).mul(x).add(one);
+    let mut power = x;
+    for c in &EEXP[1..] {
+        power = power.mul(x);
+        p = p.add(Ps2Float::new(*c).mul(power));
+    }
+
+    let p = p.mul(p);
+    let p = p.mul(p);
+    one.div(p)
+}
+
+/// Computes `x^y` as `e^(y * ln(x))` with EEXP, with the logarithm from the
+/// SDK's `logf`, the way games compute attenuation curves without a lookup
+/// table.
+///
+/// EEXP is only accurate for arguments in `[0, 1]`, so `y * ln(x)` is split
+/// into `k * ln2 - r` with `r` in `[0, ln2)` like `expf` splits its argument.
+/// The result is `EEXP(r)` with `k` added to its exponent, saturating to Fmax
+/// and flushing to zero past the float range.
+///
+/// Like the C `powf`, `y` of +/- 0 and `x` of 1 give 1 right away. Negative
+/// `x` gives whatever the PS2's division by zero in `logf` leads to.
+///
+/// # Arguments
+///
+/// * `x` - The base.
+/// * `y` - The exponent.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float approximating `x^y`.
+pub fn powf(x: Ps2Float, y: Ps2Float) -> Ps2Float {
+    let f = Ps2Float::new;
+    if magnitude_bits(y) == 0 || x.as_u32() == ONE {
+        return f(ONE);
+    }
+
+    let a = y.mul(logf(x));
+    // Truncated, so one short of `ceil(a / ln2)` when `a` is positive
+    let mut k = f(INV_LN2).mul(a).to_i32_cvtw();
+    if k > 128 {
+        return Ps2Float::max();
+    }
+    if k < -128 {
+        return f(0);
+    }
+
+    let reduce = |k: i32| {
+        let t = Ps2Float::from_i32_cvtsw(k);
+        t.mul(f(LN2_HI)).sub(a).add(t.mul(f(LN2_LO)))
+    };
+    let mut r = reduce(k);
+    if r.sign() && magnitude_bits(r) != 0 {
+        k += 1;
+        r = reduce(k);
+    }
+
+    let p = eexp(r);
+    let exponent = p.exponent() as i32 + k;
+    if exponent > 255 {
+        Ps2Float::max()
+    } else if exponent < 1 {
+        f(0)
+    } else {
+        Ps2Float::from_params(false, exponent as u8, p.mantissa())
+    }
+}
@@ -0,0 +1,41 @@
+//! Reimplementations of SDK and game math routines with PS2 float semantics.
+//!
+//! Each routine runs the same operations in the same order as the code games
+//! ship with, so the results match the console bit for bit rather than being
+//! as accurate as possible.
+
+mod atan;
+mod bounds;
+mod exp;
+mod spline;
+mod sqrt;
+mod transform;
+mod trig;
+mod vu0;
+
+pub use atan::{atan2f, atanf};
+pub use bounds::{distance, distance_squared, hypot, Aabb, Sphere};
+pub use exp::{eexp, expf, logf, powf};
+pub use spline::{bezier, bezier_basis, catmull_rom, catmull_rom_basis};
+pub use sqrt::{rsqrtf, sqrtf};
+pub use transform::rot_trans_pers;
+pub use trig::{cosf, sinf};
+pub use vu0::{vu0_cos, vu0_sin, vu0_sincos};
+
+use crate::Ps2Float;
+
+const ONE: u32 = 0x3F800000; // 1.0
+const HALF: u32 = 0x3F000000; // 0.5
+
+/// Returns the bits of the float without its sign, like the `ix` of the C
+/// routines.
+fn magnitude_bits(value: Ps2Float) -> u32 {
+    value.as_u32() & 0x7FFFFFFF
+}
+
+/// Evaluates `c0 + z * (c1 + z * (... + z * cn))` from the innermost term out.
+fn horner(z: Ps2Float, coefficients: &[u32]) -> Ps2Float {
+    let (last, rest) = coefficients.split_last().unwrap();
+
+    rest.iter().rev().fold(Ps2Float::new(*last), |acc, c| Ps2Float::new(*c).add(z.mul(acc)))
+}
@@ -0,0 +1,76 @@
+//! Cubic spline evaluation the way VU code does it.
+//!
+//! The basis weights are computed by transforming `[t^3, t^2, t, 1]` with the
+//! spline's basis matrix, then the control points are blended with the
+//! weights by a second transform. Both use the MULAx, MADDAy, MADDAz, MADDw
+//! chain of [`Ps2Matrix::transform`], so evaluating per frame accumulates the
+//! same truncation as the console.
+
+use crate::{Ps2Float, Ps2Matrix, Ps2Vec4};
+
+/// The cubic Bezier basis matrix, one row per power of `t` from `t^3`.
+const BEZIER: [[u32; 4]; 4] = [
+    [0xBF800000, 0x40400000, 0xC0400000, 0x3F800000], // -1, 3, -3, 1
+    [0x40400000, 0xC0C00000, 0x40400000, 0x00000000], // 3, -6, 3, 0
+    [0xC0400000, 0x40400000, 0x00000000, 0x00000000], // -3, 3, 0, 0
+    [0x3F800000, 0x00000000, 0x00000000, 0x00000000], // 1, 0, 0, 0
+];
+
+/// The uniform Catmull-Rom basis matrix with the 1/2 folded in, one row per
+/// power of `t` from `t^3`.
+const CATMULL_ROM: [[u32; 4]; 4] = [
+    [0xBF000000, 0x3FC00000, 0xBFC00000, 0x3F000000], // -0.5, 1.5, -1.5, 0.5
+    [0x3F800000, 0xC0200000, 0x40000000, 0xBF000000], // 1, -2.5, 2, -0.5
+    [0xBF000000, 0x00000000, 0x3F000000, 0x00000000], // -0.5, 0, 0.5, 0
+    [0x00000000, 0x3F800000, 0x00000000, 0x00000000], // 0, 1, 0, 0
+];
+
+/// Returns the weights of the four control points of a cubic Bezier curve at
+/// `t`.
+pub fn bezier_basis(t: Ps2Float) -> Ps2Vec4 {
+    basis(&BEZIER, t)
+}
+
+/// Returns the weights of the four control points of a uniform Catmull-Rom
+/// spline at `t`.
+pub fn catmull_rom_basis(t: Ps2Float) -> Ps2Vec4 {
+    basis(&CATMULL_ROM, t)
+}
+
+/// Evaluates a cubic Bezier curve at `t` in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `points` - The four control points.
+/// * `t` - The curve parameter.
+///
+/// # Returns
+///
+/// The point on the curve.
+pub fn bezier(points: &[Ps2Vec4; 4], t: Ps2Float) -> Ps2Vec4 {
+    Ps2Matrix(*points).transform(&bezier_basis(t))
+}
+
+/// Evaluates a uniform Catmull-Rom spline segment at `t` in `[0, 1]`, running
+/// from `points[1]` to `points[2]`.
+///
+/// # Arguments
+///
+/// * `points` - The four control points.
+/// * `t` - The segment parameter.
+///
+/// # Returns
+///
+/// The point on the segment.
+pub fn catmull_rom(points: &[Ps2Vec4; 4], t: Ps2Float) -> Ps2Vec4 {
+    Ps2Matrix(*points).transform(&catmull_rom_basis(t))
+}
+
+/// Transforms `[t^3, t^2, t, 1]` with a basis matrix.
+fn basis(matrix: &[[u32; 4]; 4], t: Ps2Float) -> Ps2Vec4 {
+    let t2 = t.mul(t);
+    let t3 = t2.mul(t);
+    let powers = Ps2Vec4::new(t3, t2, t, Ps2Float::new(super::ONE));
+
+    Ps2Matrix(matrix.map(Ps2Vec4::from_bits)).transform(&powers)
+}
@@ -0,0 +1,57 @@
+//! `sqrtf` and `rsqrtf` as the SDK wraps the SQRT.S and RSQRT.S instructions.
+//!
+//! The instructions take the root of the absolute value of a negative operand.
+//! The C wrappers check the sign first like fdlibm: +/- 0 is returned as-is and
+//! a negative operand returns `(x - x) / (x - x)`, which is Fmax on the PS2
+//! rather than NaN.
+
+use super::{magnitude_bits, ONE};
+use crate::Ps2Float;
+
+/// Computes the square root of `x` like the SDK's `sqrtf`.
+///
+/// Unlike [`Ps2Float::sqrt`], negative operands don't take the root of their
+/// absolute value.
+///
+/// # Arguments
+///
+/// * `x` - The radicand.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `sqrt(x)`.
+pub fn sqrtf(x: Ps2Float) -> Ps2Float {
+    if magnitude_bits(x) == 0 {
+        return x;
+    }
+
+    negative_domain_error(x).unwrap_or_else(|| x.sqrt())
+}
+
+/// Computes the reciprocal square root of `x` like the SDK's `rsqrtf`, a
+/// single RSQRT.S of 1.0 by `x`.
+///
+/// Unlike [`Ps2Float::rsqrt`], negative operands don't take the root of their
+/// absolute value.
+///
+/// # Arguments
+///
+/// * `x` - The radicand.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `1 / sqrt(x)`.
+pub fn rsqrtf(x: Ps2Float) -> Ps2Float {
+    negative_domain_error(x).unwrap_or_else(|| Ps2Float::new(ONE).rsqrt(x))
+}
+
+/// Returns `(x - x) / (x - x)` if `x` is negative and not -0, otherwise `None`
+/// to run the instruction.
+fn negative_domain_error(x: Ps2Float) -> Option<Ps2Float> {
+    if magnitude_bits(x) == 0 || !x.sign() {
+        return None;
+    }
+
+    let zero = x.sub(x);
+    Some(zero.div(zero))
+}
@@ -0,0 +1,36 @@
+//! The RotTransPers vertex transform chain.
+
+use super::ONE;
+use crate::{Ps2Float, Ps2Matrix, Ps2Vec4};
+
+/// Transforms a vertex to GS coordinates like the SDK's RotTransPers:
+/// rotate and translate with the matrix, divide by `w`, and convert to 28.4
+/// fixed point.
+///
+/// The VU runs:
+///
+/// ```text
+/// MULAx.xyzw  ACC, m0, v
+/// MADDAy.xyzw ACC, m1, v
+/// MADDAz.xyzw ACC, m2, v
+/// MADDw.xyzw  p,   m3, v
+/// DIV         Q,   vf0w, pw
+/// MULq.xyz    p,   p,  Q
+/// FTOI4.xyzw  out, p
+/// ```
+///
+/// # Arguments
+///
+/// * `matrix` - The combined world-view-screen matrix.
+/// * `vertex` - The vertex, usually with a `w` of 1.0.
+///
+/// # Returns
+///
+/// The `xyzw` lanes in 28.4 fixed point, where `w` holds the undivided `w`.
+pub fn rot_trans_pers(matrix: &Ps2Matrix, vertex: &Ps2Vec4) -> [i32; 4] {
+    let p = matrix.transform(vertex);
+    let q = Ps2Float::new(ONE).div(p.w);
+    let projected = Ps2Vec4::new(p.x.mul(q), p.y.mul(q), p.z.mul(q), p.w);
+
+    projected.ftoi4()
+}
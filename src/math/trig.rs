@@ -0,0 +1,145 @@
+//! `sinf` and `cosf` as shipped in the PS2 SDK's fdlibm-derived libm.
+//!
+//! The polynomial kernels use fdlibm's single-precision coefficients and
+//! evaluation order. Arguments are reduced with fdlibm's two-constant
+//! Cody-Waite step, which is precise up to about 2^7 * pi/2. Larger arguments
+//! are reduced the same way and lose precision, where fdlibm would switch to
+//! Payne-Hanek reduction.
+
+use super::{horner, magnitude_bits, HALF, ONE};
+use crate::Ps2Float;
+
+const S1: u32 = 0xBE2AAAAB; // -1.6666667163e-01
+const S2: u32 = 0x3C088889; // 8.3333337680e-03
+const S3: u32 = 0xB9500D01; // -1.9841270114e-04
+const S4: u32 = 0x3638EF1B; // 2.7557314297e-06
+const S5: u32 = 0xB2D72F34; // -2.5050759689e-08
+const S6: u32 = 0x2F2EC9D3; // 1.5896910177e-10
+
+const C1: u32 = 0x3D2AAAAB; // 4.1666667908e-02
+const C2: u32 = 0xBAB60B61; // -1.3888889225e-03
+const C3: u32 = 0x37D00D01; // 2.4801587642e-05
+const C4: u32 = 0xB493F27C; // -2.7557314297e-07
+const C5: u32 = 0x310F74F6; // 2.0875723372e-09
+const C6: u32 = 0xAD47D74E; // -1.1359647598e-11
+
+const INV_PIO2: u32 = 0x3F22F984; // 6.3661980629e-01
+const PIO2_1: u32 = 0x3FC90F80; // 1.5707855225e+00, first 17 bits of pi/2
+const PIO2_1T: u32 = 0x37354443; // 1.0804334124e-05, pi/2 - PIO2_1
+
+/// The largest `ix` evaluated without reduction, about pi/4.
+const PIO4_BITS: u32 = 0x3F490FD8;
+/// The `ix` below which `x` is returned as-is, 2^-27.
+const TINY_BITS: u32 = 0x32000000;
+
+/// Computes the sine of `x` in radians like the SDK's `sinf`.
+///
+/// # Arguments
+///
+/// * `x` - The angle in radians.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `sin(x)`.
+pub fn sinf(x: Ps2Float) -> Ps2Float {
+    if magnitude_bits(x) <= PIO4_BITS {
+        return kernel_sin(x, Ps2Float::new(0), false);
+    }
+
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => kernel_sin(y0, y1, true),
+        1 => kernel_cos(y0, y1),
+        2 => kernel_sin(y0, y1, true).neg(),
+        _ => kernel_cos(y0, y1).neg(),
+    }
+}
+
+/// Computes the cosine of `x` in radians like the SDK's `cosf`.
+///
+/// # Arguments
+///
+/// * `x` - The angle in radians.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float representing `cos(x)`.
+pub fn cosf(x: Ps2Float) -> Ps2Float {
+    if magnitude_bits(x) <= PIO4_BITS {
+        return kernel_cos(x, Ps2Float::new(0));
+    }
+
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => kernel_cos(y0, y1),
+        1 => kernel_sin(y0, y1, true).neg(),
+        2 => kernel_cos(y0, y1).neg(),
+        _ => kernel_sin(y0, y1, true),
+    }
+}
+
+/// Reduces `x` to `y0 + y1` in `[-pi/4, pi/4]`, returning the quadrant `n`
+/// with `x = n * pi/2 + y0 + y1`.
+fn rem_pio2(x: Ps2Float) -> (i32, Ps2Float, Ps2Float) {
+    let t = Ps2Float::new(magnitude_bits(x));
+
+    let n = t.mul(Ps2Float::new(INV_PIO2)).add(Ps2Float::new(HALF)).to_i32_cvtw();
+    let f_n = Ps2Float::from_i32_cvtsw(n);
+    let r = t.sub(f_n.mul(Ps2Float::new(PIO2_1)));
+    let w = f_n.mul(Ps2Float::new(PIO2_1T));
+    let y0 = r.sub(w);
+    let y1 = r.sub(y0).sub(w);
+
+    if x.sign() {
+        (-n, y0.neg(), y1.neg())
+    } else {
+        (n, y0, y1)
+    }
+}
+
+/// fdlibm's `__kernel_sinf`, where `y` is the tail of `x` if `has_tail`.
+fn kernel_sin(x: Ps2Float, y: Ps2Float, has_tail: bool) -> Ps2Float {
+    if magnitude_bits(x) < TINY_BITS && x.to_i32_cvtw() == 0 {
+        return x;
+    }
+
+    let z = x.mul(x);
+    let v = z.mul(x);
+    let r = horner(z, &[S2, S3, S4, S5, S6]);
+
+    if !has_tail {
+        x.add(v.mul(Ps2Float::new(S1).add(z.mul(r))))
+    } else {
+        let half_y = Ps2Float::new(HALF).mul(y);
+        let inner = z.mul(half_y.sub(v.mul(r))).sub(y);
+        x.sub(inner.sub(v.mul(Ps2Float::new(S1))))
+    }
+}
+
+/// fdlibm's `__kernel_cosf`, where `y` is the tail of `x`.
+fn kernel_cos(x: Ps2Float, y: Ps2Float) -> Ps2Float {
+    let one = Ps2Float::new(ONE);
+    let ix = magnitude_bits(x);
+    if ix < TINY_BITS && x.to_i32_cvtw() == 0 {
+        return one;
+    }
+
+    let z = x.mul(x);
+    let r = z.mul(horner(z, &[C1, C2, C3, C4, C5, C6]));
+    let half_z = Ps2Float::new(HALF).mul(z);
+    let tail = z.mul(r).sub(x.mul(y));
+
+    if ix < 0x3E99999A {
+        // |x| < 0.3
+        return one.sub(half_z.sub(tail));
+    }
+
+    let qx = if ix > 0x3F480000 {
+        Ps2Float::new(0x3E900000) // 0.28125
+    } else {
+        Ps2Float::new(ix - 0x01000000) // |x| / 4
+    };
+    let hz = half_z.sub(qx);
+    let a = one.sub(qx);
+    a.sub(hz.sub(tail))
+}
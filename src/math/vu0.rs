@@ -0,0 +1,68 @@
+//! The VU0 sine micro-kernel shared by many engines, from the SDK's libvu0.
+//!
+//! The odd powers of the angle are computed across the four lanes of a vector
+//! register, multiplied lane by lane with the coefficients, and summed from
+//! the highest power down. Its results differ from [`super::sinf`], and games
+//! mix both.
+
+use super::magnitude_bits;
+use crate::Ps2Float;
+
+/// The coefficients of `t^9`, `t^7`, `t^5` and `t^3`, in lane order x to w.
+const S5432: [u32; 4] = [
+    0x362E9C14, // 2.6018870e-06
+    0xB94FB21F, // -1.9807414e-04
+    0x3C08873E, // 8.3330255e-03
+    0xBE2AAAA4, // -1.6666657e-01
+];
+
+const PI_O_2: u32 = 0x3FC90FDB; // 1.5707963705e+00
+
+/// Computes the sine of `t` like the VU0 micro-kernel.
+///
+/// The kernel is accurate for `t` in `[-pi/2, pi/2]`, callers reduce the
+/// angle first.
+///
+/// # Arguments
+///
+/// * `t` - The angle in radians.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float approximating `sin(t)`.
+pub fn vu0_sin(t: Ps2Float) -> Ps2Float {
+    let t2 = t.mul(t);
+    let t3 = t2.mul(t);
+    let t5 = t3.mul(t2);
+    let t7 = t5.mul(t2);
+    let t9 = t7.mul(t2);
+
+    // One lane-wise multiply, then the lanes summed from x to w
+    let powers = [t9, t7, t5, t3];
+    let terms = powers.iter().zip(S5432).map(|(power, c)| power.mul(Ps2Float::new(c)));
+
+    terms.fold(Ps2Float::new(0), |acc, term| acc.add(term)).add(t)
+}
+
+/// Computes the cosine of `t` like the VU0 micro-kernel, as the sine of
+/// `pi/2 - |t|`.
+///
+/// The kernel is accurate for `t` in `[-pi, pi]`, callers reduce the angle
+/// first.
+///
+/// # Arguments
+///
+/// * `t` - The angle in radians.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float approximating `cos(t)`.
+pub fn vu0_cos(t: Ps2Float) -> Ps2Float {
+    vu0_sin(Ps2Float::new(PI_O_2).sub(Ps2Float::new(magnitude_bits(t))))
+}
+
+/// Computes the sine and cosine of `t` like the VU0 micro-kernel, see
+/// [`vu0_sin`] and [`vu0_cos`].
+pub fn vu0_sincos(t: Ps2Float) -> (Ps2Float, Ps2Float) {
+    (vu0_sin(t), vu0_cos(t))
+}
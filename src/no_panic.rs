@@ -0,0 +1,38 @@
+//! Link-time enforcement that the arithmetic core can't panic.
+//!
+//! With the `no-panic` feature enabled in optimized builds, every guarded entry
+//! point holds a guard whose destructor only runs while unwinding out of it.
+//! The destructor calls a function that doesn't exist, so if the optimizer
+//! can't prove the entry point never panics, linking fails with an error
+//! naming it. Debug builds skip the check since they keep every panic path.
+
+/// Evaluates `$body` as the body of the entry point `$name`, failing to link
+/// if it may panic.
+macro_rules! no_panic {
+    ($name:literal, $body:expr) => {{
+        #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+        let guard = {
+            extern "C" {
+                #[link_name = concat!("\n\nERROR[ps2_floating_point]: `", $name, "` may panic\n\n")]
+                fn may_panic() -> !;
+            }
+
+            struct Guard;
+
+            impl Drop for Guard {
+                fn drop(&mut self) {
+                    unsafe { may_panic() }
+                }
+            }
+
+            Guard
+        };
+
+        let result = $body;
+
+        #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+        core::mem::forget(guard);
+
+        result
+    }};
+}
@@ -0,0 +1,132 @@
+//! Binary arithmetic operations on PS2 floats.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::Ps2Float;
+
+/// A binary arithmetic operation on PS2 floats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// [`Ps2Float::add`]
+    Add,
+    /// [`Ps2Float::sub`]
+    Sub,
+    /// [`Ps2Float::mul`]
+    Mul,
+    /// [`Ps2Float::div`]
+    Div,
+}
+
+impl Operation {
+    /// All the operations.
+    pub const ALL: [Operation; 4] =
+        [Operation::Add, Operation::Sub, Operation::Mul, Operation::Div];
+
+    /// Applies the operation to the given operands.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left-hand side operand.
+    /// * `b` - The right-hand side operand.
+    ///
+    /// # Returns
+    ///
+    /// A PS2 IEEE 754 variant float representing the result of `a op b`.
+    pub fn apply(&self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        match self {
+            Operation::Add => a.add(b),
+            Operation::Sub => a.sub(b),
+            Operation::Mul => a.mul(b),
+            Operation::Div => a.div(b),
+        }
+    }
+
+    /// Applies the operation to host IEEE 754 floats, the reference the PS2
+    /// results diverge from.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left-hand side operand.
+    /// * `b` - The right-hand side operand.
+    ///
+    /// # Returns
+    ///
+    /// An IEEE 754 float representing the result of `a op b`.
+    pub fn apply_ieee(&self, a: f32, b: f32) -> f32 {
+        match self {
+            Operation::Add => a + b,
+            Operation::Sub => a - b,
+            Operation::Mul => a * b,
+            Operation::Div => a / b,
+        }
+    }
+
+    /// Applies the operation with both PS2 and host IEEE 754 semantics to the
+    /// same operands.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The left-hand side operand.
+    /// * `b` - The right-hand side operand.
+    ///
+    /// # Returns
+    ///
+    /// The results of [`Operation::apply`] and [`Operation::apply_ieee`].
+    pub fn apply_both(&self, a: Ps2Float, b: Ps2Float) -> (Ps2Float, f32) {
+        let ieee = self.apply_ieee(f32::from_bits(a.as_u32()), f32::from_bits(b.as_u32()));
+        (self.apply(a, b), ieee)
+    }
+
+    /// Returns the lowercase name of the operation, e.g. `add`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::Add => "add",
+            Operation::Sub => "sub",
+            Operation::Mul => "mul",
+            Operation::Div => "div",
+        }
+    }
+
+    /// Returns the arithmetic symbol of the operation, e.g. `+`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Sub => "-",
+            Operation::Mul => "*",
+            Operation::Div => "/",
+        }
+    }
+}
+
+impl Display for Operation {
+    /// Formats the operation as its lowercase name.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An error returned when parsing an [`Operation`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOperationError(String);
+
+impl Display for ParseOperationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown operation '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseOperationError {}
+
+impl FromStr for Operation {
+    type Err = ParseOperationError;
+
+    /// Parses an operation from its name or symbol, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operation = s.trim();
+        Operation::ALL
+            .into_iter()
+            .find(|op| op.name().eq_ignore_ascii_case(operation) || op.symbol() == operation)
+            .ok_or_else(|| ParseOperationError(operation.to_string()))
+    }
+}
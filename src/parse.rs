@@ -0,0 +1,171 @@
+//! Parsing PS2 floats from the notations used by debuggers, docs and configs.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::Ps2Float;
+
+/// The notation a float was written in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// The raw bits in hex, like `0x40A9999A`.
+    HexBits,
+    /// A decimal, like `5.3` or `-1e-3`, rounded to the nearest float.
+    Decimal,
+    /// A C99 hex float, like `0x1.5p+2`.
+    HexFloat,
+    /// The sign, exponent and mantissa fields, like `0:0x81:0x29999A` or
+    /// `(0, 129, 0x29999A)`.
+    Fields,
+}
+
+/// An error returned when parsing a [`Ps2Float`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePs2FloatError {
+    input: String,
+    reason: &'static str,
+}
+
+impl Ps2Float {
+    /// Parses a float, detecting which notation it's written in.
+    ///
+    /// The notations are tried from the most specific: fields, then hex floats,
+    /// then hex bits, then decimals. Decimals are rounded to the nearest IEEE
+    /// 754 single like a compiler would, so `5.3` is `0x40A9999A`, while hex
+    /// floats must be exactly representable up to Fmax.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to parse, surrounding whitespace is ignored.
+    ///
+    /// # Returns
+    ///
+    /// The float along with the notation it was written in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::{Notation, Ps2Float};
+    ///
+    /// let value = Ps2Float::new(0x40A9999A);
+    ///
+    /// assert_eq!(Ps2Float::parse_any("0x40A9999A"), Ok((value, Notation::HexBits)));
+    /// assert_eq!(Ps2Float::parse_any("5.3"), Ok((value, Notation::Decimal)));
+    /// assert_eq!(Ps2Float::parse_any("0x1.533334p+2"), Ok((value, Notation::HexFloat)));
+    /// assert_eq!(Ps2Float::parse_any("0:0x81:0x29999A"), Ok((value, Notation::Fields)));
+    /// ```
+    pub fn parse_any(s: &str) -> Result<(Ps2Float, Notation), ParsePs2FloatError> {
+        let s = s.trim();
+        let error = |reason| ParsePs2FloatError { input: s.to_string(), reason };
+
+        if let Some(fields) = split_fields(s) {
+            return parse_fields(fields).map(|value| (value, Notation::Fields)).map_err(error);
+        }
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if let Some(hex) = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+            if hex.contains(['p', 'P']) {
+                let value = parse_hex_float(hex).map_err(error)?;
+                let value = if negative { -value } else { value };
+                return Ok((Ps2Float::nearest(value), Notation::HexFloat));
+            }
+
+            if unsigned.len() != s.len() {
+                return Err(error("hex bits can't have a sign"));
+            }
+            if hex.is_empty() || hex.len() > 8 {
+                return Err(error("hex bits must have 1 to 8 digits"));
+            }
+            return u32::from_str_radix(hex, 16)
+                .map(|bits| (Ps2Float::new(bits), Notation::HexBits))
+                .map_err(|_| error("invalid hex digit"));
+        }
+
+        let value: f32 = s.parse().map_err(|_| error("not a float in any known notation"))?;
+        if value.is_infinite() && !unsigned.to_ascii_lowercase().starts_with("inf") {
+            // Past the range of an IEEE 754 single, but maybe not of a PS2 float
+            let value: f64 = s.parse().map_err(|_| error("decimal out of range"))?;
+            return Ok((Ps2Float::nearest(value), Notation::Decimal));
+        }
+
+        Ok((Ps2Float::new(value.to_bits()), Notation::Decimal))
+    }
+}
+
+/// Splits the three fields of the fields notation, or returns `None` if the
+/// string isn't in it.
+fn split_fields(s: &str) -> Option<Vec<&str>> {
+    let inner = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')).unwrap_or(s);
+    let fields: Vec<&str> = inner
+        .split(|c: char| c == ':' || c == ',' || c.is_whitespace())
+        .filter(|field| !field.is_empty())
+        .collect();
+
+    (fields.len() == 3).then_some(fields)
+}
+
+/// Parses the sign, exponent and mantissa fields, each decimal or hex.
+fn parse_fields(fields: Vec<&str>) -> Result<Ps2Float, &'static str> {
+    let mut values = [0u32; 3];
+    for (value, field) in values.iter_mut().zip(fields) {
+        let parsed = match field.strip_prefix("0x").or_else(|| field.strip_prefix("0X")) {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => field.parse(),
+        };
+        *value = parsed.map_err(|_| "fields must be unsigned integers")?;
+    }
+
+    let [sign, exponent, mantissa] = values;
+    if sign > 1 || exponent > 0xFF || mantissa > 0x7FFFFF {
+        return Err("field out of range");
+    }
+
+    Ok(Ps2Float::from_params(sign == 1, exponent as u8, mantissa))
+}
+
+/// Parses the digits of a hex float after `0x`, like `1.5p+2`.
+///
+/// The significand must fit in 53 bits so the value is exact.
+fn parse_hex_float(s: &str) -> Result<f64, &'static str> {
+    let (significand, exponent) = s.split_once(['p', 'P']).ok_or("missing binary exponent")?;
+    let mut exponent: i32 = exponent.parse().map_err(|_| "invalid binary exponent")?;
+
+    let (integer, fraction) = significand.split_once('.').unwrap_or((significand, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err("missing hex digits");
+    }
+
+    let mut bits: u64 = 0;
+    for (i, digit) in integer.chars().chain(fraction.chars()).enumerate() {
+        let digit = digit.to_digit(16).ok_or("invalid hex digit")?;
+        if i >= integer.len() {
+            exponent = exponent.checked_sub(4).ok_or("binary exponent out of range")?;
+        }
+        bits = bits * 16 + digit as u64;
+        if bits >= 1 << 53 {
+            return Err("too many significant hex digits");
+        }
+    }
+
+    Ok(bits as f64 * 2f64.powi(exponent.clamp(-1100, 1100)))
+}
+
+impl FromStr for Ps2Float {
+    type Err = ParsePs2FloatError;
+
+    /// Parses a float in any notation, see [`Ps2Float::parse_any`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ps2Float::parse_any(s).map(|(value, _)| value)
+    }
+}
+
+impl Display for ParsePs2FloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid float '{}': {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParsePs2FloatError {}
@@ -0,0 +1,146 @@
+//! A compatibility shim mirroring PCSX2's `PS2Float` class.
+//!
+//! The method names and signatures follow the C++ class, so its unit tests and
+//! call sites port over with minimal edits:
+//!
+//! ```text
+//! PS2Float(0x3F800000).Add(PS2Float::One()).AsUInt32()   // C++
+//! PS2Float::new(0x3F800000).Add(PS2Float::One()).AsUInt32()   // Rust
+//! ```
+//!
+//! Prefer [`Ps2Float`] in new code.
+
+#![allow(non_snake_case)]
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+use crate::Ps2Float;
+
+/// PCSX2's `PS2Float` class, backed by [`Ps2Float`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct PS2Float {
+    pub Sign: bool,
+    pub Exponent: u8,
+    pub Mantissa: u32,
+}
+
+impl PS2Float {
+    pub const BIAS: u8 = 127;
+    pub const SIGNMASK: u32 = 0x80000000;
+    pub const MAX_FLOATING_POINT_VALUE: u32 = 0x7FFFFFFF;
+    pub const MIN_FLOATING_POINT_VALUE: u32 = 0xFFFFFFFF;
+    pub const POSITIVE_INFINITY_VALUE: u32 = 0x7F800000;
+    pub const NEGATIVE_INFINITY_VALUE: u32 = 0xFF800000;
+    pub const ONE: u32 = 0x3F800000;
+    pub const MIN_ONE: u32 = 0xBF800000;
+    pub const IMPLICIT_LEADING_BIT_POS: i32 = 23;
+
+    /// Mirrors `PS2Float(u32 value)`.
+    pub fn new(value: u32) -> Self {
+        Ps2Float::new(value).into()
+    }
+
+    /// Mirrors `PS2Float(bool sign, u8 exponent, u32 mantissa)`.
+    pub fn from_params(sign: bool, exponent: u8, mantissa: u32) -> Self {
+        Self { Sign: sign, Exponent: exponent, Mantissa: mantissa }
+    }
+
+    pub fn Max() -> Self {
+        Self::new(Self::MAX_FLOATING_POINT_VALUE)
+    }
+
+    pub fn Min() -> Self {
+        Self::new(Self::MIN_FLOATING_POINT_VALUE)
+    }
+
+    pub fn One() -> Self {
+        Self::new(Self::ONE)
+    }
+
+    pub fn MinOne() -> Self {
+        Self::new(Self::MIN_ONE)
+    }
+
+    pub fn AsUInt32(&self) -> u32 {
+        Ps2Float::from(*self).as_u32()
+    }
+
+    pub fn Add(&self, addend: PS2Float) -> PS2Float {
+        Ps2Float::from(*self).add(addend.into()).into()
+    }
+
+    pub fn Sub(&self, subtrahend: PS2Float) -> PS2Float {
+        Ps2Float::from(*self).sub(subtrahend.into()).into()
+    }
+
+    pub fn Mul(&self, mulend: PS2Float) -> PS2Float {
+        Ps2Float::from(*self).mul(mulend.into()).into()
+    }
+
+    pub fn Div(&self, divend: PS2Float) -> PS2Float {
+        Ps2Float::from(*self).div(divend.into()).into()
+    }
+
+    pub fn IsDenormalized(&self) -> bool {
+        self.Exponent == 0
+    }
+
+    pub fn IsZero(&self) -> bool {
+        self.Abs() == 0
+    }
+
+    /// Returns the bits without the sign, like the C++ class.
+    pub fn Abs(&self) -> u32 {
+        self.AsUInt32() & 0x7FFFFFFF
+    }
+
+    pub fn Negate(&self) -> PS2Float {
+        Self::new(self.AsUInt32() ^ Self::SIGNMASK)
+    }
+
+    /// Compares the signed values, returning -1, 0 or 1.
+    pub fn CompareTo(&self, other: PS2Float) -> i32 {
+        ordering_to_i32(Ps2Float::from(*self).cmp(&other.into()))
+    }
+
+    /// Compares the magnitudes, returning -1, 0 or 1.
+    pub fn CompareOperands(&self, other: PS2Float) -> i32 {
+        ordering_to_i32(self.Abs().cmp(&other.Abs()))
+    }
+
+    /// Returns the exact value, treating exponent 255 as a regular exponent.
+    pub fn ToDouble(&self) -> f64 {
+        Ps2Float::from(*self).exact_value()
+    }
+
+    pub fn ToString(&self) -> String {
+        Ps2Float::from(*self).to_string()
+    }
+}
+
+fn ordering_to_i32(ordering: Ordering) -> i32 {
+    match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+impl From<Ps2Float> for PS2Float {
+    fn from(value: Ps2Float) -> Self {
+        Self::from_params(value.sign(), value.exponent(), value.mantissa())
+    }
+}
+
+impl From<PS2Float> for Ps2Float {
+    fn from(value: PS2Float) -> Self {
+        Ps2Float::from_params(value.Sign, value.Exponent, value.Mantissa)
+    }
+}
+
+impl Display for PS2Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Ps2Float::from(*self))
+    }
+}
@@ -0,0 +1,165 @@
+//! The PSP's Allegrex FPU float variant.
+//!
+//! The Allegrex FPU follows IEEE 754 single precision except that it flushes
+//! denormalized operands and results to zero, keeping their sign. Infinities
+//! and NaNs behave like IEEE 754, unlike the PS2 where exponent 255 is a
+//! regular exponent, though NaN results carry whatever payload the host FPU
+//! produces. Implementing [`FloatOps`] lets code written for PS2 floats run
+//! with PSP semantics too.
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::FloatOps;
+
+/// A floating point number in the PSP's flush-to-zero IEEE 754 variant.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::psp::PspFloat;
+///
+/// let tiny = PspFloat::new(0x00800000); // The smallest normalized float
+/// let half = PspFloat::new(0x3F000000);
+///
+/// // IEEE 754 gives the denormalized 0x00400000, the PSP flushes it to zero.
+/// assert_eq!((tiny * half).as_u32(), 0x00000000);
+/// assert_eq!((PspFloat::new(0x7F800000) + half).as_u32(), 0x7F800000);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PspFloat(u32);
+
+impl PspFloat {
+    /// Creates a new PSP float from its bits.
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// Returns the bits of `self`.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the host float with the same value, denormalized floats being
+    /// zero.
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits(Self::flush(self.0))
+    }
+
+    /// Returns `self + other`.
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn add(self, other: PspFloat) -> PspFloat {
+        Self::from_result(self.to_f32() + other.to_f32())
+    }
+
+    /// Returns `self - other`.
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn sub(self, other: PspFloat) -> PspFloat {
+        Self::from_result(self.to_f32() - other.to_f32())
+    }
+
+    /// Returns `self * other`.
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn mul(self, other: PspFloat) -> PspFloat {
+        Self::from_result(self.to_f32() * other.to_f32())
+    }
+
+    /// Returns `self / other`.
+    #[allow(clippy::should_implement_trait)] // The operator traits forward here.
+    pub fn div(self, other: PspFloat) -> PspFloat {
+        Self::from_result(self.to_f32() / other.to_f32())
+    }
+
+    /// Returns the square root of `self`.
+    pub fn sqrt(self) -> PspFloat {
+        Self::from_result(self.to_f32().sqrt())
+    }
+
+    /// Returns the PSP float of a host result, flushing it if denormalized.
+    fn from_result(result: f32) -> PspFloat {
+        Self(Self::flush(result.to_bits()))
+    }
+
+    /// Replaces denormalized bits with zero of the same sign.
+    fn flush(bits: u32) -> u32 {
+        if bits & 0x7F800000 == 0 {
+            bits & 0x80000000
+        } else {
+            bits
+        }
+    }
+}
+
+impl FloatOps for PspFloat {
+    const ZERO: Self = PspFloat::new(0);
+    const ONE: Self = PspFloat::new(0x3F800000);
+    const MAX: Self = PspFloat::new(0x7F7FFFFF);
+    const MIN: Self = PspFloat::new(0xFF7FFFFF);
+
+    fn from_bits(bits: u32) -> Self {
+        PspFloat::new(bits)
+    }
+
+    fn to_bits(self) -> u32 {
+        self.as_u32()
+    }
+
+    fn add(self, other: Self) -> Self {
+        PspFloat::add(self, other)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        PspFloat::sub(self, other)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        PspFloat::mul(self, other)
+    }
+
+    fn div(self, other: Self) -> Self {
+        PspFloat::div(self, other)
+    }
+
+    fn sqrt(self) -> Self {
+        PspFloat::sqrt(self)
+    }
+}
+
+impl Add for PspFloat {
+    type Output = PspFloat;
+
+    fn add(self, other: PspFloat) -> PspFloat {
+        PspFloat::add(self, other)
+    }
+}
+
+impl Sub for PspFloat {
+    type Output = PspFloat;
+
+    fn sub(self, other: PspFloat) -> PspFloat {
+        PspFloat::sub(self, other)
+    }
+}
+
+impl Mul for PspFloat {
+    type Output = PspFloat;
+
+    fn mul(self, other: PspFloat) -> PspFloat {
+        PspFloat::mul(self, other)
+    }
+}
+
+impl Div for PspFloat {
+    type Output = PspFloat;
+
+    fn div(self, other: PspFloat) -> PspFloat {
+        PspFloat::div(self, other)
+    }
+}
+
+impl Display for PspFloat {
+    /// Formats the value like the host float it equals.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.to_f32(), f)
+    }
+}
@@ -0,0 +1,91 @@
+//! Random sampling of PS2 floats with the `rand` crate.
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::sampling::edge_case_from_bits;
+use crate::Ps2Float;
+
+/// Samples PS2 floats uniformly over all 32-bit patterns, including
+/// denormalized and abnormal encodings.
+impl Distribution<Ps2Float> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ps2Float {
+        Ps2Float::new(rng.gen())
+    }
+}
+
+/// A distribution sampling PS2 floats uniformly over `[0, 1)`.
+///
+/// Values are multiples of 2^-24, so the result is never denormalized.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UnitInterval;
+
+impl Distribution<Ps2Float> for UnitInterval {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ps2Float {
+        let value: f32 = rng.gen();
+        Ps2Float::new(value.to_bits())
+    }
+}
+
+/// A distribution sampling PS2 floats biased toward edge cases.
+///
+/// See [`edge_case_from_bits`] for the odds of each kind of edge case.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct EdgeCases;
+
+impl Distribution<Ps2Float> for EdgeCases {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ps2Float {
+        edge_case_from_bits(rng.gen())
+    }
+}
+
+/// Samples PS2 floats uniformly among the representable values in a range.
+///
+/// Every representable float in the range is equally likely, so each exponent
+/// band is sampled as densely as every other. This makes it suited to probe a
+/// specific band, unlike sampling uniformly over the real numbers in the range.
+/// Denormalized floats aren't representable on the PS2 and are never sampled.
+///
+/// Use it through [`rand::distributions::Uniform`] or [`Rng::gen_range`].
+///
+/// # Panics
+///
+/// Like the samplers of `rand`, panics if the range is empty. Both zeros and
+/// every denormalized float share the position of `+0`, so a half-open range
+/// between them, such as `Uniform::new(-0.0, +0.0)`, is empty. An inclusive
+/// range between them only samples `+0`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UniformPs2Float {
+    ordinals: UniformInt<i32>,
+}
+
+impl UniformSampler for UniformPs2Float {
+    type X = Ps2Float;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self { ordinals: UniformInt::new(low.borrow().ordinal(), high.borrow().ordinal()) }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            ordinals: UniformInt::new_inclusive(low.borrow().ordinal(), high.borrow().ordinal()),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Ps2Float::from_ordinal(self.ordinals.sample(rng))
+    }
+}
+
+impl SampleUniform for Ps2Float {
+    type Sampler = UniformPs2Float;
+}
@@ -0,0 +1,64 @@
+//! Experimental rounding modes for the arithmetic.
+//!
+//! The PS2 truncates every result toward zero. Rounding to nearest even
+//! instead, while keeping every other PS2 behavior such as saturating to Fmax
+//! and flushing denormals, isolates how much of a divergence from IEEE 754 is
+//! caused by the truncation alone.
+//!
+//! The mode is set per thread, so a whole pipeline built on the crate can be
+//! rerun with another mode without threading a setting through it.
+
+use std::cell::Cell;
+
+/// How the arithmetic rounds results that don't fit in the mantissa.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Truncate toward zero like the hardware.
+    #[default]
+    Truncate,
+    /// Round to the nearest float, ties to even, like IEEE 754 by default.
+    NearestEven,
+}
+
+thread_local! {
+    static ROUNDING_MODE: Cell<RoundingMode> = const { Cell::new(RoundingMode::Truncate) };
+}
+
+/// Restores the previous rounding mode when dropped.
+struct RestoreRoundingMode(RoundingMode);
+
+impl Drop for RestoreRoundingMode {
+    fn drop(&mut self) {
+        ROUNDING_MODE.with(|mode| mode.set(self.0));
+    }
+}
+
+/// Returns the rounding mode of the current thread, [`RoundingMode::Truncate`]
+/// unless changed by [`with_rounding_mode`].
+///
+/// Returns the default mode while the thread is being torn down.
+pub fn rounding_mode() -> RoundingMode {
+    ROUNDING_MODE.try_with(Cell::get).unwrap_or_default()
+}
+
+/// Runs `f` with the rounding mode of the current thread set to `mode`,
+/// restoring the previous mode afterwards even if `f` panics.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::rounding::{with_rounding_mode, RoundingMode};
+/// use ps2_floating_point::Ps2Float;
+///
+/// let a = Ps2Float::new(0x3F800000); // 1.0
+/// let b = Ps2Float::new(0x3DCCCCCD); // 0.1
+///
+/// assert_eq!(a.sub(b).as_u32(), 0x3F666668);
+/// let rounded = with_rounding_mode(RoundingMode::NearestEven, || a.sub(b));
+/// assert_eq!(rounded.as_u32(), 0x3F666666);
+/// ```
+pub fn with_rounding_mode<T>(mode: RoundingMode, f: impl FnOnce() -> T) -> T {
+    let _restore = RestoreRoundingMode(rounding_mode());
+    ROUNDING_MODE.with(|current| current.set(mode));
+    f()
+}
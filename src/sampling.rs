@@ -0,0 +1,118 @@
+//! Sampling PS2 floats biased toward edge cases.
+//!
+//! Uniformly random bit patterns essentially never hit the encodings where the
+//! PS2 differs from IEEE 754, such as exponents 0 and 0xFF, all-ones mantissas,
+//! or values one ULP from Fmax. The samplers here favor those encodings while
+//! still covering every bit pattern.
+
+use crate::Ps2Float;
+
+/// Encodings where PS2 floats behave differently from IEEE 754 floats or sit
+/// on the edge of a special case.
+pub(crate) const SPECIAL_ENCODINGS: [u32; 16] = [
+    0x00000000, // 0.00
+    0x80000000, // -0.00
+    0x00000001, // Smallest denormalized
+    0x807FFFFF, // Biggest negative denormalized
+    0x00800000, // Smallest normalized
+    0x80800000, // Smallest negative normalized
+    0x3F800000, // 1.00
+    0xBF800000, // -1.00
+    0x7F7FFFFF, // Biggest IEEE 754 float
+    0xFF7FFFFF, // Smallest IEEE 754 float
+    0x7F800000, // INF
+    0xFF800000, // -INF
+    0x7FFFFFFE, // One ULP below MAX
+    0xFFFFFFFE, // One ULP above -MAX
+    0x7FFFFFFF, // MAX
+    0xFFFFFFFF, // -MAX
+];
+
+/// Exponents on the edges of the normalized range.
+const EDGE_EXPONENTS: [u8; 4] = [0x00, 0x01, 0xFE, 0xFF];
+
+/// Mantissas on the edges of the mantissa range.
+const EDGE_MANTISSAS: [u32; 4] = [0x000000, 0x000001, 0x7FFFFE, 0x7FFFFF];
+
+/// Picks a PS2 float from 64 random bits, biased toward edge cases.
+///
+/// A quarter of the floats are special encodings such as +/- 0, INF or MAX and
+/// their direct neighbours, three eighths have an edge exponent and/or an edge
+/// mantissa, and the rest are the plain low 32 bits.
+///
+/// # Arguments
+///
+/// * `bits` - Uniformly random bits.
+///
+/// # Returns
+///
+/// A PS2 IEEE 754 variant float.
+pub fn edge_case_from_bits(bits: u64) -> Ps2Float {
+    let pattern = Ps2Float::new(bits as u32);
+    let selector = (bits >> 32) & 0xF;
+    let choice = (bits >> 36) as usize;
+
+    match selector {
+        0..=3 => Ps2Float::new(SPECIAL_ENCODINGS[choice % SPECIAL_ENCODINGS.len()]),
+        4..=9 => {
+            let sign = pattern.as_u32() >> 31 != 0;
+            let mut exponent = ((pattern.as_u32() >> 23) & 0xFF) as u8;
+            let mut mantissa = pattern.as_u32() & 0x7FFFFF;
+
+            // At least one of the fields is replaced by an edge value.
+            let replace_exponent = choice & 0b01 != 0 || choice & 0b10 == 0;
+            let replace_mantissa = choice & 0b10 != 0;
+            if replace_exponent {
+                exponent = EDGE_EXPONENTS[(choice >> 2) % EDGE_EXPONENTS.len()];
+            }
+            if replace_mantissa {
+                mantissa = EDGE_MANTISSAS[(choice >> 4) % EDGE_MANTISSAS.len()];
+            }
+
+            Ps2Float::from_params(sign, exponent, mantissa)
+        }
+        _ => pattern,
+    }
+}
+
+/// A seedable generator of PS2 floats biased toward edge cases.
+///
+/// The same seed always produces the same sequence of floats, on every
+/// platform and crate version, so failing inputs can be replayed from the seed.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::sampling::EdgeCaseGenerator;
+///
+/// let operands: Vec<_> = EdgeCaseGenerator::new(42).take(4).collect();
+/// assert_eq!(operands, EdgeCaseGenerator::new(42).take(4).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeCaseGenerator {
+    state: u64,
+}
+
+impl EdgeCaseGenerator {
+    /// Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next 64 random bits using the SplitMix64 algorithm.
+    pub fn next_bits(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Iterator for EdgeCaseGenerator {
+    type Item = Ps2Float;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(edge_case_from_bits(self.next_bits()))
+    }
+}
@@ -0,0 +1,188 @@
+//! Reading and writing the FPU and VU register blocks of PCSX2 savestates.
+//!
+//! PCSX2 stores its `fpuRegisters` and `VURegs` structs verbatim, little-endian,
+//! in the internal structures entry of a savestate. Where they start depends on
+//! the PCSX2 version, so the blocks are sliced out by the caller and converted
+//! here:
+//!
+//! ```text
+//! fpuRegisters                       VURegs
+//! offset  size  field                offset  size  field
+//!      0   128  fpr[32]                   0   512  VF[32], x y z w
+//!    128   128  fprc[32]                512   512  VI[32], 16 bytes each
+//!    256     4  ACC                    1024    16  ACC
+//!    260     4  ACCflag                1040    16  q, the pending Q
+//!                                      1056    16  p, the pending P
+//! ```
+//!
+//! VI16 to VI23 of `VURegs` hold the special registers: the status, MAC and
+//! clip flags, R, I, Q and P. The rest of `VURegs` is emulator bookkeeping and
+//! isn't part of the block.
+
+use crate::clip::ClipFlags;
+use crate::vu::{MacFlags, StatusFlags, VuRegisterFile};
+use crate::{Ps2Float, Ps2Vec4};
+
+/// The size in bytes of a PCSX2 `fpuRegisters` block.
+pub const FPU_BLOCK_SIZE: usize = 264;
+
+/// The size in bytes of the register block at the start of a PCSX2 `VURegs`.
+pub const VU_BLOCK_SIZE: usize = 1072;
+
+/// The offset of `VI[0]` in a VU block.
+const VI_OFFSET: usize = 512;
+/// The offset of `ACC` in a VU block.
+const ACC_OFFSET: usize = 1024;
+/// The offset of the pending `q` in a VU block, followed by the pending `p`.
+const PENDING_Q_OFFSET: usize = 1040;
+const PENDING_P_OFFSET: usize = 1056;
+
+/// The VI slots PCSX2 keeps the special registers in.
+const REG_STATUS_FLAG: usize = 16;
+const REG_MAC_FLAG: usize = 17;
+const REG_CLIP_FLAG: usize = 18;
+const REG_R: usize = 20;
+const REG_I: usize = 21;
+const REG_Q: usize = 22;
+const REG_P: usize = 23;
+
+/// The EE FPU (COP1) registers of a PCSX2 savestate.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct FpuRegisters {
+    /// The float registers `f0` to `f31`.
+    pub fpr: [Ps2Float; 32],
+    /// The control registers, of which FCR0 holds the revision and FCR31 the
+    /// control and status flags.
+    pub fcr: [u32; 32],
+    /// The accumulator of the `ADDA`/`MADD` family.
+    pub acc: Ps2Float,
+    /// PCSX2's flag of whether the accumulator overflowed.
+    pub acc_flag: u32,
+}
+
+impl FpuRegisters {
+    /// Decodes the registers from a PCSX2 `fpuRegisters` block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::savestate::{FpuRegisters, FPU_BLOCK_SIZE};
+    ///
+    /// let mut block = [0; FPU_BLOCK_SIZE];
+    /// block[4..8].copy_from_slice(&0x3F800000u32.to_le_bytes()); // f1
+    ///
+    /// let fpu = FpuRegisters::from_bytes(&block);
+    /// assert_eq!(fpu.fpr[1].as_u32(), 0x3F800000);
+    /// assert_eq!(fpu.to_bytes(), block);
+    /// ```
+    pub fn from_bytes(bytes: &[u8; FPU_BLOCK_SIZE]) -> Self {
+        Self {
+            fpr: std::array::from_fn(|i| Ps2Float::new(word(bytes, i * 4))),
+            fcr: std::array::from_fn(|i| word(bytes, 128 + i * 4)),
+            acc: Ps2Float::new(word(bytes, 256)),
+            acc_flag: word(bytes, 260),
+        }
+    }
+
+    /// Encodes the registers into a PCSX2 `fpuRegisters` block.
+    pub fn to_bytes(&self) -> [u8; FPU_BLOCK_SIZE] {
+        let mut bytes = [0; FPU_BLOCK_SIZE];
+        for (i, value) in self.fpr.iter().enumerate() {
+            set_word(&mut bytes, i * 4, value.as_u32());
+        }
+        for (i, value) in self.fcr.iter().enumerate() {
+            set_word(&mut bytes, 128 + i * 4, *value);
+        }
+        set_word(&mut bytes, 256, self.acc.as_u32());
+        set_word(&mut bytes, 260, self.acc_flag);
+        bytes
+    }
+}
+
+/// Decodes a VU register file from the register block of a PCSX2 `VURegs`.
+///
+/// Q and P are taken from their VI slots, the values instructions read, rather
+/// than the pending results. VF0 and VI0 keep their hardwired values.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::savestate::{read_vu_block, write_vu_block, VU_BLOCK_SIZE};
+/// use ps2_floating_point::vu::VuRegisterFile;
+/// use ps2_floating_point::{Ps2Float, Ps2Vec4};
+///
+/// let mut registers = VuRegisterFile::new();
+/// registers.set_vf(1, Ps2Vec4::splat(Ps2Float::new(0x3F800000)));
+///
+/// let mut block = [0; VU_BLOCK_SIZE];
+/// write_vu_block(&registers, &mut block);
+///
+/// assert_eq!(read_vu_block(&block), registers);
+/// ```
+pub fn read_vu_block(block: &[u8; VU_BLOCK_SIZE]) -> VuRegisterFile {
+    let vi = |index: usize| word(block, VI_OFFSET + index * 16);
+
+    let mut registers = VuRegisterFile::new();
+    for index in 0..32 {
+        registers.set_vf(index, vector(block, index * 16));
+    }
+    for index in 0..16 {
+        registers.set_vi(index, vi(index) as u16);
+    }
+
+    registers.acc = vector(block, ACC_OFFSET);
+    registers.q = Ps2Float::new(vi(REG_Q));
+    registers.p = Ps2Float::new(vi(REG_P));
+    registers.i = Ps2Float::new(vi(REG_I));
+    registers.r.rinit(&Ps2Float::new(vi(REG_R)));
+    registers.status_flags = StatusFlags::from_bits(vi(REG_STATUS_FLAG) as u16);
+    registers.mac_flags = MacFlags::from_bits(vi(REG_MAC_FLAG) as u16);
+    registers.clip_flags = ClipFlags::from_bits(vi(REG_CLIP_FLAG));
+    registers
+}
+
+/// Encodes a VU register file into the register block of a PCSX2 `VURegs`.
+///
+/// Only the registers of the register file are written, so the other VI slots
+/// of an existing block, like the TPC, are kept. Q and P are written to their
+/// VI slots and as the pending results, so PCSX2 doesn't land a stale result
+/// over them.
+pub fn write_vu_block(registers: &VuRegisterFile, block: &mut [u8; VU_BLOCK_SIZE]) {
+    let mut set_vi = |index: usize, value: u32| set_word(block, VI_OFFSET + index * 16, value);
+
+    for index in 0..16 {
+        set_vi(index, registers.vi(index) as u32);
+    }
+    set_vi(REG_STATUS_FLAG, registers.status_flags.bits() as u32);
+    set_vi(REG_MAC_FLAG, registers.mac_flags.bits() as u32);
+    set_vi(REG_CLIP_FLAG, registers.clip_flags.bits());
+    set_vi(REG_R, registers.r.rget().as_u32());
+    set_vi(REG_I, registers.i.as_u32());
+    set_vi(REG_Q, registers.q.as_u32());
+    set_vi(REG_P, registers.p.as_u32());
+
+    for index in 0..32 {
+        set_vector(block, index * 16, registers.vf(index));
+    }
+    set_vector(block, ACC_OFFSET, registers.acc);
+    set_word(block, PENDING_Q_OFFSET, registers.q.as_u32());
+    set_word(block, PENDING_P_OFFSET, registers.p.as_u32());
+}
+
+fn word(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn set_word(bytes: &mut [u8], offset: usize, value: u32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+fn vector(bytes: &[u8], offset: usize) -> Ps2Vec4 {
+    Ps2Vec4::from_bits(std::array::from_fn(|lane| word(bytes, offset + lane * 4)))
+}
+
+fn set_vector(bytes: &mut [u8], offset: usize, value: Ps2Vec4) {
+    for (lane, bits) in value.to_bits().into_iter().enumerate() {
+        set_word(bytes, offset + lane * 4, bits);
+    }
+}
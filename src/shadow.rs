@@ -0,0 +1,152 @@
+//! Shadow execution of PS2 float operations against IEEE 754.
+//!
+//! Every operation is computed with both PS2 semantics and host IEEE 754
+//! floats, and results further apart than a threshold are reported along with
+//! their position in the operation stream. This finds the first point where an
+//! emulated game's math departs from a PC port.
+//!
+//! For instrumenting a math layer in production builds without an executor,
+//! the `*_both` operations return both results of a single operation.
+
+use crate::{Operation, Ps2Float};
+
+/// An operation whose PS2 and IEEE 754 results diverged.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Divergence {
+    /// The position of the operation in the stream, counting from 0.
+    pub index: u64,
+    /// The operation that diverged.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The result with PS2 semantics.
+    pub ps2: Ps2Float,
+    /// The result with IEEE 754 semantics.
+    pub ieee: f32,
+    /// The ULP distance between both results, see [`Ps2Float::ulp_distance`].
+    pub ulps: u32,
+}
+
+/// Executes operations with PS2 semantics while shadowing them with IEEE 754.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::shadow::ShadowExecutor;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let big = Ps2Float::new(0x7F7FFFFF);
+///
+/// let mut divergences = Vec::new();
+/// {
+///     let mut executor = ShadowExecutor::new(0, |d| divergences.push(*d));
+///     executor.add(big, big); // Fmax on the PS2, infinity in IEEE 754
+/// }
+///
+/// assert_eq!(divergences.len(), 1);
+/// ```
+pub struct ShadowExecutor<F: FnMut(&Divergence)> {
+    threshold: u32,
+    index: u64,
+    on_divergence: F,
+}
+
+impl<F: FnMut(&Divergence)> ShadowExecutor<F> {
+    /// Creates a new shadow executor.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - Results at most this many ULPs apart aren't reported.
+    /// * `on_divergence` - Called with every divergence above the threshold.
+    pub fn new(threshold: u32, on_divergence: F) -> Self {
+        Self { threshold, index: 0, on_divergence }
+    }
+
+    /// Returns the number of operations executed so far.
+    pub fn operations(&self) -> u64 {
+        self.index
+    }
+
+    /// Executes the operation with PS2 semantics, reporting a divergence from
+    /// IEEE 754 above the threshold.
+    ///
+    /// # Returns
+    ///
+    /// The result with PS2 semantics.
+    pub fn apply(&mut self, operation: Operation, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        let (ps2, ieee) = operation.apply_both(a, b);
+
+        let ulps = ps2.ulp_distance(Ps2Float::new(ieee.to_bits()));
+        if ulps > self.threshold {
+            (self.on_divergence)(&Divergence {
+                index: self.index,
+                operation,
+                a,
+                b,
+                ps2,
+                ieee,
+                ulps,
+            });
+        }
+
+        self.index += 1;
+        ps2
+    }
+
+    /// Adds two floats, see [`ShadowExecutor::apply`].
+    pub fn add(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.apply(Operation::Add, a, b)
+    }
+
+    /// Subtracts two floats, see [`ShadowExecutor::apply`].
+    pub fn sub(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.apply(Operation::Sub, a, b)
+    }
+
+    /// Multiplies two floats, see [`ShadowExecutor::apply`].
+    pub fn mul(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.apply(Operation::Mul, a, b)
+    }
+
+    /// Divides two floats, see [`ShadowExecutor::apply`].
+    pub fn div(&mut self, a: Ps2Float, b: Ps2Float) -> Ps2Float {
+        self.apply(Operation::Div, a, b)
+    }
+}
+
+/// Computing PS2 and IEEE 754 results together.
+impl Ps2Float {
+    /// Adds two floats with both PS2 and IEEE 754 semantics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// let big = Ps2Float::new(0x7F7FFFFF);
+    /// let (ps2, ieee) = big.add_both(big);
+    ///
+    /// assert_eq!(ps2.as_u32(), 0x7FFFFFFF); // Fmax
+    /// assert_eq!(ieee, f32::INFINITY);
+    /// ```
+    pub fn add_both(self, addend: Ps2Float) -> (Ps2Float, f32) {
+        Operation::Add.apply_both(self, addend)
+    }
+
+    /// Subtracts two floats with both PS2 and IEEE 754 semantics.
+    pub fn sub_both(self, subtrahend: Ps2Float) -> (Ps2Float, f32) {
+        Operation::Sub.apply_both(self, subtrahend)
+    }
+
+    /// Multiplies two floats with both PS2 and IEEE 754 semantics.
+    pub fn mul_both(self, factor: Ps2Float) -> (Ps2Float, f32) {
+        Operation::Mul.apply_both(self, factor)
+    }
+
+    /// Divides two floats with both PS2 and IEEE 754 semantics.
+    pub fn div_both(self, divisor: Ps2Float) -> (Ps2Float, f32) {
+        Operation::Div.apply_both(self, divisor)
+    }
+}
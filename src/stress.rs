@@ -0,0 +1,159 @@
+//! Seeded stress testing of PS2 float operations against a reference.
+//!
+//! Every case is derived from the seed and its index alone, so a mismatch
+//! found after millions of cases can be replayed instantly with
+//! [`StressTest::case`].
+
+use std::fmt::{Display, Formatter};
+
+use crate::sampling::EdgeCaseGenerator;
+use crate::{Operation, Ps2Float};
+
+/// A deterministic stress test running random operations from a seed.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::stress::StressTest;
+///
+/// let report = StressTest::new(42).iterations(1000).run(|op, a, b| op.apply(a, b));
+/// assert_eq!(report.unwrap().cases, 1000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StressTest {
+    seed: u64,
+    iterations: u64,
+    operations: Vec<Operation>,
+}
+
+/// A single stress test case.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StressCase {
+    /// The seed of the stress test.
+    pub seed: u64,
+    /// The index of the case within the stress test.
+    pub index: u64,
+    /// The operation to run.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+}
+
+/// The summary of a stress test run without mismatches.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StressReport {
+    /// The number of cases run.
+    pub cases: u64,
+}
+
+/// A case where the crate disagreed with the reference.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StressMismatch {
+    /// The case that mismatched.
+    pub case: StressCase,
+    /// The result of the reference.
+    pub expected: Ps2Float,
+    /// The result of the crate.
+    pub actual: Ps2Float,
+}
+
+impl StressTest {
+    /// The default number of cases to run.
+    pub const DEFAULT_ITERATIONS: u64 = 1_000_000;
+
+    /// Creates a new stress test from the given seed.
+    ///
+    /// Runs [`StressTest::DEFAULT_ITERATIONS`] cases of addition and
+    /// subtraction by default.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            iterations: Self::DEFAULT_ITERATIONS,
+            operations: vec![Operation::Add, Operation::Sub],
+        }
+    }
+
+    /// Sets the number of cases to run.
+    pub fn iterations(mut self, iterations: u64) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the operations to pick from, which must not be empty.
+    pub fn operations(mut self, operations: &[Operation]) -> Self {
+        assert!(!operations.is_empty(), "At least one operation is required");
+        self.operations = operations.to_vec();
+        self
+    }
+
+    /// Returns the case at the given index, used to replay a mismatch.
+    pub fn case(&self, index: u64) -> StressCase {
+        let mut generator =
+            EdgeCaseGenerator::new(self.seed ^ index.wrapping_mul(0xD1B54A32D192ED03));
+        let operation =
+            self.operations[(generator.next_bits() % self.operations.len() as u64) as usize];
+        let a = generator.next().unwrap();
+        let b = generator.next().unwrap();
+
+        StressCase { seed: self.seed, index, operation, a, b }
+    }
+
+    /// Runs every case and compares the crate's result with the reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - Computes the expected result of an operation.
+    ///
+    /// # Returns
+    ///
+    /// A [`StressReport`] if every case matched, otherwise the first
+    /// [`StressMismatch`].
+    pub fn run<F>(&self, mut reference: F) -> Result<StressReport, StressMismatch>
+    where
+        F: FnMut(Operation, Ps2Float, Ps2Float) -> Ps2Float,
+    {
+        for index in 0..self.iterations {
+            let case = self.case(index);
+            let expected = reference(case.operation, case.a, case.b);
+            let actual = case.operation.apply(case.a, case.b);
+
+            if expected != actual {
+                return Err(StressMismatch { case, expected, actual });
+            }
+        }
+
+        Ok(StressReport { cases: self.iterations })
+    }
+}
+
+impl Display for StressMismatch {
+    /// Formats the mismatch with a reproducer that can be pasted into a unit
+    /// test case list.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let case = &self.case;
+        writeln!(
+            f,
+            "Mismatch at seed {} index {}: 0x{:08X} {} 0x{:08X}",
+            case.seed,
+            case.index,
+            case.a.as_u32(),
+            case.operation.symbol(),
+            case.b.as_u32()
+        )?;
+        writeln!(f, "  expected: 0x{:08X} ({})", self.expected.as_u32(), self.expected)?;
+        writeln!(f, "    actual: 0x{:08X} ({})", self.actual.as_u32(), self.actual)?;
+        write!(
+            f,
+            "#[case(0x{:08X}, 0x{:08X}, 0x{:08X})] // {} {} {} = {}",
+            case.a.as_u32(),
+            case.b.as_u32(),
+            self.expected.as_u32(),
+            case.a,
+            case.operation.symbol(),
+            case.b,
+            self.expected
+        )
+    }
+}
@@ -0,0 +1,321 @@
+//! Summing PS2 floats while keeping track of saturation and flushes.
+//!
+//! A long sum that overflows to Fmax or flushes a partial sum to zero silently
+//! corrupts the result, so the sum carries sticky status flags like the VU
+//! does, raised by any addition along the way.
+//!
+//! Truncating additions also make the sum depend on the order the values are
+//! added in. [`compare_sums`] sums a slice in several orders and exactly, to
+//! tell how much of a discrepancy comes from the order alone.
+//!
+//! [`Ps2Float`] also implements [`Sum`] and [`Product`], folding from the
+//! first value to the last like a loop on the console, without the flags.
+
+use std::borrow::Borrow;
+use std::iter::{Product, Sum};
+
+use crate::vu::{MacFlags, StatusFlags};
+use crate::Ps2Float;
+
+/// The result of [`TrySum::try_sum`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FlaggedSum {
+    /// The sum of every value, added from the first.
+    pub sum: Ps2Float,
+    /// The status flags of the last addition, with the sticky flags of every
+    /// addition.
+    pub flags: StatusFlags,
+}
+
+/// Sums an iterator of PS2 floats, carrying sticky flags.
+///
+/// Implemented for everything iterating over [`Ps2Float`] or `&Ps2Float`.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::sum::TrySum;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let weights = [0x7FFFFFFF, 0x7F7FFFFF, 0xFF7FFFFF].map(Ps2Float::new);
+/// let result = weights.iter().try_sum();
+///
+/// // The second weight saturates the sum, so subtracting it back doesn't give
+/// // the first.
+/// assert_eq!(result.sum.as_u32(), 0x7F800000);
+/// assert!(result.overflowed());
+/// assert!(!result.underflowed());
+/// ```
+pub trait TrySum {
+    /// Adds the values in order with PS2 addition, starting from the first.
+    ///
+    /// # Returns
+    ///
+    /// The sum, +0 if there are no values and +/- 0 for a lone denormalized
+    /// value, and the status flags of the additions.
+    fn try_sum(self) -> FlaggedSum;
+}
+
+/// The order in which [`sum_with`] accumulates the values of a slice.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Accumulation {
+    /// One after another from the first, like a loop on the console.
+    Sequential,
+    /// Both halves summed recursively and then added, like a reduction tree.
+    Pairwise,
+    /// Exactly, rounding the sum once to the nearest PS2 float.
+    Exact,
+}
+
+/// The sum of a slice under every [`Accumulation`], see [`compare_sums`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SumComparison {
+    /// The sum accumulated with [`Accumulation::Sequential`].
+    pub sequential: Ps2Float,
+    /// The sum accumulated with [`Accumulation::Pairwise`].
+    pub pairwise: Ps2Float,
+    /// The sum accumulated with [`Accumulation::Exact`].
+    pub exact: Ps2Float,
+}
+
+/// A two's complement fixed-point number in units of 2^-149, the weight of the
+/// lowest mantissa bit at the smallest exponent, holding the exact sum of up to
+/// 2^40 floats.
+#[derive(Debug, Default)]
+struct ExactSum {
+    words: [u64; 5],
+}
+
+impl<I> TrySum for I
+where
+    I: IntoIterator,
+    I::Item: Borrow<Ps2Float>,
+{
+    fn try_sum(self) -> FlaggedSum {
+        let mut values = self.into_iter();
+        let first = values.next().map_or(Ps2Float::default(), |value| *value.borrow());
+        // A lone value is never added, so flush it like an addition would.
+        let first =
+            if first.is_denormalized() { Ps2Float::from_params(first.sign(), 0, 0) } else { first };
+        let mut result = FlaggedSum { sum: first, flags: StatusFlags::default() };
+
+        for value in values {
+            let explanation = result.sum.add_explained(*value.borrow());
+
+            let mut mac = MacFlags::default();
+            mac.set_lane_from(0, &explanation);
+            result.flags.update_from_mac(mac);
+            result.sum = explanation.result;
+        }
+
+        result
+    }
+}
+
+/// Sums a slice of PS2 floats in the given order.
+///
+/// # Returns
+///
+/// The sum, +0 if the slice is empty.
+pub fn sum_with(values: &[Ps2Float], accumulation: Accumulation) -> Ps2Float {
+    match accumulation {
+        Accumulation::Sequential => values.try_sum().sum,
+        Accumulation::Pairwise => pairwise_sum(values),
+        Accumulation::Exact => {
+            let mut sum = ExactSum::default();
+            values.iter().for_each(|value| sum.add(value));
+            sum.to_float()
+        }
+    }
+}
+
+/// Sums a slice of PS2 floats under every [`Accumulation`].
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::sum::compare_sums;
+/// use ps2_floating_point::Ps2Float;
+///
+/// // 2^24 + 1.5 + 1.5: added one at a time, each 1.5 is truncated away.
+/// let values = [0x4B800000, 0x3FC00000, 0x3FC00000].map(Ps2Float::new);
+/// let sums = compare_sums(&values);
+///
+/// assert_eq!(sums.sequential.as_u32(), 0x4B800000);
+/// assert_eq!(sums.pairwise.as_u32(), 0x4B800001);
+/// assert_eq!(sums.exact.as_u32(), 0x4B800002);
+/// ```
+pub fn compare_sums(values: &[Ps2Float]) -> SumComparison {
+    SumComparison {
+        sequential: sum_with(values, Accumulation::Sequential),
+        pairwise: sum_with(values, Accumulation::Pairwise),
+        exact: sum_with(values, Accumulation::Exact),
+    }
+}
+
+fn pairwise_sum(values: &[Ps2Float]) -> Ps2Float {
+    match values {
+        [] => Ps2Float::default(),
+        [value] => *value,
+        _ => {
+            let (left, right) = values.split_at(values.len() / 2);
+            pairwise_sum(left).add(pairwise_sum(right))
+        }
+    }
+}
+
+impl Sum for Ps2Float {
+    /// Adds the values from the first to the last, +0 if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::Ps2Float;
+    ///
+    /// // 2^24 + 1.5 + 1.5: each 1.5 is truncated away.
+    /// let values = [0x4B800000, 0x3FC00000, 0x3FC00000].map(Ps2Float::new);
+    ///
+    /// assert_eq!(values.into_iter().sum::<Ps2Float>().as_u32(), 0x4B800000);
+    /// ```
+    fn sum<I: Iterator<Item = Ps2Float>>(values: I) -> Self {
+        fold_from_first(values, Ps2Float::default(), Ps2Float::add)
+    }
+}
+
+impl<'a> Sum<&'a Ps2Float> for Ps2Float {
+    /// Adds the values from the first to the last, +0 if there are none.
+    fn sum<I: Iterator<Item = &'a Ps2Float>>(values: I) -> Self {
+        values.copied().sum()
+    }
+}
+
+impl Product for Ps2Float {
+    /// Multiplies the values from the first to the last, 1.0 if there are
+    /// none.
+    fn product<I: Iterator<Item = Ps2Float>>(values: I) -> Self {
+        fold_from_first(values, Ps2Float::new(0x3F800000), Ps2Float::mul)
+    }
+}
+
+impl<'a> Product<&'a Ps2Float> for Ps2Float {
+    /// Multiplies the values from the first to the last, 1.0 if there are
+    /// none.
+    fn product<I: Iterator<Item = &'a Ps2Float>>(values: I) -> Self {
+        values.copied().product()
+    }
+}
+
+/// Folds the values starting from the first rather than from an identity, as
+/// `+0 + -0` is +0 and `1.0 * x` can differ from `x` for abnormal floats.
+fn fold_from_first(
+    mut values: impl Iterator<Item = Ps2Float>,
+    empty: Ps2Float,
+    operation: fn(Ps2Float, Ps2Float) -> Ps2Float,
+) -> Ps2Float {
+    match values.next() {
+        Some(first) => values.fold(first, operation),
+        None => empty,
+    }
+}
+
+impl FlaggedSum {
+    /// Returns if any addition overflowed and saturated to +/- Fmax.
+    pub fn overflowed(&self) -> bool {
+        self.flags.contains(StatusFlags::STICKY_OVERFLOW)
+    }
+
+    /// Returns if any addition underflowed and flushed to +/- 0.
+    pub fn underflowed(&self) -> bool {
+        self.flags.contains(StatusFlags::STICKY_UNDERFLOW)
+    }
+}
+
+impl SumComparison {
+    /// Returns the ULPs between the sequential and the exact sum, the error
+    /// the console's order of accumulation leads to.
+    pub fn sequential_error(&self) -> u32 {
+        self.sequential.ulp_distance(self.exact)
+    }
+
+    /// Returns the ULPs between the pairwise and the exact sum.
+    pub fn pairwise_error(&self) -> u32 {
+        self.pairwise.ulp_distance(self.exact)
+    }
+}
+
+impl ExactSum {
+    /// Adds a float exactly, denormalized floats being zero.
+    fn add(&mut self, value: &Ps2Float) {
+        let significand = value.significand();
+        if significand == 0 {
+            return;
+        }
+
+        let shift = value.exponent() as usize - 1;
+        let wide = (significand as u128) << (shift % 64);
+        let mut addend = [0; 5];
+        addend[shift / 64] = wide as u64;
+        if shift / 64 < 4 {
+            addend[shift / 64 + 1] = (wide >> 64) as u64;
+        }
+        if value.sign() {
+            negate(&mut addend);
+        }
+
+        let mut carry = false;
+        for (word, addend) in self.words.iter_mut().zip(addend) {
+            let (sum, first) = word.overflowing_add(addend);
+            let (sum, second) = sum.overflowing_add(carry as u64);
+            *word = sum;
+            carry = first || second;
+        }
+    }
+
+    /// Rounds the sum to the nearest PS2 float, ties to even, flushing below
+    /// the normalized range and saturating past Fmax.
+    fn to_float(&self) -> Ps2Float {
+        let sign = self.words[4] >> 63 == 1;
+        let mut magnitude = self.words;
+        if sign {
+            negate(&mut magnitude);
+        }
+
+        let bit = |index: usize| magnitude[index / 64] >> (index % 64) & 1 == 1;
+        let highest = match (0..320).rev().find(|&index| bit(index)) {
+            Some(highest) if highest >= 23 => highest,
+            _ => return Ps2Float::from_params(sign, 0, 0),
+        };
+
+        let lowest = highest - 23;
+        let mut significand =
+            (lowest..=highest).rev().fold(0u32, |bits, index| bits << 1 | bit(index) as u32);
+        if lowest > 0 && bit(lowest - 1) {
+            let sticky = (0..lowest - 1).any(bit);
+            if sticky || significand & 1 == 1 {
+                significand += 1;
+            }
+        }
+
+        let mut exponent = highest - 22;
+        if significand == 1 << 24 {
+            significand >>= 1;
+            exponent += 1;
+        }
+
+        match (exponent > 255, sign) {
+            (true, false) => Ps2Float::max(),
+            (true, true) => Ps2Float::min(),
+            _ => Ps2Float::from_params(sign, exponent as u8, significand & 0x7FFFFF),
+        }
+    }
+}
+
+/// Negates a two's complement number in place.
+fn negate(words: &mut [u64; 5]) {
+    let mut carry = true;
+    for word in words.iter_mut() {
+        let (negated, overflow) = (!*word).overflowing_add(carry as u64);
+        *word = negated;
+        carry = overflow;
+    }
+}
@@ -0,0 +1,236 @@
+//! Loading and running regression test vectors.
+//!
+//! Test vectors are `(op, a, b, expected)` records where the floats are hex
+//! bit patterns. They can be kept outside the crate as CSV:
+//!
+//! ```text
+//! # op, a, b, expected
+//! add, 0x3F800000, 0x3F800000, 0x40000000
+//! sub, 0x40400000, 0x3F800000, 0x40000000
+//! ```
+//!
+//! or, with the `json` feature, as JSON:
+//!
+//! ```text
+//! [{ "op": "add", "a": "0x3F800000", "b": "0x3F800000", "expected": "0x40000000" }]
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use crate::{Operation, Ps2Float};
+
+/// A single `(op, a, b, expected)` test vector.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TestVector {
+    /// The operation to run.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The expected result.
+    pub expected: Ps2Float,
+}
+
+/// A test vector whose result didn't match the expected result.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TestVectorFailure {
+    /// The index of the test vector in the list that was run.
+    pub index: usize,
+    /// The test vector that failed.
+    pub vector: TestVector,
+    /// The actual result of the operation.
+    pub actual: Ps2Float,
+}
+
+/// The results of running a list of test vectors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestVectorReport {
+    /// The number of test vectors that passed.
+    pub passed: usize,
+    /// The test vectors that failed.
+    pub failures: Vec<TestVectorFailure>,
+}
+
+/// An error returned when loading test vectors fails.
+#[derive(Debug)]
+pub enum TestVectorError {
+    /// The file couldn't be read.
+    Io(std::io::Error),
+    /// A record couldn't be parsed, `line` starts counting from 1.
+    Parse { line: usize, message: String },
+}
+
+impl TestVector {
+    /// Parses test vectors from CSV text.
+    ///
+    /// Blank lines, lines starting with `#`, and an `op,a,b,expected` header
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `csv` - The CSV text.
+    ///
+    /// # Returns
+    ///
+    /// The parsed test vectors, or the first record that couldn't be parsed.
+    pub fn parse_csv(csv: &str) -> Result<Vec<TestVector>, TestVectorError> {
+        let mut vectors = Vec::new();
+
+        for (i, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("op") {
+                continue;
+            }
+
+            let parse_error = |message: String| TestVectorError::Parse { line: i + 1, message };
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 4 {
+                return Err(parse_error(format!("expected 4 fields, found {}", fields.len())));
+            }
+
+            vectors.push(TestVector {
+                operation: fields[0].parse().map_err(|e| parse_error(format!("{}", e)))?,
+                a: parse_bits(fields[1]).map_err(parse_error)?,
+                b: parse_bits(fields[2]).map_err(parse_error)?,
+                expected: parse_bits(fields[3]).map_err(parse_error)?,
+            });
+        }
+
+        Ok(vectors)
+    }
+
+    /// Loads test vectors from a CSV file, see [`TestVector::parse_csv`].
+    pub fn load_csv<P: AsRef<Path>>(path: P) -> Result<Vec<TestVector>, TestVectorError> {
+        Self::parse_csv(&std::fs::read_to_string(path).map_err(TestVectorError::Io)?)
+    }
+
+    /// Parses test vectors from a JSON array of objects with `op`, `a`, `b` and
+    /// `expected` fields.
+    ///
+    /// Floats are either hex strings like `"0x3F800000"` or integer bit
+    /// patterns. The reported line of a bad record is its index plus 1.
+    #[cfg(feature = "json")]
+    pub fn parse_json(json: &str) -> Result<Vec<TestVector>, TestVectorError> {
+        use serde_json::Value;
+
+        let parse_error = |line: usize, message: String| TestVectorError::Parse { line, message };
+
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| parse_error(e.line(), format!("{}", e)))?;
+        let records = value
+            .as_array()
+            .ok_or_else(|| parse_error(1, "expected an array of test vectors".to_string()))?;
+
+        let mut vectors = Vec::with_capacity(records.len());
+        for (i, record) in records.iter().enumerate() {
+            let field = |name: &str| -> Result<&Value, TestVectorError> {
+                record.get(name).ok_or_else(|| parse_error(i + 1, format!("missing '{}'", name)))
+            };
+            let float = |name: &str| -> Result<Ps2Float, TestVectorError> {
+                match field(name)? {
+                    Value::String(s) => parse_bits(s).map_err(|e| parse_error(i + 1, e)),
+                    Value::Number(n) => n
+                        .as_u64()
+                        .and_then(|n| u32::try_from(n).ok())
+                        .map(Ps2Float::new)
+                        .ok_or_else(|| parse_error(i + 1, format!("invalid bits '{}'", n))),
+                    other => Err(parse_error(i + 1, format!("invalid float '{}'", other))),
+                }
+            };
+
+            let operation = field("op")?
+                .as_str()
+                .ok_or_else(|| parse_error(i + 1, "'op' must be a string".to_string()))?
+                .parse()
+                .map_err(|e| parse_error(i + 1, format!("{}", e)))?;
+
+            vectors.push(TestVector {
+                operation,
+                a: float("a")?,
+                b: float("b")?,
+                expected: float("expected")?,
+            });
+        }
+
+        Ok(vectors)
+    }
+
+    /// Loads test vectors from a JSON file, see [`TestVector::parse_json`].
+    #[cfg(feature = "json")]
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<Vec<TestVector>, TestVectorError> {
+        Self::parse_json(&std::fs::read_to_string(path).map_err(TestVectorError::Io)?)
+    }
+
+    /// Runs the test vector and returns the actual result.
+    pub fn run(&self) -> Ps2Float {
+        self.operation.apply(self.a, self.b)
+    }
+}
+
+/// Runs every test vector and collects the failures.
+///
+/// # Arguments
+///
+/// * `vectors` - The test vectors to run.
+///
+/// # Returns
+///
+/// A [`TestVectorReport`] with the number of passing vectors and the failures.
+pub fn run_test_vectors(vectors: &[TestVector]) -> TestVectorReport {
+    let mut report = TestVectorReport::default();
+
+    for (index, vector) in vectors.iter().enumerate() {
+        let actual = vector.run();
+        if actual == vector.expected {
+            report.passed += 1;
+        } else {
+            report.failures.push(TestVectorFailure { index, vector: *vector, actual });
+        }
+    }
+
+    report
+}
+
+/// Parses a float from its hex bit pattern, with or without a `0x` prefix.
+fn parse_bits(s: &str) -> Result<Ps2Float, String> {
+    let digits = s.trim().trim_start_matches("0x").trim_start_matches("0X");
+    u32::from_str_radix(digits, 16).map(Ps2Float::new).map_err(|_| format!("invalid bits '{}'", s))
+}
+
+impl TestVectorReport {
+    /// Returns if every test vector passed.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl Display for TestVectorFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{}: 0x{:08X} {} 0x{:08X} expected 0x{:08X} ({}) but was 0x{:08X} ({})",
+            self.index,
+            self.vector.a.as_u32(),
+            self.vector.operation.symbol(),
+            self.vector.b.as_u32(),
+            self.vector.expected.as_u32(),
+            self.vector.expected,
+            self.actual.as_u32(),
+            self.actual
+        )
+    }
+}
+
+impl Display for TestVectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestVectorError::Io(e) => write!(f, "failed to read test vectors: {}", e),
+            TestVectorError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for TestVectorError {}
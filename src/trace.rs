@@ -0,0 +1,291 @@
+//! Recording and replaying streams of PS2 float operations.
+//!
+//! A trace file starts with the magic `PS2T` and a version byte, followed by
+//! fixed-size little-endian records:
+//!
+//! ```text
+//! offset  size  field
+//!      0     1  operation (0 = add, 1 = sub, 2 = mul, 3 = div)
+//!      1     4  a
+//!      5     4  b
+//!      9     4  result
+//!     13     1  flags
+//! ```
+//!
+//! Traces captured from hardware or an emulator can be replayed to re-verify
+//! them against the current arithmetic.
+
+use std::fmt::{Display, Formatter};
+use std::io::{ErrorKind, Read, Write};
+
+use crate::{Operation, Ps2Float};
+
+/// The magic bytes every trace file starts with.
+pub const TRACE_MAGIC: [u8; 4] = *b"PS2T";
+
+/// The version of the trace format written by [`TraceWriter`].
+pub const TRACE_VERSION: u8 = 1;
+
+/// The size in bytes of a single record.
+pub const TRACE_RECORD_SIZE: usize = 14;
+
+/// A single recorded operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TraceRecord {
+    /// The operation that was performed.
+    pub operation: Operation,
+    /// The left-hand side operand.
+    pub a: Ps2Float,
+    /// The right-hand side operand.
+    pub b: Ps2Float,
+    /// The recorded result.
+    pub result: Ps2Float,
+    /// The raw status flags raised by the operation, as recorded.
+    pub flags: u8,
+}
+
+/// Writes operation records to a trace.
+#[derive(Debug)]
+pub struct TraceWriter<W: Write> {
+    inner: W,
+}
+
+/// Reads operation records from a trace, one record per iteration.
+#[derive(Debug)]
+pub struct TraceReader<R: Read> {
+    inner: R,
+    index: u64,
+    done: bool,
+}
+
+/// A recorded operation whose replayed result didn't match.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TraceMismatch {
+    /// The index of the record in the trace.
+    pub index: u64,
+    /// The recorded operation.
+    pub record: TraceRecord,
+    /// The result of replaying the operation.
+    pub actual: Ps2Float,
+}
+
+/// The results of replaying a trace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceReplayReport {
+    /// The number of records whose result matched.
+    pub matched: u64,
+    /// The records whose result didn't match.
+    pub mismatches: Vec<TraceMismatch>,
+}
+
+/// An error returned when reading or writing a trace fails.
+#[derive(Debug)]
+pub enum TraceError {
+    /// The underlying reader or writer failed.
+    Io(std::io::Error),
+    /// The trace doesn't start with [`TRACE_MAGIC`].
+    InvalidMagic,
+    /// The trace was written by an unsupported version of the format.
+    UnsupportedVersion(u8),
+    /// A record has an unknown operation code.
+    InvalidOperation { index: u64, code: u8 },
+    /// The trace ends in the middle of a record.
+    Truncated { index: u64 },
+}
+
+impl TraceRecord {
+    /// Records the operation by running it.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to run.
+    /// * `a` - The left-hand side operand.
+    /// * `b` - The right-hand side operand.
+    /// * `flags` - The raw status flags to record alongside the result.
+    pub fn capture(operation: Operation, a: &Ps2Float, b: &Ps2Float, flags: u8) -> Self {
+        Self { operation, a: *a, b: *b, result: operation.apply(*a, *b), flags }
+    }
+
+    /// Replays the operation and returns the actual result.
+    pub fn replay(&self) -> Ps2Float {
+        self.operation.apply(self.a, self.b)
+    }
+
+    /// Encodes the record into its binary form.
+    pub fn to_bytes(&self) -> [u8; TRACE_RECORD_SIZE] {
+        let mut bytes = [0; TRACE_RECORD_SIZE];
+        bytes[0] = operation_code(self.operation);
+        bytes[1..5].copy_from_slice(&self.a.as_u32().to_le_bytes());
+        bytes[5..9].copy_from_slice(&self.b.as_u32().to_le_bytes());
+        bytes[9..13].copy_from_slice(&self.result.as_u32().to_le_bytes());
+        bytes[13] = self.flags;
+        bytes
+    }
+
+    /// Decodes a record from its binary form.
+    ///
+    /// # Returns
+    ///
+    /// The record, or the unknown operation code.
+    pub fn from_bytes(bytes: &[u8; TRACE_RECORD_SIZE]) -> Result<Self, u8> {
+        let word = |offset: usize| {
+            Ps2Float::new(u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()))
+        };
+
+        Ok(Self {
+            operation: *Operation::ALL.get(bytes[0] as usize).ok_or(bytes[0])?,
+            a: word(1),
+            b: word(5),
+            result: word(9),
+            flags: bytes[13],
+        })
+    }
+}
+
+impl<W: Write> TraceWriter<W> {
+    /// Creates a new trace writer and writes the header.
+    pub fn new(mut inner: W) -> Result<Self, TraceError> {
+        inner.write_all(&TRACE_MAGIC).map_err(TraceError::Io)?;
+        inner.write_all(&[TRACE_VERSION]).map_err(TraceError::Io)?;
+        Ok(Self { inner })
+    }
+
+    /// Appends a record to the trace.
+    pub fn write(&mut self, record: &TraceRecord) -> Result<(), TraceError> {
+        self.inner.write_all(&record.to_bytes()).map_err(TraceError::Io)
+    }
+
+    /// Flushes the trace and returns the underlying writer.
+    pub fn into_inner(mut self) -> Result<W, TraceError> {
+        self.inner.flush().map_err(TraceError::Io)?;
+        Ok(self.inner)
+    }
+}
+
+impl<R: Read> TraceReader<R> {
+    /// Creates a new trace reader and checks the header.
+    pub fn new(mut inner: R) -> Result<Self, TraceError> {
+        let mut header = [0; 5];
+        inner.read_exact(&mut header).map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => TraceError::InvalidMagic,
+            _ => TraceError::Io(e),
+        })?;
+
+        if header[..4] != TRACE_MAGIC {
+            return Err(TraceError::InvalidMagic);
+        }
+        if header[4] != TRACE_VERSION {
+            return Err(TraceError::UnsupportedVersion(header[4]));
+        }
+
+        Ok(Self { inner, index: 0, done: false })
+    }
+
+    /// Reads the next record, or `None` at the end of the trace.
+    fn read_record(&mut self) -> Result<Option<TraceRecord>, TraceError> {
+        let mut bytes = [0; TRACE_RECORD_SIZE];
+        let mut filled = 0;
+        while filled < TRACE_RECORD_SIZE {
+            match self.inner.read(&mut bytes[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(TraceError::Truncated { index: self.index }),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(TraceError::Io(e)),
+            }
+        }
+
+        let record = TraceRecord::from_bytes(&bytes)
+            .map_err(|code| TraceError::InvalidOperation { index: self.index, code })?;
+        self.index += 1;
+        Ok(Some(record))
+    }
+}
+
+impl<R: Read> Iterator for TraceReader<R> {
+    type Item = Result<TraceRecord, TraceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let record = self.read_record().transpose();
+        self.done = !matches!(record, Some(Ok(_)));
+        record
+    }
+}
+
+/// Replays every record of a trace and collects the mismatching results.
+///
+/// Only results are re-verified, recorded flags are kept as-is.
+///
+/// # Arguments
+///
+/// * `reader` - The trace to replay.
+///
+/// # Returns
+///
+/// A [`TraceReplayReport`], or the first error reading the trace.
+pub fn replay_trace<R: Read>(reader: TraceReader<R>) -> Result<TraceReplayReport, TraceError> {
+    let mut report = TraceReplayReport::default();
+
+    for (index, record) in reader.enumerate() {
+        let record = record?;
+        let actual = record.replay();
+        if actual == record.result {
+            report.matched += 1;
+        } else {
+            report.mismatches.push(TraceMismatch { index: index as u64, record, actual });
+        }
+    }
+
+    Ok(report)
+}
+
+/// Returns the code of the operation in a record, its index in [`Operation::ALL`].
+fn operation_code(operation: Operation) -> u8 {
+    Operation::ALL.iter().position(|op| *op == operation).unwrap() as u8
+}
+
+impl TraceReplayReport {
+    /// Returns if every replayed result matched.
+    pub fn is_success(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl Display for TraceMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{}: 0x{:08X} {} 0x{:08X} recorded 0x{:08X} ({}) but replayed 0x{:08X} ({})",
+            self.index,
+            self.record.a.as_u32(),
+            self.record.operation.symbol(),
+            self.record.b.as_u32(),
+            self.record.result.as_u32(),
+            self.record.result,
+            self.actual.as_u32(),
+            self.actual
+        )
+    }
+}
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceError::Io(e) => write!(f, "failed to access trace: {}", e),
+            TraceError::InvalidMagic => write!(f, "not a trace, missing 'PS2T' magic"),
+            TraceError::UnsupportedVersion(version) => {
+                write!(f, "unsupported trace version {}", version)
+            }
+            TraceError::InvalidOperation { index, code } => {
+                write!(f, "record {}: invalid operation code {}", index, code)
+            }
+            TraceError::Truncated { index } => write!(f, "record {}: truncated", index),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {}
@@ -0,0 +1,209 @@
+//! Four-lane vectors and matrices of PS2 floats, as held in VU registers.
+
+use std::fmt::{Display, Formatter};
+
+use crate::explain::Explanation;
+use crate::vu::MacFlags;
+use crate::Ps2Float;
+
+/// A vector of four PS2 floats, the `xyzw` lanes of a VU register.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2Vec4 {
+    pub x: Ps2Float,
+    pub y: Ps2Float,
+    pub z: Ps2Float,
+    pub w: Ps2Float,
+}
+
+/// A 4x4 matrix of PS2 floats stored as four quadwords, like
+/// `sceVu0FMATRIX`.
+///
+/// Vectors are transformed as rows, `v * M`, which the VU computes as
+/// `m[0] * v.x + m[1] * v.y + m[2] * v.z + m[3] * v.w`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Ps2Matrix(pub [Ps2Vec4; 4]);
+
+impl Ps2Vec4 {
+    /// Creates a new vector from its lanes.
+    pub fn new(x: Ps2Float, y: Ps2Float, z: Ps2Float, w: Ps2Float) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a new vector with the same value in every lane.
+    pub fn splat(value: Ps2Float) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Creates a new vector from the bit patterns of its lanes.
+    pub fn from_bits(bits: [u32; 4]) -> Self {
+        Self::from_lanes(bits.map(Ps2Float::new))
+    }
+
+    /// Creates a new vector from its lanes in `xyzw` order.
+    pub fn from_lanes(lanes: [Ps2Float; 4]) -> Self {
+        Self::new(lanes[0], lanes[1], lanes[2], lanes[3])
+    }
+
+    /// Returns the bit patterns of the lanes.
+    pub fn to_bits(&self) -> [u32; 4] {
+        self.lanes().map(|lane| lane.as_u32())
+    }
+
+    /// Returns the lanes in `xyzw` order.
+    pub fn lanes(&self) -> [Ps2Float; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Adds two vectors lane by lane, like ADD.xyzw.
+    pub fn add(&self, other: &Ps2Vec4) -> Self {
+        self.zip(other, |a, b| a.add(b))
+    }
+
+    /// Subtracts two vectors lane by lane, like SUB.xyzw.
+    pub fn sub(&self, other: &Ps2Vec4) -> Self {
+        self.zip(other, |a, b| a.sub(b))
+    }
+
+    /// Adds two vectors lane by lane like [`Ps2Vec4::add`], also returning the
+    /// MAC flags it raises.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ps2_floating_point::vu::MacFlags;
+    /// use ps2_floating_point::Ps2Vec4;
+    ///
+    /// let a = Ps2Vec4::from_bits([0x3F800000, 0x7FFFFFFF, 0x3F800000, 0x3F800000]);
+    /// let b = Ps2Vec4::from_bits([0xBF800000, 0x7F7FFFFF, 0xC0000000, 0x3F800000]);
+    /// let (sum, flags) = a.add_flagged(&b);
+    ///
+    /// assert_eq!(sum.to_bits(), [0x00000000, 0x7FFFFFFF, 0xBF800000, 0x40000000]);
+    /// assert_eq!(flags, MacFlags::from_bits(0x4028)); // Zx Sz Oy
+    /// ```
+    pub fn add_flagged(&self, other: &Ps2Vec4) -> (Self, MacFlags) {
+        self.zip_flagged(other, Ps2Float::add_explained)
+    }
+
+    /// Subtracts two vectors lane by lane like [`Ps2Vec4::sub`], also returning
+    /// the MAC flags it raises.
+    pub fn sub_flagged(&self, other: &Ps2Vec4) -> (Self, MacFlags) {
+        self.zip_flagged(other, Ps2Float::sub_explained)
+    }
+
+    /// Multiplies two vectors lane by lane, like MUL.xyzw.
+    pub fn mul(&self, other: &Ps2Vec4) -> Self {
+        self.zip(other, |a, b| a.mul(b))
+    }
+
+    /// Multiplies every lane by a scalar, like MULx or MULq.
+    pub fn mul_scalar(&self, scalar: &Ps2Float) -> Self {
+        self.map(|lane| lane.mul(*scalar))
+    }
+
+    /// Returns the dot product of the `xyz` lanes, multiplied lane by lane and
+    /// summed as `(x + y) + z`.
+    pub fn dot3(&self, other: &Ps2Vec4) -> Ps2Float {
+        let product = self.mul(other);
+        product.x.add(product.y).add(product.z)
+    }
+
+    /// Converts every lane to 28.4 fixed point, like FTOI4.xyzw.
+    pub fn ftoi4(&self) -> [i32; 4] {
+        self.ftoi(4)
+    }
+
+    /// Converts every lane to fixed point with the given number of fraction
+    /// bits, truncating toward zero and saturating like FTOI0/4/12/15.
+    pub fn ftoi(&self, fraction_bits: u8) -> [i32; 4] {
+        self.lanes().map(|lane| float_to_fixed(lane, fraction_bits))
+    }
+
+    /// Converts fixed point values with the given number of fraction bits to
+    /// floats, truncating mantissa bits that don't fit like ITOF0/4/12/15.
+    pub fn itof(values: [i32; 4], fraction_bits: u8) -> Self {
+        Self::from_lanes(values.map(|value| fixed_to_float(value, fraction_bits)))
+    }
+
+    /// Applies a function to every lane.
+    fn map<F: Fn(Ps2Float) -> Ps2Float>(&self, f: F) -> Self {
+        Self::from_lanes(self.lanes().map(f))
+    }
+
+    /// Applies a function to every pair of lanes.
+    fn zip<F: Fn(Ps2Float, Ps2Float) -> Ps2Float>(&self, other: &Ps2Vec4, f: F) -> Self {
+        Self::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z), f(self.w, other.w))
+    }
+
+    /// Applies a traced operation to every pair of lanes, collecting the MAC
+    /// flags of the results.
+    fn zip_flagged<F>(&self, other: &Ps2Vec4, f: F) -> (Self, MacFlags)
+    where
+        F: Fn(Ps2Float, Ps2Float) -> Explanation,
+    {
+        let (a, b) = (self.lanes(), other.lanes());
+        let mut flags = MacFlags::default();
+        let lanes = std::array::from_fn(|i| {
+            let explanation = f(a[i], b[i]);
+            flags.set_lane_from(i, &explanation);
+            explanation.result
+        });
+
+        (Self::from_lanes(lanes), flags)
+    }
+}
+
+impl Ps2Matrix {
+    /// Creates the identity matrix.
+    pub fn identity() -> Self {
+        let (zero, one) = (Ps2Float::new(0), Ps2Float::new(0x3F800000));
+        Self([
+            Ps2Vec4::new(one, zero, zero, zero),
+            Ps2Vec4::new(zero, one, zero, zero),
+            Ps2Vec4::new(zero, zero, one, zero),
+            Ps2Vec4::new(zero, zero, zero, one),
+        ])
+    }
+
+    /// Transforms a vector with the MULAx, MADDAy, MADDAz, MADDw chain.
+    ///
+    /// Every multiply-add truncates the product before adding it to the
+    /// accumulator.
+    pub fn transform(&self, v: &Ps2Vec4) -> Ps2Vec4 {
+        let acc = self.0[0].mul_scalar(&v.x);
+        let acc = acc.add(&self.0[1].mul_scalar(&v.y));
+        let acc = acc.add(&self.0[2].mul_scalar(&v.z));
+        acc.add(&self.0[3].mul_scalar(&v.w))
+    }
+}
+
+/// Converts a float to fixed point with the given number of fraction bits,
+/// truncating toward zero and saturating like the FTOI instructions.
+pub(crate) fn float_to_fixed(value: Ps2Float, fraction_bits: u8) -> i32 {
+    if value.exponent() == 0 {
+        return 0;
+    }
+
+    (value.exact_value() * (1u32 << fraction_bits) as f64) as i32
+}
+
+/// Converts a fixed point value to a float with the given number of fraction
+/// bits, truncating mantissa bits that don't fit.
+pub(crate) fn fixed_to_float(value: i32, fraction_bits: u8) -> Ps2Float {
+    if value == 0 {
+        return Ps2Float::new(0);
+    }
+
+    let magnitude = value.unsigned_abs();
+    let msb = 31 - magnitude.leading_zeros() as i32;
+    let mantissa = if msb > 23 { magnitude >> (msb - 23) } else { magnitude << (23 - msb) };
+    let exponent = 127 + msb - fraction_bits as i32;
+
+    Ps2Float::from_params(value < 0, exponent as u8, mantissa & 0x7FFFFF)
+}
+
+impl Display for Ps2Vec4 {
+    /// Formats the vector as `(x, y, z, w)`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
@@ -0,0 +1,113 @@
+//! Kani model-checking harnesses for the PS2 float arithmetic operations.
+//!
+//! The harnesses prove that the scalar operations cannot panic or overflow for
+//! any pair of 32-bit inputs, which fuzzing can only sample. Every operation
+//! wrapped in `no_panic!` has a harness here.
+//!
+//! Run with `cargo kani --features verification`.
+
+use crate::Ps2Float;
+
+/// Proves adding any two PS2 floats never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn add_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.add(b);
+}
+
+/// Proves subtracting any two PS2 floats never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn sub_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.sub(b);
+}
+
+/// Proves the result of adding any two PS2 floats is a valid 32-bit encoding.
+#[kani::proof]
+#[kani::unwind(34)]
+fn add_result_round_trips() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let result = a.add(b);
+
+    assert_eq!(Ps2Float::new(result.as_u32()), result);
+}
+
+/// Proves multiplying any two PS2 floats never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn mul_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.mul(b);
+}
+
+/// Proves dividing any two PS2 floats never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn div_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.div(b);
+}
+
+/// Proves taking the square root of any PS2 float never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn sqrt_never_panics() {
+    let a = Ps2Float::new(kani::any());
+
+    let _ = a.sqrt();
+}
+
+/// Proves dividing any PS2 float by the square root of another never panics
+/// or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn rsqrt_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.rsqrt(b);
+}
+
+/// Proves multiplying and adding to an accumulator never panics or overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn madd_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+    let acc = Ps2Float::new(kani::any());
+
+    let _ = a.madd(b, acc);
+}
+
+/// Proves multiplying and subtracting from an accumulator never panics or
+/// overflows.
+#[kani::proof]
+#[kani::unwind(34)]
+fn msub_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+    let acc = Ps2Float::new(kani::any());
+
+    let _ = a.msub(b, acc);
+}
+
+/// Proves comparing any two PS2 floats never panics or overflows.
+#[kani::proof]
+fn cmp_never_panics() {
+    let a = Ps2Float::new(kani::any());
+    let b = Ps2Float::new(kani::any());
+
+    let _ = a.cmp(&b);
+}
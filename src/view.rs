@@ -0,0 +1,178 @@
+//! Views walking interleaved vertex data in emulated memory.
+//!
+//! Vertex buffers interleave attributes, e.g. a position at offset 0 and a
+//! normal at offset 16 of every 32-byte vertex. A [`StridedView`] reads one
+//! attribute of every vertex in place, without copying or de-interleaving the
+//! buffer first.
+
+use std::iter::FusedIterator;
+
+use crate::{Ps2Float, Ps2Vec4};
+
+/// A value that can be read from little-endian emulated memory.
+pub trait ViewElement: Copy {
+    /// The size of the value in bytes.
+    const SIZE: usize;
+
+    /// Reads the value from exactly [`ViewElement::SIZE`] bytes.
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+impl ViewElement for Ps2Float {
+    const SIZE: usize = 4;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Ps2Float::new(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+impl ViewElement for Ps2Vec4 {
+    const SIZE: usize = 16;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Ps2Vec4::from_lanes(std::array::from_fn(|i| {
+            Ps2Float::from_le_bytes(&bytes[i * Ps2Float::SIZE..(i + 1) * Ps2Float::SIZE])
+        }))
+    }
+}
+
+/// A view of the values at `offset`, `offset + stride`, `offset + 2 * stride`
+/// and so on in emulated memory, as many as fit entirely.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::view::StridedView;
+/// use ps2_floating_point::Ps2Float;
+///
+/// // Two 8-byte vertices, each a u coordinate followed by a v coordinate.
+/// let memory = [
+///     0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40, // u = 1.0, v = 2.0
+///     0x00, 0x00, 0x40, 0x40, 0x00, 0x00, 0x80, 0x40, // u = 3.0, v = 4.0
+/// ];
+/// let v: StridedView<Ps2Float> = StridedView::new(&memory, 4, 8);
+///
+/// let bits: Vec<u32> = v.iter().map(|v| v.as_u32()).collect();
+/// assert_eq!(bits, [0x40000000, 0x40800000]);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct StridedView<'a, T> {
+    memory: &'a [u8],
+    offset: usize,
+    stride: usize,
+    len: usize,
+    element: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: ViewElement> StridedView<'a, T> {
+    /// Creates a new view over memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `memory` - The emulated memory, in little-endian byte order.
+    /// * `offset` - The byte offset of the first value.
+    /// * `stride` - The number of bytes between the starts of two values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    pub fn new(memory: &'a [u8], offset: usize, stride: usize) -> Self {
+        assert!(stride > 0, "stride must not be zero");
+
+        let len = match memory.len().checked_sub(offset + T::SIZE) {
+            Some(remaining) => remaining / stride + 1,
+            None => 0,
+        };
+
+        Self { memory, offset, stride, len, element: std::marker::PhantomData }
+    }
+
+    /// Returns the number of values in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the view has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value at an index, or `None` if it's out of range.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let start = self.offset + index * self.stride;
+        Some(T::from_le_bytes(&self.memory[start..start + T::SIZE]))
+    }
+
+    /// Returns an iterator over the values.
+    pub fn iter(&self) -> StridedIter<'a, T> {
+        StridedIter { view: *self, front: 0, back: self.len }
+    }
+}
+
+impl<'a, T: ViewElement> IntoIterator for StridedView<'a, T> {
+    type Item = T;
+    type IntoIter = StridedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: ViewElement> IntoIterator for &StridedView<'a, T> {
+    type Item = T;
+    type IntoIter = StridedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`StridedView`].
+#[derive(Debug, Clone)]
+pub struct StridedIter<'a, T> {
+    view: StridedView<'a, T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: ViewElement> Iterator for StridedIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        self.view.get(self.front - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<T: ViewElement> DoubleEndedIterator for StridedIter<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.view.get(self.back)
+    }
+}
+
+impl<T: ViewElement> ExactSizeIterator for StridedIter<'_, T> {}
+
+impl<T: ViewElement> FusedIterator for StridedIter<'_, T> {}
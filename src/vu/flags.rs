@@ -0,0 +1,291 @@
+//! The VU MAC and status flag registers.
+
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
+
+use crate::explain::{Explanation, NormalizationStep};
+use crate::DivideFlags;
+
+/// The 16-bit MAC flag register, the zero, sign, underflow and overflow bits of
+/// each lane of the last FMAC result.
+///
+/// Each group of 4 bits holds the lanes in `xyzw` order from the highest bit,
+/// the same as the `dest` field of an instruction.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MacFlags(u16);
+
+/// A bit that differs between two values of a flag register.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BitChange {
+    /// The index of the bit, 0 being the lowest.
+    pub bit: u8,
+    /// The bit in the earlier value, the later value holds its complement.
+    pub before: bool,
+}
+
+/// The 12-bit status flag register, summarizing the MAC flags and the last
+/// DIV/SQRT/RSQRT, with sticky copies that stay set until cleared.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct StatusFlags(u16);
+
+/// Sticky status flags shared between threads, such as the EE and VU threads
+/// of an emulator, accumulated without a lock.
+///
+/// Every thread keeps its own [`StatusFlags`] and records them here, setting
+/// the sticky bits of every flag raised on any thread and counting how many
+/// recorded values had each flag set.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::vu::{AtomicStatusFlags, StatusFlags};
+///
+/// let shared = AtomicStatusFlags::new();
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| shared.record(StatusFlags::from_bits(StatusFlags::OVERFLOW)));
+///     scope.spawn(|| shared.record(StatusFlags::from_bits(StatusFlags::SIGN)));
+/// });
+///
+/// assert_eq!(shared.count(StatusFlags::OVERFLOW), 1);
+/// assert_eq!(shared.take().bits(), StatusFlags::STICKY_OVERFLOW | StatusFlags::STICKY_SIGN);
+/// assert_eq!(shared.sticky().bits(), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicStatusFlags {
+    sticky: AtomicU16,
+    /// The number of recorded values with each flag set, by bit.
+    counts: [AtomicU64; 6],
+}
+
+impl MacFlags {
+    /// The zero bits of the lanes.
+    pub const ZERO: u16 = 0x000F;
+    /// The sign bits of the lanes.
+    pub const SIGN: u16 = 0x00F0;
+    /// The underflow bits of the lanes.
+    pub const UNDERFLOW: u16 = 0x0F00;
+    /// The overflow bits of the lanes.
+    pub const OVERFLOW: u16 = 0xF000;
+
+    /// Creates the MAC flags from their 16 bits.
+    pub fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the 16 bits of the MAC flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the bits that differ in `other`, from the lowest.
+    pub fn diff(&self, other: &MacFlags) -> Vec<BitChange> {
+        diff_bits(self.0 as u32, other.0 as u32)
+    }
+
+    /// Returns the zero, sign, underflow and overflow bits of a lane, 0 being
+    /// `x`.
+    pub fn lane(&self, lane: usize) -> (bool, bool, bool, bool) {
+        let bit = 3 - lane;
+        let flag = |group: u32| self.0 & (1 << (group * 4 + bit as u32)) != 0;
+        (flag(0), flag(1), flag(2), flag(3))
+    }
+
+    /// Sets the zero, sign, underflow and overflow bits of a lane, 0 being `x`.
+    pub(crate) fn set_lane(&mut self, lane: usize, flags: (bool, bool, bool, bool)) {
+        let bit = 3 - lane;
+        for (group, set) in [flags.0, flags.1, flags.2, flags.3].into_iter().enumerate() {
+            let mask = 1 << (group * 4 + bit);
+            if set {
+                self.0 |= mask;
+            } else {
+                self.0 &= !mask;
+            }
+        }
+    }
+
+    /// Sets the bits of a lane from the trace of the operation that produced
+    /// it, 0 being `x`.
+    pub(crate) fn set_lane_from(&mut self, lane: usize, explanation: &Explanation) {
+        let result = explanation.result;
+        let underflow = explanation.normalization.contains(&NormalizationStep::Underflow);
+        self.set_lane(
+            lane,
+            (result.is_denormalized(), result.sign(), underflow, explanation.overflowed()),
+        );
+    }
+
+    /// Keeps only the bits of the lanes selected by a `dest` mask, `x` being
+    /// the highest of its 4 bits.
+    pub(crate) fn masked(&self, dest: u8) -> Self {
+        Self(self.0 & ((dest as u16 & 0xF) * 0x1111))
+    }
+}
+
+impl StatusFlags {
+    /// Zero, any lane of the last FMAC result was zero.
+    pub const ZERO: u16 = 1 << 0;
+    /// Sign, any lane of the last FMAC result was negative.
+    pub const SIGN: u16 = 1 << 1;
+    /// Underflow, any lane of the last FMAC result underflowed.
+    pub const UNDERFLOW: u16 = 1 << 2;
+    /// Overflow, any lane of the last FMAC result overflowed.
+    pub const OVERFLOW: u16 = 1 << 3;
+    /// Invalid, the last DIV/SQRT/RSQRT had an invalid operand.
+    pub const INVALID: u16 = 1 << 4;
+    /// Divide by zero, the last DIV/RSQRT divided by zero.
+    pub const DIVIDE: u16 = 1 << 5;
+    /// Sticky zero, a result was zero since the flags were last cleared.
+    pub const STICKY_ZERO: u16 = Self::ZERO << 6;
+    /// Sticky sign, a result was negative since the flags were last cleared.
+    pub const STICKY_SIGN: u16 = Self::SIGN << 6;
+    /// Sticky underflow, a result underflowed since the flags were last
+    /// cleared.
+    pub const STICKY_UNDERFLOW: u16 = Self::UNDERFLOW << 6;
+    /// Sticky overflow, a result overflowed since the flags were last cleared.
+    pub const STICKY_OVERFLOW: u16 = Self::OVERFLOW << 6;
+    /// Sticky invalid, a DIV/SQRT/RSQRT had an invalid operand since the flags
+    /// were last cleared.
+    pub const STICKY_INVALID: u16 = Self::INVALID << 6;
+    /// Sticky divide by zero, a DIV/RSQRT divided by zero since the flags were
+    /// last cleared.
+    pub const STICKY_DIVIDE: u16 = Self::DIVIDE << 6;
+    /// The sticky bits, the 6 bits above shifted left by 6.
+    pub const STICKY: u16 = 0xFC0;
+
+    /// Creates the status flags from their 12 bits.
+    pub fn from_bits(bits: u16) -> Self {
+        Self(bits & 0xFFF)
+    }
+
+    /// Returns the 12 bits of the status flags.
+    pub fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns if all the given bits are set.
+    pub fn contains(&self, bits: u16) -> bool {
+        self.0 & bits == bits
+    }
+
+    /// Returns the bits that differ in `other`, from the lowest.
+    pub fn diff(&self, other: &StatusFlags) -> Vec<BitChange> {
+        diff_bits(self.0 as u32, other.0 as u32)
+    }
+
+    /// Replaces the zero, sign, underflow and overflow bits with the summary of
+    /// the MAC flags and sets their sticky bits.
+    pub(crate) fn update_from_mac(&mut self, mac: MacFlags) {
+        let mut summary = 0;
+        for (i, group) in [MacFlags::ZERO, MacFlags::SIGN, MacFlags::UNDERFLOW, MacFlags::OVERFLOW]
+            .into_iter()
+            .enumerate()
+        {
+            if mac.bits() & group != 0 {
+                summary |= 1 << i;
+            }
+        }
+
+        self.0 = (self.0 & !0xF) | summary | (summary << 6);
+    }
+
+    /// Replaces the invalid and divide by zero bits and sets their sticky bits.
+    pub(crate) fn update_divide(&mut self, flags: DivideFlags) {
+        let bits = flags.status_bits();
+        self.0 = (self.0 & !(Self::INVALID | Self::DIVIDE)) | bits | (bits << 6);
+    }
+}
+
+impl AtomicStatusFlags {
+    /// Creates shared status flags with no sticky bits set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the sticky bits of every flag set or sticky in `flags`, and counts
+    /// the flags set in it.
+    pub fn record(&self, flags: StatusFlags) {
+        let raised = (flags.0 & StatusFlags::STICKY) | ((flags.0 & !StatusFlags::STICKY) << 6);
+        // The bits and counters are independent and guard no other data, so no
+        // ordering is needed.
+        self.sticky.fetch_or(raised & StatusFlags::STICKY, Ordering::Relaxed);
+        for (bit, count) in self.counts.iter().enumerate() {
+            if flags.0 & (1 << bit) != 0 {
+                count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns the sticky bits set so far.
+    pub fn sticky(&self) -> StatusFlags {
+        StatusFlags(self.sticky.load(Ordering::Relaxed))
+    }
+
+    /// Clears the sticky bits, returning the ones that were set.
+    pub fn take(&self) -> StatusFlags {
+        StatusFlags(self.sticky.swap(0, Ordering::Relaxed))
+    }
+
+    /// Returns how many recorded values had `flag` set, one of the non-sticky
+    /// flags like [`StatusFlags::OVERFLOW`]. Any other value counts 0.
+    pub fn count(&self, flag: u16) -> u64 {
+        match self.counts.get(flag.trailing_zeros() as usize) {
+            Some(count) if flag.is_power_of_two() => count.load(Ordering::Relaxed),
+            _ => 0,
+        }
+    }
+
+    /// Resets the counts of every flag to 0, leaving the sticky bits.
+    pub fn clear_counts(&self) {
+        for count in &self.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Display for MacFlags {
+    /// Formats the set bits by flag and lane, e.g. `Zx Zy Sw`, or `-` if none.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut names = Vec::new();
+        for (group, flag) in ["Z", "S", "U", "O"].into_iter().enumerate() {
+            for (lane, name) in ["x", "y", "z", "w"].into_iter().enumerate() {
+                if self.0 & (1 << (group * 4 + 3 - lane)) != 0 {
+                    names.push(format!("{}{}", flag, name));
+                }
+            }
+        }
+
+        write!(f, "{}", join_names(names))
+    }
+}
+
+impl Display for StatusFlags {
+    /// Formats the set bits by name, then the sticky ones, e.g.
+    /// `Z S | sticky: Z S O`, with `-` for either part if none are set.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names_of = |bits: u16| {
+            let names = ["Z", "S", "U", "O", "I", "D"].into_iter().enumerate();
+            join_names(names.filter(|(bit, _)| bits & (1 << bit) != 0).map(|(_, name)| name))
+        };
+
+        write!(f, "{} | sticky: {}", names_of(self.0), names_of(self.0 >> 6))
+    }
+}
+
+/// Joins the names of the set bits with spaces, or returns `-` if none are set.
+fn join_names<S: AsRef<str>>(names: impl IntoIterator<Item = S>) -> String {
+    let names: Vec<S> = names.into_iter().collect();
+    if names.is_empty() {
+        "-".to_string()
+    } else {
+        names.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join(" ")
+    }
+}
+
+/// Returns the bits that differ between two values of a flag register.
+pub(crate) fn diff_bits(before: u32, after: u32) -> Vec<BitChange> {
+    let changed = before ^ after;
+    (0..32)
+        .filter(|bit| changed & (1 << bit) != 0)
+        .map(|bit| BitChange { bit, before: before & (1 << bit) != 0 })
+        .collect()
+}
@@ -0,0 +1,313 @@
+//! Decoding of VU upper and lower instructions.
+//!
+//! Only the float ALU, conversion, flag, Q and R register subset is decoded,
+//! which is what numeric analysis of a microprogram needs. Branches, integer
+//! arithmetic, the EFU and GIF/XGKICK transfers are reported as unsupported.
+
+use std::fmt::{Display, Formatter};
+
+use crate::{Ps2Float, Ps2Vec4};
+
+/// A lane of a vector register, selected by the `bc`, `fsf` and `ftf` fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Lane {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+/// The second operand of an upper FMAC instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Operand {
+    /// Each lane of the VF register, like ADD.
+    Register(u8),
+    /// One lane of the VF register broadcast to every lane, like ADDbc.
+    Broadcast(u8, Lane),
+    /// The Q register broadcast to every lane, like ADDq.
+    Q,
+    /// The I register broadcast to every lane, like ADDi.
+    I,
+}
+
+/// The arithmetic of an upper FMAC instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FmacOperation {
+    /// `fs + operand`.
+    Add,
+    /// `fs - operand`.
+    Sub,
+    /// `fs * operand`.
+    Mul,
+    /// `ACC + fs * operand`.
+    Madd,
+    /// `ACC - fs * operand`.
+    Msub,
+    /// The larger of `fs` and `operand`.
+    Max,
+    /// The smaller of `fs` and `operand`.
+    Mini,
+}
+
+/// A decoded upper instruction.
+///
+/// `dest` is the 4-bit lane mask with `x` in the highest bit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UpperInstruction {
+    /// NOP.
+    Nop,
+    /// An FMAC instruction, writing ACC instead of VF`fd` when `fd` is `None`
+    /// like ADDA.
+    Fmac { operation: FmacOperation, dest: u8, fd: Option<u8>, fs: u8, operand: Operand },
+    /// OPMULA, the first half of a cross product into ACC.
+    Opmula { fs: u8, ft: u8 },
+    /// OPMSUB, the second half of a cross product.
+    Opmsub { fd: u8, fs: u8, ft: u8 },
+    /// ABS, the absolute value of each lane.
+    Abs { dest: u8, ft: u8, fs: u8 },
+    /// ITOF0/4/12/15, fixed point to float.
+    Itof { dest: u8, ft: u8, fs: u8, fraction_bits: u8 },
+    /// FTOI0/4/12/15, float to fixed point.
+    Ftoi { dest: u8, ft: u8, fs: u8, fraction_bits: u8 },
+    /// CLIP, judging `fs.xyz` against `ft.w` into the clip flags.
+    Clip { fs: u8, ft: u8 },
+}
+
+/// A decoded lower instruction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LowerInstruction {
+    /// NOP.
+    Nop,
+    /// The lower word is an immediate loaded into the I register, because the
+    /// I bit of the upper instruction is set.
+    LoadI(Ps2Float),
+    /// MOVE, copying VF`fs` to VF`ft`.
+    Move { dest: u8, ft: u8, fs: u8 },
+    /// MR32, rotating VF`fs` one lane toward `x` into VF`ft`.
+    Mr32 { dest: u8, ft: u8, fs: u8 },
+    /// DIV, `Q = fs.fsf / ft.ftf`.
+    Div { fs: u8, fsf: Lane, ft: u8, ftf: Lane },
+    /// SQRT, `Q = sqrt(ft.ftf)`.
+    Sqrt { ft: u8, ftf: Lane },
+    /// RSQRT, `Q = fs.fsf / sqrt(ft.ftf)`.
+    Rsqrt { fs: u8, fsf: Lane, ft: u8, ftf: Lane },
+    /// WAITQ.
+    WaitQ,
+    /// MFIR, sign-extending VI`is` into the lanes of VF`ft`.
+    Mfir { dest: u8, ft: u8, is: u8 },
+    /// MTIR, moving the low 16 bits of `fs.fsf` into VI`it`.
+    Mtir { it: u8, fs: u8, fsf: Lane },
+    /// RNEXT, advancing R into the lanes of VF`ft`.
+    Rnext { dest: u8, ft: u8 },
+    /// RGET, reading R into the lanes of VF`ft`.
+    Rget { dest: u8, ft: u8 },
+    /// RINIT, seeding R with `fs.fsf`.
+    Rinit { fs: u8, fsf: Lane },
+    /// RXOR, mixing `fs.fsf` into R.
+    Rxor { fs: u8, fsf: Lane },
+    /// LQ, loading the quadword at `VI[is] + offset` into VF`ft`.
+    Lq { dest: u8, ft: u8, is: u8, offset: i16 },
+    /// SQ, storing VF`fs` to the quadword at `VI[it] + offset`.
+    Sq { dest: u8, fs: u8, it: u8, offset: i16 },
+    /// FSAND, `VI[it] = status & imm`.
+    Fsand { it: u8, imm: u16 },
+    /// FSEQ, `VI[it] = status == imm`.
+    Fseq { it: u8, imm: u16 },
+    /// FSOR, `VI[it] = status | imm`.
+    Fsor { it: u8, imm: u16 },
+    /// FSSET, setting the sticky status flags from `imm`.
+    Fsset { imm: u16 },
+    /// FMAND, `VI[it] = mac & VI[is]`.
+    Fmand { it: u8, is: u8 },
+    /// FMEQ, `VI[it] = mac == VI[is]`.
+    Fmeq { it: u8, is: u8 },
+    /// FMOR, `VI[it] = mac | VI[is]`.
+    Fmor { it: u8, is: u8 },
+    /// FCAND, `VI[1] = (clip & imm) != 0`.
+    Fcand { imm: u32 },
+    /// FCEQ, `VI[1] = clip == imm`.
+    Fceq { imm: u32 },
+    /// FCOR, `VI[1] = (clip | imm) == 0xFFFFFF`.
+    Fcor { imm: u32 },
+    /// FCSET, `clip = imm`.
+    Fcset { imm: u32 },
+    /// FCGET, `VI[it] = clip & 0xFFF`.
+    Fcget { it: u8 },
+}
+
+/// An instruction word outside the supported subset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UnsupportedInstruction(pub u32);
+
+/// The I bit of an upper instruction, the lower word is an immediate for I.
+pub const I_BIT: u32 = 1 << 31;
+
+/// The E bit of an upper instruction, the program ends after the next pair.
+pub const E_BIT: u32 = 1 << 30;
+
+impl Lane {
+    /// The lanes in register order.
+    pub const ALL: [Lane; 4] = [Lane::X, Lane::Y, Lane::Z, Lane::W];
+
+    /// Returns the lane of a 2-bit field, 0 being `x`.
+    pub fn from_bits(bits: u32) -> Self {
+        Self::ALL[(bits & 3) as usize]
+    }
+
+    /// Returns the index of the lane, 0 being `x`.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns the lane of a vector.
+    pub fn of(&self, vector: &Ps2Vec4) -> Ps2Float {
+        vector.lanes()[self.index()]
+    }
+}
+
+/// The fraction bits of ITOF/FTOI 0, 4, 12 and 15, selected by the low 2 bits.
+const FRACTION_BITS: [u8; 4] = [0, 4, 12, 15];
+
+/// The opcodes of the lower instructions with their function in the low 6 bits.
+const LOWER_OP: u32 = 0x40;
+
+/// Decodes an upper instruction, ignoring the I, E, M, D and T bits.
+pub fn decode_upper(word: u32) -> Result<UpperInstruction, UnsupportedInstruction> {
+    let dest = ((word >> 21) & 0xF) as u8;
+    let ft = ((word >> 16) & 0x1F) as u8;
+    let fs = ((word >> 11) & 0x1F) as u8;
+    let fd = ((word >> 6) & 0x1F) as u8;
+    let bc = Lane::from_bits(word);
+    let fraction_bits = FRACTION_BITS[bc.index()];
+
+    let fmac = |operation, fd, operand| UpperInstruction::Fmac { operation, dest, fd, fs, operand };
+    let broadcast = Operand::Broadcast(ft, bc);
+    let register = Operand::Register(ft);
+
+    let opcode = word & 0x3F;
+    let instruction = match opcode {
+        0x00..=0x0F => fmac(broadcast_operation(opcode), Some(fd), broadcast),
+        0x10..=0x13 => fmac(FmacOperation::Max, Some(fd), broadcast),
+        0x14..=0x17 => fmac(FmacOperation::Mini, Some(fd), broadcast),
+        0x18..=0x1B => fmac(FmacOperation::Mul, Some(fd), broadcast),
+        0x1C => fmac(FmacOperation::Mul, Some(fd), Operand::Q),
+        0x1D => fmac(FmacOperation::Max, Some(fd), Operand::I),
+        0x1E => fmac(FmacOperation::Mul, Some(fd), Operand::I),
+        0x1F => fmac(FmacOperation::Mini, Some(fd), Operand::I),
+        0x20..=0x27 => {
+            let (operation, operand) = q_i_form(opcode);
+            fmac(operation, Some(fd), operand)
+        }
+        0x28 => fmac(FmacOperation::Add, Some(fd), register),
+        0x29 => fmac(FmacOperation::Madd, Some(fd), register),
+        0x2A => fmac(FmacOperation::Mul, Some(fd), register),
+        0x2B => fmac(FmacOperation::Max, Some(fd), register),
+        0x2C => fmac(FmacOperation::Sub, Some(fd), register),
+        0x2D => fmac(FmacOperation::Msub, Some(fd), register),
+        0x2E => UpperInstruction::Opmsub { fd, fs, ft },
+        0x2F => fmac(FmacOperation::Mini, Some(fd), register),
+        0x3C..=0x3F => match (fd as u32) << 2 | (word & 3) {
+            special @ 0x00..=0x0F => fmac(broadcast_operation(special), None, broadcast),
+            0x10..=0x13 => UpperInstruction::Itof { dest, ft, fs, fraction_bits },
+            0x14..=0x17 => UpperInstruction::Ftoi { dest, ft, fs, fraction_bits },
+            0x18..=0x1B => fmac(FmacOperation::Mul, None, broadcast),
+            0x1C => fmac(FmacOperation::Mul, None, Operand::Q),
+            0x1D => UpperInstruction::Abs { dest, ft, fs },
+            0x1E => fmac(FmacOperation::Mul, None, Operand::I),
+            0x1F => UpperInstruction::Clip { fs, ft },
+            special @ 0x20..=0x27 => {
+                let (operation, operand) = q_i_form(special);
+                fmac(operation, None, operand)
+            }
+            0x28 => fmac(FmacOperation::Add, None, register),
+            0x29 => fmac(FmacOperation::Madd, None, register),
+            0x2A => fmac(FmacOperation::Mul, None, register),
+            0x2C => fmac(FmacOperation::Sub, None, register),
+            0x2D => fmac(FmacOperation::Msub, None, register),
+            0x2E => UpperInstruction::Opmula { fs, ft },
+            0x2F => UpperInstruction::Nop,
+            _ => return Err(UnsupportedInstruction(word)),
+        },
+        _ => return Err(UnsupportedInstruction(word)),
+    };
+
+    Ok(instruction)
+}
+
+/// Decodes a lower instruction.
+///
+/// When the I bit of the paired upper instruction is set, the lower word is an
+/// immediate instead, see [`LowerInstruction::LoadI`].
+pub fn decode_lower(word: u32) -> Result<LowerInstruction, UnsupportedInstruction> {
+    let dest = ((word >> 21) & 0xF) as u8;
+    let ft = ((word >> 16) & 0x1F) as u8;
+    let fs = ((word >> 11) & 0x1F) as u8;
+    let fsf = Lane::from_bits(word >> 21);
+    let ftf = Lane::from_bits(word >> 23);
+    let (it, is) = (ft & 0xF, fs & 0xF);
+    let offset = ((word as i16) << 5) >> 5;
+    let imm12 = (((word >> 10) & 0x800) | (word & 0x7FF)) as u16;
+    let imm24 = word & 0xFFFFFF;
+
+    let instruction = match word >> 25 {
+        0x00 => LowerInstruction::Lq { dest, ft, is, offset },
+        0x01 => LowerInstruction::Sq { dest, fs, it, offset },
+        0x10 => LowerInstruction::Fceq { imm: imm24 },
+        0x11 => LowerInstruction::Fcset { imm: imm24 },
+        0x12 => LowerInstruction::Fcand { imm: imm24 },
+        0x13 => LowerInstruction::Fcor { imm: imm24 },
+        0x14 => LowerInstruction::Fseq { it, imm: imm12 },
+        0x15 => LowerInstruction::Fsset { imm: imm12 },
+        0x16 => LowerInstruction::Fsand { it, imm: imm12 },
+        0x17 => LowerInstruction::Fsor { it, imm: imm12 },
+        0x18 => LowerInstruction::Fmeq { it, is },
+        0x1A => LowerInstruction::Fmand { it, is },
+        0x1B => LowerInstruction::Fmor { it, is },
+        0x1C => LowerInstruction::Fcget { it },
+        LOWER_OP if word & 0x3C == 0x3C => match ((word >> 6) & 0x1F, word & 3) {
+            (0x0C, 0) if word == 0x8000033C => LowerInstruction::Nop,
+            (0x0C, 0) => LowerInstruction::Move { dest, ft, fs },
+            (0x0C, 1) => LowerInstruction::Mr32 { dest, ft, fs },
+            (0x0E, 0) => LowerInstruction::Div { fs, fsf, ft, ftf },
+            (0x0E, 1) => LowerInstruction::Sqrt { ft, ftf },
+            (0x0E, 2) => LowerInstruction::Rsqrt { fs, fsf, ft, ftf },
+            (0x0E, 3) => LowerInstruction::WaitQ,
+            (0x0F, 0) => LowerInstruction::Mtir { it, fs, fsf },
+            (0x0F, 1) => LowerInstruction::Mfir { dest, ft, is },
+            (0x10, 0) => LowerInstruction::Rnext { dest, ft },
+            (0x10, 1) => LowerInstruction::Rget { dest, ft },
+            (0x10, 2) => LowerInstruction::Rinit { fs, fsf },
+            (0x10, 3) => LowerInstruction::Rxor { fs, fsf },
+            _ => return Err(UnsupportedInstruction(word)),
+        },
+        _ => return Err(UnsupportedInstruction(word)),
+    };
+
+    Ok(instruction)
+}
+
+/// Returns the operation of the broadcast forms, ADDbc through MSUBAbc.
+fn broadcast_operation(opcode: u32) -> FmacOperation {
+    [FmacOperation::Add, FmacOperation::Sub, FmacOperation::Madd, FmacOperation::Msub]
+        [(opcode >> 2) as usize & 3]
+}
+
+/// Returns the operation and operand of the Q and I forms, ADDq through MSUBAi.
+fn q_i_form(opcode: u32) -> (FmacOperation, Operand) {
+    let operation = match (opcode & 4 != 0, opcode & 1 != 0) {
+        (false, false) => FmacOperation::Add,
+        (false, true) => FmacOperation::Madd,
+        (true, false) => FmacOperation::Sub,
+        (true, true) => FmacOperation::Msub,
+    };
+
+    (operation, if opcode & 2 == 0 { Operand::Q } else { Operand::I })
+}
+
+impl Display for UnsupportedInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsupported VU instruction 0x{:08X}", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedInstruction {}
@@ -0,0 +1,758 @@
+//! An interpreter running VU microprograms limited to the float ALU subset.
+
+use std::fmt::{Display, Formatter};
+
+use crate::clip::{ClipFlags, ClipJudgment};
+use crate::explain::{Explanation, NormalizationStep};
+use crate::vu::instruction::{
+    decode_lower, decode_upper, FmacOperation, Lane, LowerInstruction, Operand,
+    UnsupportedInstruction, UpperInstruction, E_BIT, I_BIT,
+};
+use crate::vu::{MacFlags, StatusFlags, VuMem, VuRegisterFile};
+use crate::{DivideFlags, Ps2Float, Ps2Vec4};
+
+/// The `dest` mask writing `x`, `y` and `z`, used by OPMULA and OPMSUB.
+const DEST_XYZ: u8 = 0b1110;
+
+/// The number of instructions whose flags [`FlagMode::Deferred`] holds before
+/// computing them in a batch, bounding the memory of long programs.
+const MAX_PENDING_FLAGS: usize = 1024;
+
+/// The result of executing one instruction pair.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The program continues with the next pair.
+    Running,
+    /// The pair after the one with the E bit set was executed and the program
+    /// ended.
+    Ended,
+}
+
+/// When the interpreter computes the MAC and status flags of FMAC
+/// instructions.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum FlagMode {
+    /// With every instruction.
+    #[default]
+    Eager,
+    /// Only when an instruction reads or sets them, when
+    /// [`VuInterpreter::sync_flags`] is called, or in a batch once enough
+    /// instructions are pending. FMAC instructions compute only their results
+    /// until then, and the flag registers are stale.
+    Deferred,
+}
+
+/// An error stopping a microprogram.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VuError {
+    /// The pair at `pc` holds an instruction outside the supported subset.
+    Unsupported { pc: usize, instruction: UnsupportedInstruction },
+    /// The program counter ran past the end of the program without ending.
+    PcOutOfRange { pc: usize },
+    /// The program didn't end within the step limit.
+    StepLimit { steps: u64 },
+}
+
+/// Runs VU microprograms over a register file and a data memory, so programs
+/// extracted from games can be run in isolation for numeric analysis.
+///
+/// Programs are sequences of 64-bit instruction pairs, the lower instruction in
+/// the low word and the upper instruction in the high word. Both instructions
+/// of a pair read the registers as they were before the pair, and the upper
+/// instruction wins when both write the same register.
+///
+/// Every instruction completes immediately: pipeline latencies, stalls and the
+/// delayed Q result aren't modeled, so programs that rely on reading stale
+/// values compute something different than the hardware. The MAC flags of
+/// MADD and MSUB come from the add or subtract, not from the product.
+///
+/// Computing the flags takes most of the time of the FMAC instructions, so
+/// programs that never read them run faster with [`FlagMode::Deferred`].
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::vu::VuInterpreter;
+/// use ps2_floating_point::{Ps2Float, Ps2Vec4};
+///
+/// // ADD.xyzw vf3, vf1, vf2 with the E bit, then NOP/NOP.
+/// let mut vu = VuInterpreter::new(vec![0x41E2_08E8_8000_033C, 0x0000_02FF_8000_033C]);
+/// vu.registers.set_vf(1, Ps2Vec4::splat(Ps2Float::new(0x3F800000)));
+/// vu.registers.set_vf(2, Ps2Vec4::splat(Ps2Float::new(0x40000000)));
+///
+/// assert_eq!(vu.run(16), Ok(2));
+/// assert_eq!(vu.registers.vf(3), Ps2Vec4::splat(Ps2Float::new(0x40400000)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VuInterpreter {
+    /// The registers the program runs on.
+    pub registers: VuRegisterFile,
+    /// The data memory.
+    pub memory: VuMem,
+    program: Vec<Result<DecodedPair, UnsupportedInstruction>>,
+    pc: usize,
+    ending: bool,
+    flag_mode: FlagMode,
+    /// The `dest` and lanes of the FMAC instructions whose flags are deferred.
+    pending_flags: Vec<(u8, [LaneOp; 4])>,
+}
+
+/// Executes an upper instruction, returning its write to a VF register.
+type UpperHandler = fn(&mut VuInterpreter, UpperInstruction) -> PendingWrite;
+/// Executes a lower instruction, returning its write to a register the upper
+/// instruction could read.
+type LowerHandler = fn(&mut VuInterpreter, LowerInstruction) -> PendingWrite;
+
+/// An instruction pair decoded ahead of execution, with the handlers of both
+/// instructions looked up.
+#[derive(Debug, Copy, Clone)]
+struct DecodedPair {
+    upper: UpperInstruction,
+    lower: LowerInstruction,
+    upper_handler: UpperHandler,
+    lower_handler: LowerHandler,
+    /// If the lower instruction reads or sets the flags, which needs the
+    /// deferred flags computed first.
+    syncs_flags: bool,
+    /// If the E bit is set, ending the program after the next pair.
+    end: bool,
+}
+
+/// A register write held back until both instructions of a pair have read
+/// their operands.
+#[derive(Debug, Copy, Clone)]
+enum PendingWrite {
+    None,
+    Vf { index: u8, dest: u8, value: Ps2Vec4 },
+    Q(Ps2Float),
+    I(Ps2Float),
+}
+
+/// One lane of an FMAC operation, computed with or without its flags.
+#[derive(Debug, Copy, Clone)]
+enum LaneOp {
+    Add(Ps2Float, Ps2Float),
+    Sub(Ps2Float, Ps2Float),
+    Mul(Ps2Float, Ps2Float),
+    /// A result without underflow and overflow flags.
+    Value(Ps2Float),
+}
+
+/// The result of one lane of an FMAC operation with its flags.
+#[derive(Debug, Copy, Clone)]
+struct LaneResult {
+    value: Ps2Float,
+    underflow: bool,
+    overflow: bool,
+}
+
+impl VuInterpreter {
+    /// Creates an interpreter for the program with cleared registers and a
+    /// cleared VU0-sized data memory.
+    pub fn new(program: Vec<u64>) -> Self {
+        Self {
+            registers: VuRegisterFile::new(),
+            memory: VuMem::vu0(),
+            program: program.into_iter().map(decode_pair).collect(),
+            pc: 0,
+            ending: false,
+            flag_mode: FlagMode::Eager,
+            pending_flags: Vec::new(),
+        }
+    }
+
+    /// Returns when the flags of FMAC instructions are computed.
+    pub fn flag_mode(&self) -> FlagMode {
+        self.flag_mode
+    }
+
+    /// Sets when the flags of FMAC instructions are computed, computing any
+    /// deferred flags first.
+    pub fn set_flag_mode(&mut self, flag_mode: FlagMode) {
+        self.sync_flags();
+        self.flag_mode = flag_mode;
+    }
+
+    /// Computes the flags deferred by [`FlagMode::Deferred`] in program order,
+    /// bringing the MAC and status flags up to date.
+    pub fn sync_flags(&mut self) {
+        for (dest, lanes) in std::mem::take(&mut self.pending_flags) {
+            self.update_mac(dest, &lanes.map(LaneOp::flagged));
+        }
+    }
+
+    /// Returns the index of the next pair to execute.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Executes the next instruction pair.
+    pub fn step(&mut self) -> Result<StepOutcome, VuError> {
+        let pc = self.pc;
+        let pair = self.program.get(pc).ok_or(VuError::PcOutOfRange { pc })?;
+        let pair = pair.map_err(|instruction| VuError::Unsupported { pc, instruction })?;
+
+        self.dispatch(&pair);
+        self.pc += 1;
+
+        if self.ending {
+            self.ending = false;
+            return Ok(StepOutcome::Ended);
+        }
+
+        self.ending = pair.end;
+        Ok(StepOutcome::Running)
+    }
+
+    /// Executes pairs until the program ends, returning how many were executed.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_steps` - The number of pairs after which to give up.
+    pub fn run(&mut self, max_steps: u64) -> Result<u64, VuError> {
+        for steps in 1..=max_steps {
+            if self.step()? == StepOutcome::Ended {
+                return Ok(steps);
+            }
+        }
+
+        Err(VuError::StepLimit { steps: max_steps })
+    }
+
+    /// Executes a decoded instruction pair without touching the program
+    /// counter.
+    pub fn execute(&mut self, upper: UpperInstruction, lower: LowerInstruction) {
+        self.dispatch(&DecodedPair::new(upper, lower, false));
+    }
+
+    /// Runs the handlers of both instructions of a pair.
+    ///
+    /// The lower instruction runs first, holding back the writes the upper
+    /// instruction could read, so both read the registers as they were before
+    /// the pair without copying them. The upper writes land last and win.
+    fn dispatch(&mut self, pair: &DecodedPair) {
+        if pair.syncs_flags {
+            self.sync_flags();
+        }
+
+        let lower = (pair.lower_handler)(self, pair.lower);
+        let upper = (pair.upper_handler)(self, pair.upper);
+        self.apply(lower);
+        self.apply(upper);
+    }
+
+    fn apply(&mut self, write: PendingWrite) {
+        match write {
+            PendingWrite::None => {}
+            PendingWrite::Vf { index, dest, value } => self.write_vf(index, dest, value),
+            PendingWrite::Q(value) => self.registers.q = value,
+            PendingWrite::I(value) => self.registers.i = value,
+        }
+    }
+
+    /// Returns the quadword address of a base register plus an offset, which
+    /// the data memory wraps around.
+    fn address(&self, base: u16, offset: i16) -> usize {
+        (base as i16).wrapping_add(offset) as u16 as usize
+    }
+
+    fn write_vf(&mut self, index: u8, dest: u8, value: Ps2Vec4) {
+        let old = self.registers.vf(index as usize);
+        self.registers.set_vf(index as usize, masked(old, value, dest));
+    }
+
+    /// Computes the lanes of a flag-setting FMAC operation, updating the flags
+    /// from them or deferring that depending on the flag mode.
+    fn compute(&mut self, dest: u8, lanes: [LaneOp; 4]) -> Ps2Vec4 {
+        match self.flag_mode {
+            FlagMode::Eager => {
+                let lanes = lanes.map(LaneOp::flagged);
+                self.update_mac(dest, &lanes);
+                lanes_vector(&lanes)
+            }
+            FlagMode::Deferred => self.defer(dest, lanes),
+        }
+    }
+
+    /// Computes the results of the lanes and queues their flags.
+    fn defer(&mut self, dest: u8, lanes: [LaneOp; 4]) -> Ps2Vec4 {
+        if self.pending_flags.len() == MAX_PENDING_FLAGS {
+            self.sync_flags();
+        }
+        self.pending_flags.push((dest, lanes));
+        Ps2Vec4::from_lanes(lanes.map(LaneOp::value))
+    }
+
+    /// Replaces the MAC flags with the flags of the written lanes and updates
+    /// the status flags from them.
+    fn update_mac(&mut self, dest: u8, lanes: &[LaneResult; 4]) {
+        let mut mac = MacFlags::default();
+        for (i, lane) in lanes.iter().enumerate() {
+            if dest & (8 >> i) != 0 {
+                let zero = lane.value.is_denormalized();
+                mac.set_lane(i, (zero, lane.value.sign(), lane.underflow, lane.overflow));
+            }
+        }
+
+        self.registers.mac_flags = mac;
+        self.registers.status_flags.update_from_mac(mac);
+    }
+}
+
+/// The handlers of the dispatch table, one per instruction. Each handler is
+/// only ever called with the instruction it was looked up for.
+impl VuInterpreter {
+    /// Returns the handler of an upper instruction.
+    fn upper_handler(upper: &UpperInstruction) -> UpperHandler {
+        match upper {
+            UpperInstruction::Nop => Self::upper_nop,
+            UpperInstruction::Fmac { .. } => Self::fmac,
+            UpperInstruction::Opmula { .. } => Self::opmula,
+            UpperInstruction::Opmsub { .. } => Self::opmsub,
+            UpperInstruction::Abs { .. } => Self::abs,
+            UpperInstruction::Itof { .. } => Self::itof,
+            UpperInstruction::Ftoi { .. } => Self::ftoi,
+            UpperInstruction::Clip { .. } => Self::clip,
+        }
+    }
+
+    /// Returns the handler of a lower instruction.
+    fn lower_handler(lower: &LowerInstruction) -> LowerHandler {
+        match lower {
+            LowerInstruction::Nop | LowerInstruction::WaitQ => Self::lower_nop,
+            LowerInstruction::LoadI(_) => Self::load_i,
+            LowerInstruction::Move { .. } => Self::move_vf,
+            LowerInstruction::Mr32 { .. } => Self::mr32,
+            LowerInstruction::Div { .. } => Self::div,
+            LowerInstruction::Sqrt { .. } => Self::sqrt,
+            LowerInstruction::Rsqrt { .. } => Self::rsqrt,
+            LowerInstruction::Mfir { .. } => Self::mfir,
+            LowerInstruction::Mtir { .. } => Self::mtir,
+            LowerInstruction::Rnext { .. } => Self::rnext,
+            LowerInstruction::Rget { .. } => Self::rget,
+            LowerInstruction::Rinit { .. } => Self::rinit,
+            LowerInstruction::Rxor { .. } => Self::rxor,
+            LowerInstruction::Lq { .. } => Self::lq,
+            LowerInstruction::Sq { .. } => Self::sq,
+            LowerInstruction::Fsand { .. } => Self::fsand,
+            LowerInstruction::Fseq { .. } => Self::fseq,
+            LowerInstruction::Fsor { .. } => Self::fsor,
+            LowerInstruction::Fsset { .. } => Self::fsset,
+            LowerInstruction::Fmand { .. } => Self::fmand,
+            LowerInstruction::Fmeq { .. } => Self::fmeq,
+            LowerInstruction::Fmor { .. } => Self::fmor,
+            LowerInstruction::Fcand { .. } => Self::fcand,
+            LowerInstruction::Fceq { .. } => Self::fceq,
+            LowerInstruction::Fcor { .. } => Self::fcor,
+            LowerInstruction::Fcset { .. } => Self::fcset,
+            LowerInstruction::Fcget { .. } => Self::fcget,
+        }
+    }
+
+    fn upper_nop(&mut self, _: UpperInstruction) -> PendingWrite {
+        PendingWrite::None
+    }
+
+    fn fmac(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Fmac { operation, dest, fd, fs, operand } = upper else {
+            unreachable!()
+        };
+        let fs = self.registers.vf(fs as usize).lanes();
+        let operand = operand_lanes(&self.registers, operand);
+        let acc = self.registers.acc.lanes();
+        let sets_flags = !matches!(operation, FmacOperation::Max | FmacOperation::Mini);
+
+        let result = if sets_flags && self.flag_mode == FlagMode::Deferred {
+            let lanes: [LaneOp; 4] =
+                std::array::from_fn(|i| fmac_op(operation, acc[i], fs[i], operand[i]));
+            self.defer(dest, lanes)
+        } else {
+            let lanes: [LaneResult; 4] =
+                std::array::from_fn(|i| fmac_lane(operation, acc[i], fs[i], operand[i]));
+            if sets_flags {
+                self.update_mac(dest, &lanes);
+            }
+            lanes_vector(&lanes)
+        };
+        match fd {
+            Some(fd) => PendingWrite::Vf { index: fd, dest, value: result },
+            None => {
+                self.registers.acc = masked(self.registers.acc, result, dest);
+                PendingWrite::None
+            }
+        }
+    }
+
+    fn opmula(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Opmula { fs, ft } = upper else { unreachable!() };
+        let lanes = cross_lanes(&self.registers, fs, ft, |_, a, b| LaneOp::Mul(a, b));
+        let result = self.compute(DEST_XYZ, lanes);
+        self.registers.acc = masked(self.registers.acc, result, DEST_XYZ);
+        PendingWrite::None
+    }
+
+    fn opmsub(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Opmsub { fd, fs, ft } = upper else { unreachable!() };
+        let lanes = cross_lanes(&self.registers, fs, ft, |acc, a, b| LaneOp::Sub(acc, a.mul(b)));
+        let result = self.compute(DEST_XYZ, lanes);
+        PendingWrite::Vf { index: fd, dest: DEST_XYZ, value: result }
+    }
+
+    fn abs(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Abs { dest, ft, fs } = upper else { unreachable!() };
+        let bits = self.registers.vf(fs as usize).to_bits().map(|bits| bits & 0x7FFFFFFF);
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::from_bits(bits) }
+    }
+
+    fn itof(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Itof { dest, ft, fs, fraction_bits } = upper else { unreachable!() };
+        let values = self.registers.vf(fs as usize).to_bits().map(|bits| bits as i32);
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::itof(values, fraction_bits) }
+    }
+
+    fn ftoi(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Ftoi { dest, ft, fs, fraction_bits } = upper else { unreachable!() };
+        let values = self.registers.vf(fs as usize).ftoi(fraction_bits);
+        let value = Ps2Vec4::from_bits(values.map(|value| value as u32));
+        PendingWrite::Vf { index: ft, dest, value }
+    }
+
+    fn clip(&mut self, upper: UpperInstruction) -> PendingWrite {
+        let UpperInstruction::Clip { fs, ft } = upper else { unreachable!() };
+        let w = Lane::W.of(&self.registers.vf(ft as usize));
+        let judgment = ClipJudgment::against(&self.registers.vf(fs as usize), &w);
+        self.registers.clip_flags.push(judgment);
+        PendingWrite::None
+    }
+
+    fn lower_nop(&mut self, _: LowerInstruction) -> PendingWrite {
+        PendingWrite::None
+    }
+
+    fn load_i(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::LoadI(value) = lower else { unreachable!() };
+        PendingWrite::I(value)
+    }
+
+    fn move_vf(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Move { dest, ft, fs } = lower else { unreachable!() };
+        PendingWrite::Vf { index: ft, dest, value: self.registers.vf(fs as usize) }
+    }
+
+    fn mr32(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Mr32 { dest, ft, fs } = lower else { unreachable!() };
+        let [x, y, z, w] = self.registers.vf(fs as usize).lanes();
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::new(y, z, w, x) }
+    }
+
+    fn div(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Div { fs, fsf, ft, ftf } = lower else { unreachable!() };
+        let (dividend, divisor) = (self.lane(fs, fsf), self.lane(ft, ftf));
+        self.registers.status_flags.update_divide(DivideFlags::of_div(dividend, divisor));
+        PendingWrite::Q(dividend.div(divisor))
+    }
+
+    fn sqrt(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Sqrt { ft, ftf } = lower else { unreachable!() };
+        let value = self.lane(ft, ftf);
+        self.registers.status_flags.update_divide(DivideFlags::of_sqrt(value));
+        PendingWrite::Q(value.sqrt())
+    }
+
+    fn rsqrt(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Rsqrt { fs, fsf, ft, ftf } = lower else { unreachable!() };
+        let (dividend, divisor) = (self.lane(fs, fsf), self.lane(ft, ftf));
+        self.registers.status_flags.update_divide(DivideFlags::of_rsqrt(divisor));
+        PendingWrite::Q(dividend.rsqrt(divisor))
+    }
+
+    fn mfir(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Mfir { dest, ft, is } = lower else { unreachable!() };
+        let value = self.registers.vi(is as usize) as i16 as i32 as u32;
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::from_bits([value; 4]) }
+    }
+
+    fn mtir(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Mtir { it, fs, fsf } = lower else { unreachable!() };
+        self.registers.set_vi(it as usize, self.lane(fs, fsf).as_u32() as u16);
+        PendingWrite::None
+    }
+
+    fn rnext(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Rnext { dest, ft } = lower else { unreachable!() };
+        let value = self.registers.r.rnext();
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::splat(value) }
+    }
+
+    fn rget(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Rget { dest, ft } = lower else { unreachable!() };
+        PendingWrite::Vf { index: ft, dest, value: Ps2Vec4::splat(self.registers.r.rget()) }
+    }
+
+    fn rinit(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Rinit { fs, fsf } = lower else { unreachable!() };
+        let value = self.lane(fs, fsf);
+        self.registers.r.rinit(&value);
+        PendingWrite::None
+    }
+
+    fn rxor(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Rxor { fs, fsf } = lower else { unreachable!() };
+        let value = self.lane(fs, fsf);
+        self.registers.r.rxor(&value);
+        PendingWrite::None
+    }
+
+    fn lq(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Lq { dest, ft, is, offset } = lower else { unreachable!() };
+        let address = self.address(self.registers.vi(is as usize), offset);
+        PendingWrite::Vf { index: ft, dest, value: self.memory.load(address) }
+    }
+
+    fn sq(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Sq { dest, fs, it, offset } = lower else { unreachable!() };
+        let address = self.address(self.registers.vi(it as usize), offset);
+        let value = masked(self.memory.load(address), self.registers.vf(fs as usize), dest);
+        self.memory.store(address, value);
+        PendingWrite::None
+    }
+
+    fn fsand(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fsand { it, imm } = lower else { unreachable!() };
+        self.registers.set_vi(it as usize, self.registers.status_flags.bits() & imm);
+        PendingWrite::None
+    }
+
+    fn fseq(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fseq { it, imm } = lower else { unreachable!() };
+        self.registers.set_vi(it as usize, (self.registers.status_flags.bits() == imm) as u16);
+        PendingWrite::None
+    }
+
+    fn fsor(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fsor { it, imm } = lower else { unreachable!() };
+        self.registers.set_vi(it as usize, self.registers.status_flags.bits() | imm);
+        PendingWrite::None
+    }
+
+    fn fsset(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fsset { imm } = lower else { unreachable!() };
+        let status = self.registers.status_flags.bits();
+        let bits = (status & !StatusFlags::STICKY) | (imm & StatusFlags::STICKY);
+        self.registers.status_flags = StatusFlags::from_bits(bits);
+        PendingWrite::None
+    }
+
+    fn fmand(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fmand { it, is } = lower else { unreachable!() };
+        let value = self.registers.mac_flags.bits() & self.registers.vi(is as usize);
+        self.registers.set_vi(it as usize, value);
+        PendingWrite::None
+    }
+
+    fn fmeq(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fmeq { it, is } = lower else { unreachable!() };
+        let value = self.registers.mac_flags.bits() == self.registers.vi(is as usize);
+        self.registers.set_vi(it as usize, value as u16);
+        PendingWrite::None
+    }
+
+    fn fmor(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fmor { it, is } = lower else { unreachable!() };
+        let value = self.registers.mac_flags.bits() | self.registers.vi(is as usize);
+        self.registers.set_vi(it as usize, value);
+        PendingWrite::None
+    }
+
+    fn fcand(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fcand { imm } = lower else { unreachable!() };
+        self.registers.set_vi(1, (self.registers.clip_flags.bits() & imm != 0) as u16);
+        PendingWrite::None
+    }
+
+    fn fceq(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fceq { imm } = lower else { unreachable!() };
+        self.registers.set_vi(1, (self.registers.clip_flags.bits() == imm) as u16);
+        PendingWrite::None
+    }
+
+    fn fcor(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fcor { imm } = lower else { unreachable!() };
+        let all = (self.registers.clip_flags.bits() | imm) & 0xFFFFFF == 0xFFFFFF;
+        self.registers.set_vi(1, all as u16);
+        PendingWrite::None
+    }
+
+    fn fcset(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fcset { imm } = lower else { unreachable!() };
+        self.registers.clip_flags = ClipFlags::from_bits(imm);
+        PendingWrite::None
+    }
+
+    fn fcget(&mut self, lower: LowerInstruction) -> PendingWrite {
+        let LowerInstruction::Fcget { it } = lower else { unreachable!() };
+        self.registers.set_vi(it as usize, (self.registers.clip_flags.bits() & 0xFFF) as u16);
+        PendingWrite::None
+    }
+
+    /// Returns a lane of a VF register.
+    fn lane(&self, register: u8, lane: Lane) -> Ps2Float {
+        lane.of(&self.registers.vf(register as usize))
+    }
+}
+
+/// Decodes both instructions of a pair, the lower word being the value loaded
+/// into I when the I bit is set.
+fn decode_pair(pair: u64) -> Result<DecodedPair, UnsupportedInstruction> {
+    let (upper_word, lower_word) = ((pair >> 32) as u32, pair as u32);
+
+    let upper = decode_upper(upper_word)?;
+    let lower = if upper_word & I_BIT != 0 {
+        LowerInstruction::LoadI(Ps2Float::new(lower_word))
+    } else {
+        decode_lower(lower_word)?
+    };
+
+    Ok(DecodedPair::new(upper, lower, upper_word & E_BIT != 0))
+}
+
+impl DecodedPair {
+    fn new(upper: UpperInstruction, lower: LowerInstruction, end: bool) -> Self {
+        DecodedPair {
+            upper,
+            lower,
+            upper_handler: VuInterpreter::upper_handler(&upper),
+            lower_handler: VuInterpreter::lower_handler(&lower),
+            syncs_flags: accesses_flags(lower),
+            end,
+        }
+    }
+}
+
+/// Returns the lanes of the second operand of an FMAC instruction.
+fn operand_lanes(registers: &VuRegisterFile, operand: Operand) -> [Ps2Float; 4] {
+    match operand {
+        Operand::Register(ft) => registers.vf(ft as usize).lanes(),
+        Operand::Broadcast(ft, lane) => [lane.of(&registers.vf(ft as usize)); 4],
+        Operand::Q => [registers.q; 4],
+        Operand::I => [registers.i; 4],
+    }
+}
+
+/// Returns if a lower instruction reads or sets the MAC or status flags, which
+/// needs the deferred flags.
+fn accesses_flags(lower: LowerInstruction) -> bool {
+    matches!(
+        lower,
+        LowerInstruction::Fsand { .. }
+            | LowerInstruction::Fseq { .. }
+            | LowerInstruction::Fsor { .. }
+            | LowerInstruction::Fsset { .. }
+            | LowerInstruction::Fmand { .. }
+            | LowerInstruction::Fmeq { .. }
+            | LowerInstruction::Fmor { .. }
+    )
+}
+
+/// Computes one lane of an FMAC operation.
+fn fmac_lane(operation: FmacOperation, acc: Ps2Float, a: Ps2Float, b: Ps2Float) -> LaneResult {
+    match operation {
+        FmacOperation::Add => flagged(a.add_explained(b)),
+        FmacOperation::Sub => flagged(a.sub_explained(b)),
+        FmacOperation::Mul => product(a, b),
+        FmacOperation::Madd => flagged(acc.add_explained(a.mul(b))),
+        FmacOperation::Msub => flagged(acc.sub_explained(a.mul(b))),
+        FmacOperation::Max => unflagged(a.max_of(b)),
+        FmacOperation::Mini => unflagged(a.min_of(b)),
+    }
+}
+
+/// Returns one lane of an FMAC operation with its flags left to compute,
+/// computing only the products.
+fn fmac_op(operation: FmacOperation, acc: Ps2Float, a: Ps2Float, b: Ps2Float) -> LaneOp {
+    match operation {
+        FmacOperation::Add => LaneOp::Add(a, b),
+        FmacOperation::Sub => LaneOp::Sub(a, b),
+        FmacOperation::Mul => LaneOp::Mul(a, b),
+        FmacOperation::Madd => LaneOp::Add(acc, a.mul(b)),
+        FmacOperation::Msub => LaneOp::Sub(acc, a.mul(b)),
+        FmacOperation::Max => LaneOp::Value(a.max_of(b)),
+        FmacOperation::Mini => LaneOp::Value(a.min_of(b)),
+    }
+}
+
+/// Computes the `xyz` lanes of OPMULA/OPMSUB, combining each lane of ACC with
+/// the factors `fs.yzx` and `ft.zxy`.
+fn cross_lanes<F>(registers: &VuRegisterFile, fs: u8, ft: u8, combine: F) -> [LaneOp; 4]
+where
+    F: Fn(Ps2Float, Ps2Float, Ps2Float) -> LaneOp,
+{
+    let [x, y, z, _] = registers.vf(fs as usize).lanes();
+    let [tx, ty, tz, _] = registers.vf(ft as usize).lanes();
+    let acc = registers.acc.lanes();
+
+    [combine(acc[0], y, tz), combine(acc[1], z, tx), combine(acc[2], x, ty), LaneOp::Value(acc[3])]
+}
+
+fn lanes_vector(lanes: &[LaneResult; 4]) -> Ps2Vec4 {
+    Ps2Vec4::from_lanes(lanes.map(|lane| lane.value))
+}
+
+impl LaneOp {
+    /// Computes the result without its flags.
+    fn value(self) -> Ps2Float {
+        match self {
+            LaneOp::Add(a, b) => a.add(b),
+            LaneOp::Sub(a, b) => a.sub(b),
+            LaneOp::Mul(a, b) => a.mul(b),
+            LaneOp::Value(value) => value,
+        }
+    }
+
+    /// Computes the result with its overflow and underflow.
+    fn flagged(self) -> LaneResult {
+        match self {
+            LaneOp::Add(a, b) => flagged(a.add_explained(b)),
+            LaneOp::Sub(a, b) => flagged(a.sub_explained(b)),
+            LaneOp::Mul(a, b) => product(a, b),
+            LaneOp::Value(value) => unflagged(value),
+        }
+    }
+}
+
+/// Returns the lanes of `new` selected by `dest` and the rest from `old`.
+fn masked(old: Ps2Vec4, new: Ps2Vec4, dest: u8) -> Ps2Vec4 {
+    let (old, new) = (old.lanes(), new.lanes());
+    Ps2Vec4::from_lanes(std::array::from_fn(|i| if dest & (8 >> i) != 0 { new[i] } else { old[i] }))
+}
+
+/// Returns the result of a traced operation with its overflow and underflow.
+fn flagged(explanation: Explanation) -> LaneResult {
+    LaneResult {
+        value: explanation.result,
+        underflow: explanation.normalization.contains(&NormalizationStep::Underflow),
+        overflow: explanation.overflowed(),
+    }
+}
+
+/// Returns the product of two floats with its overflow and underflow.
+fn product(a: Ps2Float, b: Ps2Float) -> LaneResult {
+    let (value, flags) = a.mul_with_flags(b);
+    LaneResult { value, underflow: flags.underflow, overflow: flags.overflow }
+}
+
+fn unflagged(value: Ps2Float) -> LaneResult {
+    LaneResult { value, underflow: false, overflow: false }
+}
+
+impl Display for VuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VuError::Unsupported { pc, instruction } => write!(f, "{} at pc {}", instruction, pc),
+            VuError::PcOutOfRange { pc } => write!(f, "pc {} is past the end of the program", pc),
+            VuError::StepLimit { steps } => write!(f, "program didn't end within {} steps", steps),
+        }
+    }
+}
+
+impl std::error::Error for VuError {}
@@ -0,0 +1,98 @@
+//! The VU data memory.
+
+use crate::view::StridedView;
+use crate::Ps2Vec4;
+
+/// The data memory of a VU, 4KB on VU0 and 16KB on VU1, holding quadwords.
+///
+/// Addresses count quadwords, like the `vi` registers LQ and SQ use, and wrap
+/// around the size of the memory.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::vu::VuMem;
+/// use ps2_floating_point::Ps2Vec4;
+///
+/// let mut memory = VuMem::vu0();
+/// memory.store(0x100, Ps2Vec4::from_bits([1, 2, 3, 4]));
+///
+/// assert_eq!(memory.load(0).to_bits(), [1, 2, 3, 4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VuMem {
+    quadwords: Vec<Ps2Vec4>,
+}
+
+impl VuMem {
+    /// The size of the VU0 data memory in quadwords, 4KB.
+    pub const VU0_QUADWORDS: usize = 256;
+
+    /// The size of the VU1 data memory in quadwords, 16KB.
+    pub const VU1_QUADWORDS: usize = 1024;
+
+    /// Creates a cleared data memory holding the given number of quadwords.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quadwords` is zero.
+    pub fn new(quadwords: usize) -> Self {
+        assert!(quadwords > 0, "VU data memory must not be empty");
+        Self { quadwords: vec![Ps2Vec4::default(); quadwords] }
+    }
+
+    /// Creates a cleared VU0 data memory.
+    pub fn vu0() -> Self {
+        Self::new(Self::VU0_QUADWORDS)
+    }
+
+    /// Creates a cleared VU1 data memory.
+    pub fn vu1() -> Self {
+        Self::new(Self::VU1_QUADWORDS)
+    }
+
+    /// Creates a data memory from a little-endian dump, such as a save state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dump is empty or isn't a whole number of quadwords.
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        assert!(
+            bytes.len().is_multiple_of(16),
+            "VU data memory dump isn't a whole number of quadwords"
+        );
+        let mut memory = Self::new(bytes.len() / 16);
+        for (address, quadword) in StridedView::new(bytes, 0, 16).iter().enumerate() {
+            memory.quadwords[address] = quadword;
+        }
+
+        memory
+    }
+
+    /// Returns the size of the memory in quadwords.
+    pub fn quadwords(&self) -> usize {
+        self.quadwords.len()
+    }
+
+    /// Loads the quadword at an address, wrapping around the memory.
+    pub fn load(&self, address: usize) -> Ps2Vec4 {
+        self.quadwords[address % self.quadwords.len()]
+    }
+
+    /// Stores a quadword at an address, wrapping around the memory.
+    pub fn store(&mut self, address: usize, value: Ps2Vec4) {
+        let len = self.quadwords.len();
+        self.quadwords[address % len] = value;
+    }
+
+    /// Returns the quadwords of the memory.
+    pub fn as_slice(&self) -> &[Ps2Vec4] {
+        &self.quadwords
+    }
+}
+
+impl Default for VuMem {
+    fn default() -> Self {
+        Self::vu0()
+    }
+}
@@ -0,0 +1,14 @@
+//! Vector unit (VU0/VU1) state and instructions.
+
+pub(crate) mod flags;
+pub mod instruction;
+mod interpreter;
+mod memory;
+mod r_register;
+mod registers;
+
+pub use flags::{AtomicStatusFlags, BitChange, MacFlags, StatusFlags};
+pub use interpreter::{FlagMode, StepOutcome, VuError, VuInterpreter};
+pub use memory::VuMem;
+pub use r_register::RRegister;
+pub use registers::{RegisterChange, VuRegister, VuRegisterFile, VuRegisterValue};
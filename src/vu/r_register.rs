@@ -0,0 +1,70 @@
+//! The VU's R register pseudo-random number generator.
+
+use crate::Ps2Float;
+
+/// The R register, a 23-bit LFSR exposed as a float in `[1, 2)`.
+///
+/// The exponent is always that of 1.0 and only the mantissa holds state, so
+/// every value read from it is `1.0 + random / 2^23`. Particle systems seeded
+/// through R need the exact sequence to compare deterministically.
+///
+/// # Examples
+///
+/// ```
+/// use ps2_floating_point::vu::RRegister;
+/// use ps2_floating_point::Ps2Float;
+///
+/// let mut r = RRegister::new();
+/// r.rinit(&Ps2Float::new(0x12345));
+///
+/// assert_eq!(r.rnext().as_u32(), 0x3F82468A);
+/// assert_eq!(r.rget().as_u32(), 0x3F82468A);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RRegister {
+    bits: u32,
+}
+
+impl RRegister {
+    /// The bits of 1.0, the fixed exponent of the register.
+    const ONE: u32 = 0x3F800000;
+
+    /// The mask of the mantissa holding the state.
+    const MANTISSA_MASK: u32 = 0x007FFFFF;
+
+    /// Creates a new R register holding 1.0.
+    pub fn new() -> Self {
+        Self { bits: Self::ONE }
+    }
+
+    /// Seeds the register with the mantissa of a float, like RINIT.
+    pub fn rinit(&mut self, seed: &Ps2Float) {
+        self.bits = Self::ONE | (seed.as_u32() & Self::MANTISSA_MASK);
+    }
+
+    /// Mixes the mantissa of a float into the register, like RXOR.
+    pub fn rxor(&mut self, value: &Ps2Float) {
+        self.bits = Self::ONE | ((self.bits ^ value.as_u32()) & Self::MANTISSA_MASK);
+    }
+
+    /// Returns the register without advancing it, like RGET.
+    pub fn rget(&self) -> Ps2Float {
+        Ps2Float::new(self.bits)
+    }
+
+    /// Advances the LFSR and returns the new value, like RNEXT.
+    ///
+    /// The register shifts left by one and feeds back bit 4 XOR bit 22 into
+    /// bit 0.
+    pub fn rnext(&mut self) -> Ps2Float {
+        let feedback = ((self.bits >> 4) ^ (self.bits >> 22)) & 1;
+        self.bits = Self::ONE | (((self.bits << 1) ^ feedback) & Self::MANTISSA_MASK);
+        self.rget()
+    }
+}
+
+impl Default for RRegister {
+    fn default() -> Self {
+        Self::new()
+    }
+}
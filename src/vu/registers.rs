@@ -0,0 +1,335 @@
+//! The VU register file.
+
+use std::fmt::{Display, Formatter};
+
+use crate::clip::ClipFlags;
+use crate::vu::{MacFlags, RRegister, StatusFlags};
+use crate::{Classification, Ps2Float, Ps2Vec4};
+
+/// A register of the VU register file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VuRegister {
+    /// The float register VF`n`.
+    Vf(u8),
+    /// The accumulator.
+    Acc,
+    /// The Q register.
+    Q,
+    /// The P register.
+    P,
+    /// The I register.
+    I,
+    /// The R register.
+    R,
+    /// The integer register VI`n`.
+    Vi(u8),
+    /// The MAC flags.
+    Mac,
+    /// The status flags.
+    Status,
+    /// The clip flags.
+    Clip,
+}
+
+/// The value of a register of the VU register file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VuRegisterValue {
+    /// A VF register or the accumulator.
+    Vector(Ps2Vec4),
+    /// The Q, P, I or R register.
+    Float(Ps2Float),
+    /// A VI register.
+    Integer(u16),
+    /// The MAC flags.
+    Mac(MacFlags),
+    /// The status flags.
+    Status(StatusFlags),
+    /// The clip flags.
+    Clip(ClipFlags),
+}
+
+/// A register whose value differs between two register files.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RegisterChange {
+    /// The register that changed.
+    pub register: VuRegister,
+    /// The value in the earlier register file.
+    pub before: VuRegisterValue,
+    /// The value in the later register file.
+    pub after: VuRegisterValue,
+}
+
+/// The architectural state of a VU: the float and integer registers, the
+/// special registers and the flag registers.
+///
+/// VF0 always reads as `(0, 0, 0, 1)` and VI0 always reads as 0, writes to them
+/// are ignored like on the hardware.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VuRegisterFile {
+    vf: [Ps2Vec4; 32],
+    vi: [u16; 16],
+    /// The accumulator written by the `A` variants of the FMAC instructions.
+    pub acc: Ps2Vec4,
+    /// The Q register, the result of DIV, SQRT and RSQRT.
+    pub q: Ps2Float,
+    /// The P register, the result of the EFU instructions.
+    pub p: Ps2Float,
+    /// The I register, loaded by instructions with the I bit set.
+    pub i: Ps2Float,
+    /// The R register random number generator.
+    pub r: RRegister,
+    /// The MAC flags of the last FMAC instruction.
+    pub mac_flags: MacFlags,
+    /// The status flags.
+    pub status_flags: StatusFlags,
+    /// The clip flags of the last 4 CLIP instructions.
+    pub clip_flags: ClipFlags,
+}
+
+impl VuRegisterFile {
+    /// The value VF0 is hardwired to.
+    pub const VF0: [u32; 4] = [0, 0, 0, 0x3F800000];
+
+    /// Creates a register file with every register cleared.
+    pub fn new() -> Self {
+        Self {
+            vf: [Ps2Vec4::default(); 32],
+            vi: [0; 16],
+            acc: Ps2Vec4::default(),
+            q: Ps2Float::default(),
+            p: Ps2Float::default(),
+            i: Ps2Float::default(),
+            r: RRegister::new(),
+            mac_flags: MacFlags::default(),
+            status_flags: StatusFlags::default(),
+            clip_flags: ClipFlags::default(),
+        }
+    }
+
+    /// Returns the float register VF`index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is 32 or more.
+    pub fn vf(&self, index: usize) -> Ps2Vec4 {
+        if index == 0 {
+            return Ps2Vec4::from_bits(Self::VF0);
+        }
+
+        self.vf[index]
+    }
+
+    /// Sets the float register VF`index`, ignoring writes to VF0.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is 32 or more.
+    pub fn set_vf(&mut self, index: usize, value: Ps2Vec4) {
+        if index != 0 {
+            self.vf[index] = value;
+        }
+    }
+
+    /// Returns the integer register VI`index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is 16 or more.
+    pub fn vi(&self, index: usize) -> u16 {
+        self.vi[index]
+    }
+
+    /// Sets the integer register VI`index`, ignoring writes to VI0.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is 16 or more.
+    pub fn set_vi(&mut self, index: usize, value: u16) {
+        if index != 0 {
+            self.vi[index] = value;
+        }
+    }
+}
+
+impl VuRegisterFile {
+    /// Returns the value of a register.
+    ///
+    /// # Panics
+    ///
+    /// If the index of a VF or VI register is out of range.
+    pub fn get(&self, register: VuRegister) -> VuRegisterValue {
+        match register {
+            VuRegister::Vf(index) => VuRegisterValue::Vector(self.vf(index as usize)),
+            VuRegister::Acc => VuRegisterValue::Vector(self.acc),
+            VuRegister::Q => VuRegisterValue::Float(self.q),
+            VuRegister::P => VuRegisterValue::Float(self.p),
+            VuRegister::I => VuRegisterValue::Float(self.i),
+            VuRegister::R => VuRegisterValue::Float(self.r.rget()),
+            VuRegister::Vi(index) => VuRegisterValue::Integer(self.vi(index as usize)),
+            VuRegister::Mac => VuRegisterValue::Mac(self.mac_flags),
+            VuRegister::Status => VuRegisterValue::Status(self.status_flags),
+            VuRegister::Clip => VuRegisterValue::Clip(self.clip_flags),
+        }
+    }
+
+    /// Returns the registers whose value differs in `other`, in the order of
+    /// [`VuRegister::all`].
+    ///
+    /// Replaying a trace and diffing the state after every instruction
+    /// pinpoints the first instruction that perturbs it.
+    pub fn diff(&self, other: &VuRegisterFile) -> Vec<RegisterChange> {
+        VuRegister::all()
+            .map(|register| RegisterChange {
+                register,
+                before: self.get(register),
+                after: other.get(register),
+            })
+            .filter(|change| change.before != change.after)
+            .collect()
+    }
+
+    /// Returns the registers as an aligned table, one row per register, the
+    /// same as [`Display`].
+    ///
+    /// Each row holds the register name, the hex bits of each lane, their
+    /// decimal values and flags, like the register views of emulator debuggers.
+    /// Lanes that aren't zero or an ordinary number are flagged by their
+    /// classification, and the clip flags are listed by judgment from the
+    /// latest.
+    pub fn dump(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl VuRegister {
+    /// Returns every register in the order of the dump.
+    pub fn all() -> impl Iterator<Item = VuRegister> {
+        use VuRegister::*;
+
+        let specials = [Acc, Q, P, I, R];
+        let flags = [Mac, Status, Clip];
+
+        (0..32).map(Vf).chain(specials).chain((0..16).map(Vi)).chain(flags)
+    }
+}
+
+impl Default for VuRegisterFile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for VuRegister {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VuRegister::Vf(index) => write!(f, "VF{:02}", index),
+            VuRegister::Acc => write!(f, "ACC"),
+            VuRegister::Q => write!(f, "Q"),
+            VuRegister::P => write!(f, "P"),
+            VuRegister::I => write!(f, "I"),
+            VuRegister::R => write!(f, "R"),
+            VuRegister::Vi(index) => write!(f, "VI{:02}", index),
+            VuRegister::Mac => write!(f, "MAC"),
+            VuRegister::Status => write!(f, "STATUS"),
+            VuRegister::Clip => write!(f, "CLIP"),
+        }
+    }
+}
+
+impl Display for VuRegisterValue {
+    /// Formats the value as hex bits, lanes separated by spaces.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VuRegisterValue::Vector(value) => {
+                let [x, y, z, w] = value.to_bits();
+                write!(f, "{:08X} {:08X} {:08X} {:08X}", x, y, z, w)
+            }
+            VuRegisterValue::Float(value) => write!(f, "{:08X}", value.as_u32()),
+            VuRegisterValue::Integer(value) => write!(f, "{:04X}", value),
+            VuRegisterValue::Mac(flags) => write!(f, "{:04X}", flags.bits()),
+            VuRegisterValue::Status(flags) => write!(f, "{:03X}", flags.bits()),
+            VuRegisterValue::Clip(flags) => write!(f, "{:06X}", flags.bits()),
+        }
+    }
+}
+
+impl Display for RegisterChange {
+    /// Formats the change like `VF01: 00000000 ... -> 3F800000 ...`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.register, self.before, self.after)
+    }
+}
+
+impl Display for VuRegisterFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lanes = ["x", "y", "z", "w"].map(String::from);
+        write!(f, "{}", row("reg", &lanes, &lanes, "flags"))?;
+
+        for register in VuRegister::all() {
+            let value = self.get(register);
+            let hex: Vec<String> = value.to_string().split(' ').map(String::from).collect();
+            let row = match value {
+                VuRegisterValue::Vector(value) => {
+                    let lanes = value.lanes();
+                    row(register, &hex, &lanes.map(|lane| decimal(&lane)), &lane_flags(&lanes))
+                }
+                VuRegisterValue::Float(value) => {
+                    row(register, &hex, &[decimal(&value)], &lane_flags(&[value]))
+                }
+                VuRegisterValue::Integer(value) => {
+                    row(register, &hex, &[(value as i16).to_string()], "")
+                }
+                VuRegisterValue::Mac(flags) => row(register, &hex, &[], &flags.to_string()),
+                VuRegisterValue::Status(flags) => row(register, &hex, &[], &flags.to_string()),
+                VuRegisterValue::Clip(flags) => row(register, &hex, &[], &flags.to_string()),
+            };
+            write!(f, "\n{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a row of the table, padding the missing lanes of scalar registers.
+fn row(name: impl Display, hex: &[String], decimal: &[String], flags: &str) -> String {
+    let mut row = format!("{:<6}", name.to_string());
+    for lane in 0..4 {
+        row += &format!(" {:>8}", hex.get(lane).map_or("", String::as_str));
+    }
+    for lane in 0..4 {
+        row += &format!(" {:>14}", decimal.get(lane).map_or("", String::as_str));
+    }
+
+    (row + "  " + flags).trim_end().to_string()
+}
+/// Formats the exact value of a float with 6 digits, switching to scientific
+/// notation for magnitudes that would make the column too wide.
+fn decimal(value: &Ps2Float) -> String {
+    let value = value.exact_value();
+    if value == 0. || (1e-4..1e7).contains(&value.abs()) {
+        format!("{:.6}", value)
+    } else {
+        format!("{:.6e}", value)
+    }
+}
+
+/// Names the lanes holding anything but zeros and ordinary numbers.
+fn lane_flags(lanes: &[Ps2Float]) -> String {
+    let names = ["x", "y", "z", "w"];
+    let flags: Vec<String> = lanes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, lane)| {
+            let name = match lane.classify() {
+                Classification::Zero | Classification::Normalized => return None,
+                Classification::Denormalized => "denormalized",
+                Classification::Fmax => "Fmax",
+                Classification::Infinity => "infinity",
+                Classification::Nan => "NaN",
+            };
+            Some(if lanes.len() == 1 { name.to_string() } else { format!("{}:{}", names[i], name) })
+        })
+        .collect();
+
+    flags.join(" ")
+}
@@ -0,0 +1,106 @@
+//! WebAssembly bindings for browser-based tools.
+//!
+//! Exposes a `Ps2Float` class to JavaScript, built with `wasm-pack build
+//! --features wasm`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::Ps2Float;
+
+/// A PS2 float exposed to JavaScript.
+#[wasm_bindgen(js_name = Ps2Float)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WasmPs2Float(Ps2Float);
+
+#[wasm_bindgen(js_class = Ps2Float)]
+impl WasmPs2Float {
+    /// Creates a new PS2 float from its 32-bit pattern.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bits: u32) -> WasmPs2Float {
+        Self(Ps2Float::new(bits))
+    }
+
+    /// Creates a new PS2 float from its sign, exponent and mantissa fields.
+    #[wasm_bindgen(js_name = fromParams)]
+    pub fn from_params(sign: bool, exponent: u8, mantissa: u32) -> WasmPs2Float {
+        Self(Ps2Float::from_params(sign, exponent, mantissa & 0x7FFFFF))
+    }
+
+    /// Returns the 32-bit pattern of the float.
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> u32 {
+        self.0.as_u32()
+    }
+
+    /// Returns the sign bit of the float.
+    #[wasm_bindgen(getter)]
+    pub fn sign(&self) -> bool {
+        self.0.sign()
+    }
+
+    /// Returns the biased exponent of the float.
+    #[wasm_bindgen(getter)]
+    pub fn exponent(&self) -> u8 {
+        self.0.exponent()
+    }
+
+    /// Returns the mantissa of the float.
+    #[wasm_bindgen(getter)]
+    pub fn mantissa(&self) -> u32 {
+        self.0.mantissa()
+    }
+
+    /// Returns the exact value of the float.
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.0.exact_value()
+    }
+
+    /// Adds two PS2 floats.
+    pub fn add(&self, other: &WasmPs2Float) -> WasmPs2Float {
+        Self(self.0.add(other.0))
+    }
+
+    /// Subtracts two PS2 floats.
+    pub fn sub(&self, other: &WasmPs2Float) -> WasmPs2Float {
+        Self(self.0.sub(other.0))
+    }
+
+    /// Multiplies two PS2 floats.
+    pub fn mul(&self, other: &WasmPs2Float) -> WasmPs2Float {
+        Self(self.0.mul(other.0))
+    }
+
+    /// Divides two PS2 floats.
+    pub fn div(&self, other: &WasmPs2Float) -> WasmPs2Float {
+        Self(self.0.div(other.0))
+    }
+
+    /// Returns the hex bits, decimal value and field breakdown of the float.
+    pub fn breakdown(&self) -> String {
+        self.0.breakdown().to_string()
+    }
+
+    /// Returns the classification of the float, e.g. `normalized` or `Fmax`.
+    pub fn classification(&self) -> String {
+        self.0.classify().description().to_string()
+    }
+
+    /// Formats the float like [`Ps2Float`]'s `Display` implementation.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Ps2Float> for WasmPs2Float {
+    fn from(value: Ps2Float) -> Self {
+        Self(value)
+    }
+}
+
+impl From<WasmPs2Float> for Ps2Float {
+    fn from(value: WasmPs2Float) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{Ps2Accumulator, Ps2Float};
+use rstest::*;
+
+#[rstest]
+#[case(0x40000000, 0x40400000, 0x3F800000)] // 1.00 + 2.00 * 3.00
+#[case(0x3F800001, 0x3F800001, 0xBF800000)] // -1.00 + 1.00^2, product truncated first
+#[case(0x7FFFFFFF, 0x40000000, 0x00000000)] // 0.00 + MAX * 2.00
+#[case(0x00000001, 0x40000000, 0x3F800000)] // 1.00 + Denormalized * 2.00
+#[case(0xC0400000, 0x40400000, 0x41100000)] // 9.00 + -3.00 * 3.00
+fn accumulator_matches_madd_and_msub(#[case] a: u32, #[case] b: u32, #[case] acc: u32) {
+    let (a, b, acc) = (Ps2Float::new(a), Ps2Float::new(b), Ps2Float::new(acc));
+    let accumulator = Ps2Accumulator::new(acc);
+
+    assert_eq!(accumulator.madda(a, b).store(), a.madd(b, acc), "Testing {} + {} * {}", acc, a, b);
+    assert_eq!(accumulator.msuba(a, b).store(), a.msub(b, acc), "Testing {} - {} * {}", acc, a, b);
+    assert_eq!(accumulator.madd(a, b), a.madd(b, acc));
+    assert_eq!(accumulator.msub(a, b), a.msub(b, acc));
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000)] // 1.00, 2.00
+#[case(0x3F800000, 0x33800000)] // 1.00, 2^-24, shifted out
+#[case(0x40A00000, 0x40A00000)] // 5.00, 5.00
+#[case(0x00000001, 0xBF800000)] // Denormalized, -1.00
+#[case(0x7FFFFFFF, 0x7FFFFFFF)] // MAX, MAX
+fn accumulator_adda_and_suba(#[case] a: u32, #[case] b: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(Ps2Accumulator::adda(a, b).store(), a.add(b), "Testing {} + {}", a, b);
+    assert_eq!(Ps2Accumulator::suba(a, b).store(), a.sub(b), "Testing {} - {}", a, b);
+}
+
+#[test]
+fn accumulator_truncates_every_step() {
+    let a = Ps2Float::new(0x3F800001); // 1.0000001
+    let one = Ps2Float::new(0x3F800000);
+
+    let acc = Ps2Accumulator::mula(a, a);
+    assert_eq!(acc.store().as_u32(), 0x3F800002);
+    assert_eq!(acc.msuba(one, one).store().as_u32(), 0x34800000); // 2^-22
+    assert_eq!(acc.madda(one, one).msuba(one, one).store().as_u32(), 0x3F800002);
+}
+
+#[rstest]
+#[case(Ps2Accumulator::default(), false)]
+#[case(Ps2Accumulator::adda(Ps2Float::new(0x7FFFFFFE), Ps2Float::new(0x7FFFFFFE)), true)]
+#[case(Ps2Accumulator::suba(Ps2Float::new(0xFFFFFFFE), Ps2Float::new(0x7FFFFFFE)), true)]
+#[case(Ps2Accumulator::adda(Ps2Float::max(), Ps2Float::new(0x3F800000)), false)]
+#[case(Ps2Accumulator::mula(Ps2Float::max(), Ps2Float::new(0x40000000)), true)] // Product
+#[case(Ps2Accumulator::new(Ps2Float::new(0x7FFFFFFE))
+    .madda(Ps2Float::new(0x7FFFFFFE), Ps2Float::new(0x3F800000)), true)] // Sum
+#[case(Ps2Accumulator::new(Ps2Float::new(0xFFFFFFFE))
+    .msuba(Ps2Float::new(0x7FFFFFFE), Ps2Float::new(0x3F800000)), true)] // Difference
+#[case(Ps2Accumulator::mula(Ps2Float::max(), Ps2Float::new(0x40000000))
+    .msuba(Ps2Float::max(), Ps2Float::new(0x3F800000)), true)] // Stays set
+#[case(Ps2Accumulator::new(Ps2Float::max())
+    .madda(Ps2Float::new(0x3F800000), Ps2Float::new(0x3F800000)), false)]
+fn accumulator_overflow(#[case] accumulator: Ps2Accumulator, #[case] overflowed: bool) {
+    assert_eq!(accumulator.overflowed(), overflowed);
+}
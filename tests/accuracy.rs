@@ -0,0 +1,57 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::accuracy::{AccuracyAnalysis, Sampling};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+#[rstest]
+#[case(Operation::Add, Sampling::Normalized)]
+#[case(Operation::Sub, Sampling::Normalized)]
+#[case(Operation::Add, Sampling::EdgeCases)]
+#[case(Operation::Sub, Sampling::Uniform)]
+fn accuracy_analysis_is_deterministic(#[case] operation: Operation, #[case] sampling: Sampling) {
+    let analysis = AccuracyAnalysis::new(operation).sampling(sampling).seed(7).samples(2000);
+
+    let report = analysis.run();
+
+    assert_eq!(report, analysis.run());
+    assert_eq!(report.samples, 2000);
+    assert_eq!(report.histogram.values().sum::<u64>(), 2000);
+}
+
+#[test]
+fn accuracy_analysis_add_truncates() {
+    let report = AccuracyAnalysis::new(Operation::Add).samples(10_000).run();
+
+    assert!(report.exact_fraction() > 0.5);
+    assert!(report.max_ulps() >= 1);
+    assert!(report.max_ulps() <= 2);
+
+    let worst = report.worst.unwrap();
+    assert_eq!(worst.actual, worst.a.add(worst.b));
+}
+
+#[test]
+fn accuracy_analysis_against_itself() {
+    let report = AccuracyAnalysis::new(Operation::Sub)
+        .sampling(Sampling::EdgeCases)
+        .samples(500)
+        .run_against(|a, b| a.sub(b));
+
+    assert_eq!(report.max_ulps(), 0);
+    assert_eq!(report.mean_ulps(), 0.0);
+    assert_eq!(report.to_csv(), "ulps,count,fraction\n0,500,1.000000\n");
+    assert_eq!(
+        format!("{}", report),
+        "sub: 500 samples, 100.00% exact, mean 0.0000 ULPs, max 0 ULPs"
+    );
+}
+
+#[test]
+fn accuracy_analysis_against_off_by_one() {
+    let report = AccuracyAnalysis::new(Operation::Add)
+        .samples(4)
+        .run_against(|a, b| Ps2Float::new(a.add(b).as_u32() ^ 1));
+
+    assert_eq!(report.to_csv(), "ulps,count,fraction\n1,4,1.000000\n");
+    assert_eq!(report.exact_fraction(), 0.0);
+}
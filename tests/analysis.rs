@@ -0,0 +1,114 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::analysis::{find_closest, find_k_closest, find_within, Histogram, Match};
+use ps2_floating_point::{Classification, Ps2Float};
+use rstest::*;
+
+fn floats(bits: &[u32]) -> Vec<Ps2Float> {
+    bits.iter().copied().map(Ps2Float::new).collect()
+}
+
+fn indices(found: &[Match]) -> Vec<usize> {
+    found.iter().map(|found| found.index).collect()
+}
+
+#[rstest]
+#[case(&[0x3F800000, 0x3F800003, 0x3F7FFFFF], 0x3F800002, Some((1, 1)))]
+#[case(&[0x3F800001, 0x3F7FFFFF], 0x3F800000, Some((0, 1)))] // First on ties
+#[case(&[0x80000000, 0x3F800000], 0x00000000, Some((0, 0)))] // -0 matches +0
+#[case(&[0x00000001, 0x00800000], 0x00000000, Some((0, 0)))] // Denormalized is zero
+#[case(&[0xBF800000, 0x3F800000], 0x3F800000, Some((1, 0)))]
+#[case(&[], 0x3F800000, None)]
+fn analysis_find_closest(
+    #[case] haystack: &[u32],
+    #[case] needle: u32,
+    #[case] expected: Option<(usize, u32)>,
+) {
+    let found = find_closest(&floats(haystack), Ps2Float::new(needle));
+
+    assert_eq!(found.map(|found| (found.index, found.ulps)), expected);
+}
+
+#[rstest]
+#[case(0, &[])]
+#[case(1, &[2])]
+#[case(3, &[2, 0, 4])]
+#[case(10, &[2, 0, 4, 1, 3])]
+fn analysis_find_k_closest(#[case] k: usize, #[case] expected: &[usize]) {
+    let haystack = floats(&[0x3F800002, 0x40000000, 0x3F800000, 0xBF800000, 0x3F800002]);
+
+    let found = find_k_closest(&haystack, Ps2Float::new(0x3F800000), k);
+
+    assert_eq!(indices(&found), expected);
+}
+
+#[test]
+fn analysis_find_k_closest_reports_values() {
+    let haystack = floats(&[0x3F800000, 0x3F800005]);
+
+    let found = find_k_closest(&haystack, Ps2Float::new(0x3F800004), 2);
+
+    assert_eq!(
+        found,
+        [
+            Match { index: 1, value: Ps2Float::new(0x3F800005), ulps: 1 },
+            Match { index: 0, value: Ps2Float::new(0x3F800000), ulps: 4 },
+        ]
+    );
+}
+
+#[test]
+fn analysis_find_within() {
+    let haystack = floats(&[0x3F800003, 0x3F800000, 0x3F7FFFFE, 0x3F7FFFFD]);
+
+    let found = find_within(&haystack, Ps2Float::new(0x3F800000), 2);
+
+    assert_eq!(indices(&found), [1, 2]);
+}
+
+#[test]
+fn analysis_histogram() {
+    let values = floats(&[
+        0x00000000, 0x80000000, 0x80000000, // Zeros
+        0x00000001, 0x807FFFFF, // Denormalized
+        0x3F800000, 0xBF800001, 0x40000000, // Normalized
+        0x7FFFFFFF, 0xFFFFFFFF, // Fmax
+        0x7F800000, // Infinity
+        0x7FC00000, // NaN
+    ]);
+
+    let histogram = Histogram::of(&values);
+
+    assert_eq!(histogram.total(), 12);
+    assert_eq!(histogram.exponents[0], 5);
+    assert_eq!(histogram.exponents[127], 2);
+    assert_eq!(histogram.exponents[128], 1);
+    assert_eq!(histogram.exponents[255], 4);
+    assert_eq!(
+        [
+            Classification::Zero,
+            Classification::Denormalized,
+            Classification::Normalized,
+            Classification::Fmax,
+            Classification::Infinity,
+            Classification::Nan,
+        ]
+        .map(|classification| histogram.count(classification)),
+        [3, 2, 3, 2, 1, 1]
+    );
+    assert_eq!(histogram.negative_zeros, 2);
+    assert_eq!(histogram.suspicious(), 6);
+}
+
+#[test]
+fn analysis_histogram_from_le_bytes() {
+    let mut bytes = Vec::new();
+    for bits in [0x3F800000u32, 0x00000001, 0xFFFFFFFF] {
+        bytes.extend_from_slice(&bits.to_le_bytes());
+    }
+    bytes.extend_from_slice(&[0x00, 0x00, 0x80]); // Partial word
+
+    let histogram = Histogram::from_le_bytes(&bytes);
+
+    assert_eq!(histogram, Histogram::of(&floats(&[0x3F800000, 0x00000001, 0xFFFFFFFF])));
+    assert_eq!((histogram.denormals, histogram.fmax), (1, 1));
+}
@@ -0,0 +1,37 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{batch, Ps2Float};
+use rstest::*;
+
+#[rstest]
+#[case(0, 0)]
+#[case(3, 1)]
+#[case(4, 1)]
+#[case(9, 3)]
+fn batch_add_lengths(#[case] len: usize, #[case] groups: usize) {
+    let values = vec![Ps2Float::new(0x3F800000); len];
+
+    let (sums, flags) = batch::add(&values, &values);
+
+    assert_eq!(sums, vec![Ps2Float::new(0x40000000); len]);
+    assert_eq!(flags.len(), groups);
+    assert!(flags.iter().all(|flags| flags.bits() == 0));
+}
+
+#[test]
+fn batch_sub_clears_padded_lanes() {
+    let a = [0x3F800000, 0x3F800000, 0x3F800000].map(Ps2Float::new);
+    let b = [0x3F800000, 0x40000000, 0x00000000].map(Ps2Float::new);
+
+    let (differences, flags) = batch::sub(&a, &b);
+
+    let bits: Vec<_> = differences.iter().map(|difference| difference.as_u32()).collect();
+    assert_eq!(bits, [0x00000000, 0xBF800000, 0x3F800000]);
+    assert_eq!(flags.iter().map(|flags| flags.bits()).collect::<Vec<_>>(), [0x0048]);
+    // Zx Sy
+}
+
+#[test]
+#[should_panic(expected = "batch operands have different lengths")]
+fn batch_add_length_mismatch() {
+    batch::add(&[Ps2Float::new(0)], &[]);
+}
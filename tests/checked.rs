@@ -0,0 +1,195 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::vu::StatusFlags;
+use ps2_floating_point::{DivideFlags, Ps2Float, Ps2FloatError};
+use rstest::*;
+
+#[derive(Debug, Copy, Clone)]
+enum Checked {
+    Ok,
+    Overflow,
+    Underflow,
+    DivideByZero,
+    Invalid,
+}
+
+fn expected(checked: Checked, result: u32) -> Result<Ps2Float, Ps2FloatError> {
+    let result = Ps2Float::new(result);
+    match checked {
+        Checked::Ok => Ok(result),
+        Checked::Overflow => Err(Ps2FloatError::Overflow { result }),
+        Checked::Underflow => Err(Ps2FloatError::Underflow { result }),
+        Checked::DivideByZero => Err(Ps2FloatError::DivideByZero { result }),
+        Checked::Invalid => Err(Ps2FloatError::Invalid { result }),
+    }
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, Checked::Ok, 0x40400000)] // 1.00 + 2.00
+#[case(0x7FFFFFFE, 0x7FFFFFFE, Checked::Overflow, 0x7FFFFFFF)]
+#[case(0x00800001, 0x80800000, Checked::Underflow, 0x00000000)]
+#[case(0x7FFFFFFF, 0x7FFFFFFF, Checked::Overflow, 0x7FFFFFFF)] // MAX + MAX
+#[case(0xFFFFFFFF, 0x7FFFFFFF, Checked::Ok, 0x7FFFFFFF)] // -MAX + MAX, from the table
+#[case(0x00000001, 0x3F800000, Checked::Ok, 0x3F800000)] // Denormalized is zero
+fn checked_add(#[case] a: u32, #[case] b: u32, #[case] checked: Checked, #[case] result: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.checked_add(b), expected(checked, result));
+    assert_eq!(a.checked_add(b).unwrap_or_else(|error| error.result()), a.add(b));
+}
+
+#[rstest]
+#[case(0x40400000, 0x3F800000, Checked::Ok, 0x40000000)] // 3.00 - 1.00
+#[case(0xFFFFFFFE, 0x7FFFFFFE, Checked::Overflow, 0xFFFFFFFF)]
+#[case(0x00800001, 0x00800000, Checked::Underflow, 0x00000000)]
+#[case(0x3F800000, 0x3F800000, Checked::Ok, 0x00000000)] // Cancels out exactly
+fn checked_sub(#[case] a: u32, #[case] b: u32, #[case] checked: Checked, #[case] result: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.checked_sub(b), expected(checked, result));
+    assert_eq!(a.checked_sub(b).unwrap_or_else(|error| error.result()), a.sub(b));
+}
+
+#[rstest]
+#[case(0x40000000, 0x40400000, Checked::Ok, 0x40C00000)] // 2.00 * 3.00
+#[case(0x7FFFFFFF, 0xC0000000, Checked::Overflow, 0xFFFFFFFF)] // MAX * -2.00
+#[case(0x7F000000, 0x40000000, Checked::Ok, 0x7F800000)] // 2^127 * 2.00, the Inf pattern
+#[case(0x00800000, 0x3F000000, Checked::Underflow, 0x00000000)] // Smallest normalized * 0.50
+#[case(0x80800000, 0x3F000000, Checked::Underflow, 0x80000000)]
+#[case(0x00000001, 0x7FFFFFFF, Checked::Ok, 0x00000000)] // Denormalized is zero
+#[case(0x200007FF, 0x1FFFF003, Checked::Underflow, 0x00000000)] // Booth drops the carry
+#[case(0x5F8007FF, 0x5FFFF003, Checked::Ok, 0x7FFFFFFF)] // Booth drops the carry
+fn checked_mul(#[case] a: u32, #[case] b: u32, #[case] checked: Checked, #[case] result: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.checked_mul(b), expected(checked, result));
+    assert_eq!(a.checked_mul(b).unwrap_or_else(|error| error.result()), a.mul(b));
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40400000, Checked::Ok, 0x3EAAAAAA)] // 1.00 / 3.00
+#[case(0x7FFFFFFF, 0x3F000000, Checked::Overflow, 0x7FFFFFFF)] // MAX / 0.50
+#[case(0x00800000, 0x40000000, Checked::Underflow, 0x00000000)] // Smallest normalized / 2.00
+#[case(0x3F800000, 0x00000000, Checked::DivideByZero, 0x7FFFFFFF)]
+#[case(0xBF800000, 0x00000000, Checked::DivideByZero, 0xFFFFFFFF)]
+#[case(0x3F800000, 0x80000001, Checked::DivideByZero, 0xFFFFFFFF)] // By -Denormalized
+#[case(0x00000000, 0x00000000, Checked::Invalid, 0x7FFFFFFF)]
+#[case(0x00000001, 0x80000000, Checked::Invalid, 0xFFFFFFFF)]
+#[case(0x00000000, 0x3F800000, Checked::Ok, 0x00000000)]
+fn checked_div(#[case] a: u32, #[case] b: u32, #[case] checked: Checked, #[case] result: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.checked_div(b), expected(checked, result));
+}
+
+#[test]
+fn checked_error_display() {
+    let error = Ps2Float::new(0x80800000).checked_div(Ps2Float::new(0x40000000)).unwrap_err();
+
+    assert_eq!(error.to_string(), "underflow resulting in 0x80000000 (Denormalized(-0.00))");
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x40400000, 0b0000)] // 1.00 + 2.00
+#[case(0x3F800000, 0xC0000000, 0xBF800000, 0b0010)] // 1.00 + -2.00
+#[case(0x3F800000, 0xBF800000, 0x00000000, 0b0001)] // 1.00 + -1.00
+#[case(0x00800001, 0x80800000, 0x00000000, 0b0101)] // Underflows to 0
+#[case(0x80800001, 0x00800000, 0x80000000, 0b0111)] // Underflows to -0
+#[case(0xFFFFFFFE, 0xFFFFFFFE, 0xFFFFFFFF, 0b1010)] // Overflows to -MAX
+fn add_with_flags(#[case] a: u32, #[case] b: u32, #[case] result: u32, #[case] flags: u16) {
+    let (sum, operation_flags) = Ps2Float::new(a).add_with_flags(Ps2Float::new(b));
+
+    assert_eq!(sum.as_u32(), result);
+    assert_eq!(operation_flags.status_bits(), flags);
+}
+
+#[rstest]
+#[case(0x40400000, 0x3F800000, 0x40000000, 0b0000)] // 3.00 - 1.00
+#[case(0x80000000, 0x00000000, 0x80000000, 0b0011)] // -0.00 - 0.00
+#[case(0x00800001, 0x00800000, 0x00000000, 0b0101)] // Underflows
+#[case(0xFFFFFFFE, 0x7FFFFFFE, 0xFFFFFFFF, 0b1010)] // Overflows
+fn sub_with_flags(#[case] a: u32, #[case] b: u32, #[case] result: u32, #[case] flags: u16) {
+    let (difference, operation_flags) = Ps2Float::new(a).sub_with_flags(Ps2Float::new(b));
+
+    assert_eq!(difference.as_u32(), result);
+    assert_eq!(operation_flags.status_bits(), flags);
+}
+
+#[rstest]
+#[case(0x40000000, 0xC0400000, 0xC0C00000, 0b0010)] // 2.00 * -3.00
+#[case(0x00000001, 0xBF800000, 0x80000000, 0b0011)] // Denormalized * -1.00
+#[case(0x00800000, 0xBF000000, 0x80000000, 0b0111)] // Underflows
+#[case(0x7FFFFFFF, 0x40000000, 0x7FFFFFFF, 0b1000)] // Overflows
+#[case(0x200007FF, 0x1FFFF003, 0x00000000, 0b0101)] // Underflows, Booth drops the carry
+fn mul_with_flags(#[case] a: u32, #[case] b: u32, #[case] result: u32, #[case] flags: u16) {
+    let (product, operation_flags) = Ps2Float::new(a).mul_with_flags(Ps2Float::new(b));
+
+    assert_eq!(product.as_u32(), result);
+    assert_eq!(operation_flags.status_bits(), flags);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40400000, 0x3EAAAAAA, 0b0000)] // 1.00 / 3.00
+#[case(0x00800000, 0x40000000, 0x00000000, 0b0101)] // Underflows
+#[case(0x7FFFFFFF, 0x3F000000, 0x7FFFFFFF, 0b1000)] // Overflows
+#[case(0xBF800000, 0x00000000, 0xFFFFFFFF, 0b0010)] // Divided by zero, no overflow
+#[case(0x00000000, 0x00000000, 0x7FFFFFFF, 0b0000)] // 0 / 0, no overflow
+fn div_with_flags(#[case] a: u32, #[case] b: u32, #[case] result: u32, #[case] flags: u16) {
+    let (quotient, operation_flags) = Ps2Float::new(a).div_with_flags(Ps2Float::new(b));
+
+    assert_eq!(quotient.as_u32(), result);
+    assert_eq!(operation_flags.status_bits(), flags);
+}
+
+#[test]
+fn operation_flags_match_status_register() {
+    let (_, flags) = Ps2Float::new(0x80800001).add_with_flags(Ps2Float::new(0x00800000));
+
+    assert!(flags.zero && flags.sign && flags.underflow && !flags.overflow);
+    assert_eq!(flags.status_bits(), StatusFlags::ZERO | StatusFlags::SIGN | StatusFlags::UNDERFLOW);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, false, false)] // 1.00 / 2.00
+#[case(0x3F800000, 0x00000000, false, true)] // 1.00 / 0.00
+#[case(0xBF800000, 0x80000001, false, true)] // -1.00 / -Denormalized
+#[case(0x00000000, 0x00000000, true, false)] // 0.00 / 0.00
+#[case(0x80000001, 0x80000000, true, false)] // Denormalized / -0.00
+#[case(0x00000000, 0x7FFFFFFF, false, false)] // 0.00 / MAX
+fn divide_flags_of_div(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] invalid: bool,
+    #[case] divide: bool,
+) {
+    let flags = DivideFlags::of_div(Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(flags, DivideFlags { invalid, divide });
+}
+
+#[rstest]
+#[case(0x40800000, false, false)] // 4.00
+#[case(0xC0800000, true, false)] // -4.00
+#[case(0x80000000, false, false)] // -0.00
+#[case(0x80000001, false, false)] // -Denormalized
+#[case(0xFFFFFFFF, true, false)] // -MAX
+fn divide_flags_of_sqrt(#[case] a: u32, #[case] invalid: bool, #[case] divide: bool) {
+    assert_eq!(DivideFlags::of_sqrt(Ps2Float::new(a)), DivideFlags { invalid, divide });
+}
+
+#[rstest]
+#[case(0x40800000, false, false)] // 4.00
+#[case(0xC0800000, true, false)] // -4.00
+#[case(0x00000000, false, true)] // 0.00
+#[case(0x80000000, false, true)] // -0.00
+#[case(0x00000001, false, true)] // Denormalized
+fn divide_flags_of_rsqrt(#[case] a: u32, #[case] invalid: bool, #[case] divide: bool) {
+    assert_eq!(DivideFlags::of_rsqrt(Ps2Float::new(a)), DivideFlags { invalid, divide });
+}
+
+#[test]
+fn divide_flags_match_status_register() {
+    let flags = DivideFlags { invalid: true, divide: true };
+
+    assert_eq!(flags.status_bits(), StatusFlags::INVALID | StatusFlags::DIVIDE);
+    assert_eq!(DivideFlags::default().status_bits(), 0);
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+use rstest::*;
+
+fn ps2float(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_ps2float")).args(args).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    (output.status.success(), stdout)
+}
+
+#[rstest]
+#[case(&["0x40A9999A"], "0x40A9999A  5.300000190734863")]
+#[case(&["5.3"], "0x40A9999A")]
+#[case(&["0x40A9999A + 1.0"], "0x40C9999A")]
+#[case(&["0x3F800000", "-", "0x40400000"], "0xC0000000  -2")]
+#[case(&["(1 - 3) - -4"], "0x40000000  2")]
+#[case(&["fields", "0", "0x81", "0x29999A"], "0x40A9999A")]
+#[case(&["0x7FFFFFFF"], "[Fmax]")]
+#[case(&["0x1.8p+1 - 0x1p-1"], "0x40200000  2.5")]
+fn cli_evaluate(#[case] args: &[&str], #[case] expected: &str) {
+    let (success, stdout) = ps2float(args);
+
+    assert!(success);
+    assert!(stdout.contains(expected), "{:?} printed {}", args, stdout);
+}
+
+#[rstest]
+#[case(&["1 +"])]
+#[case(&["0xZZ"])]
+#[case(&["fields", "2", "0", "0"])]
+fn cli_evaluate_error(#[case] args: &[&str]) {
+    let (success, _) = ps2float(args);
+
+    assert!(!success);
+}
+
+fn ps2float_repl(input: &str) -> String {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ps2float"))
+        .arg("repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap()
+}
+
+#[rstest]
+#[case("x = 0x3F800000\nx + x\n", "0x3F800000 + 0x3F800000 = 0x40000000  [-]")]
+#[case("0x7F7FFFFF + 0x7F7FFFFF\n", "= 0x7FFFFFFF  [-]")] // Exponent 255 is ordinary
+#[case(":mode ieee\n0x7F7FFFFF + 0x7F7FFFFF\n", "= 0x7F800000  [O]")]
+#[case("1 - 3\n", "= 0xC0000000  [S]")]
+#[case("0x00800000 - 0x00800001\n", "= 0x80000000  [Z S U]")]
+#[case("0x7FFFFFFF + 0x7FFFFFFF\n", "= 0x7FFFFFFF  [O]")] // Fmax operands
+#[case("0x00000001 + 1\n", "= 0x3F800000  [-]")] // Denormalized operand
+#[case("1 / 0\n", "= 0x7FFFFFFF  [D]")]
+#[case("0 / 0\n", "= 0x7FFFFFFF  [I]")]
+#[case(":mode ieee\n0x00800000 * 0.5\n", "= 0x00400000  [-]")] // Exact
+#[case(":mode ieee\n0x00800001 * 0.5\n", "= 0x00400000  [U]")]
+#[case(":mode ieee\n1 / 0\n", "= 0x7F800000  [D]")]
+#[case(":mode ieee\n0 / 0\n", "I]")] // The sign of the NaN depends on the host
+#[case("a = 2\nb = a - 1\n:vars\n", "b = 0x3F800000  1")]
+#[case("nope + 1\n", "error: unknown variable 'nope'")]
+fn cli_repl(#[case] input: &str, #[case] expected: &str) {
+    let stdout = ps2float_repl(input);
+
+    assert!(stdout.contains(expected), "{:?} printed {}", input, stdout);
+}
+
+/// Writes the floats to a little endian dump in the temp directory.
+fn write_dump(name: &str, floats: &[u32]) -> String {
+    let path = std::env::temp_dir().join(format!("ps2float-{}-{}.bin", std::process::id(), name));
+    let bytes: Vec<u8> = floats.iter().flat_map(|f| f.to_le_bytes()).collect();
+    std::fs::write(&path, bytes).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn cli_diff() {
+    let a = write_dump("diff-a", &[0x3F800000, 0x40000000, 0x40400000, 0x00000000]);
+    let b = write_dump("diff-b", &[0x3F800000, 0x40000002, 0x40400001, 0x80000000]);
+
+    let (success, stdout) = ps2float(&["diff", &a, &b]);
+
+    assert!(success);
+    assert!(stdout.contains("[1] @0x4: 0x40000000 (2) vs 0x40000002 (2.000000476837158), 2 ULPs"));
+    assert!(stdout.contains("[2] @0x8: 0x40400000 (3) vs 0x40400001 (3.000000238418579), 1 ULPs"));
+    assert!(stdout.contains("[3] @0xC: 0x00000000 (0) vs 0x80000000 (-0), 0 ULPs"));
+    assert!(stdout.contains("3 of 4 floats differ, max 2 ULPs"));
+}
+
+#[test]
+fn cli_diff_stride_offset_max_ulps() {
+    let a = write_dump("stride-a", &[0, 0x3F800000, 0, 0x40000000, 0, 0x40400000]);
+    let b = write_dump("stride-b", &[1, 0x3F800000, 1, 0x40000002, 1, 0x40400001]);
+
+    let args = ["diff", &a, &b, "--stride", "8", "--offset", "4", "--max-ulps", "1"];
+    let (success, stdout) = ps2float(&args);
+
+    assert!(success);
+    assert!(stdout.contains("[1] @0xC: 0x40000000 (2) vs 0x40000002"));
+    assert!(!stdout.contains("[2]"));
+    assert!(stdout.contains("1 of 3 floats differ, max 2 ULPs"));
+}
+
+#[test]
+fn cli_scan() {
+    let elf = write_dump(
+        "scan",
+        &[
+            0x3C888889, // 1/60
+            0x3FC90FDB, // pi/2
+            0x27BDFFE0, // addiu sp, sp, -32
+            0x00000000, // 0.00
+            0x00000001, // Denormalized
+            0x7FFFFFFF, // MAX
+            0x42C80000, // 100.00
+            0x3F800001, // 1.00 + 1 ULP
+        ],
+    );
+
+    let (success, stdout) = ps2float(&["scan", &elf, "--digits", "8"]);
+
+    assert!(success);
+    assert!(stdout.contains("0x00000000: 0x3C888889"));
+    assert!(stdout.contains("0x00000004: 0x3FC90FDB"));
+    assert!(stdout.contains("0x00000018: 0x42C80000  100"));
+    assert!(stdout.contains("4 plausible constants in 8 words"));
+
+    let (success, stdout) = ps2float(&["scan", &elf]);
+
+    assert!(success);
+    assert!(stdout.contains("1 plausible constants in 8 words"));
+}
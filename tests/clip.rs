@@ -0,0 +1,44 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::clip::{classify, ClipFlags, ClipJudgment, Containment};
+use ps2_floating_point::Ps2Vec4;
+use rstest::*;
+
+#[rstest]
+#[case([0x00000000, 0x00000000, 0x00000000, 0x3F800000], 0b000000)] // Origin
+#[case([0x3F800000, 0xBF800000, 0x3F800000, 0x3F800000], 0b000000)] // On the planes
+#[case([0x40000000, 0x00000000, 0x00000000, 0x3F800000], 0b000001)] // +x
+#[case([0xC0000000, 0x40000000, 0x00000000, 0xBF800000], 0b000110)] // -x +y against |w|
+#[case([0x00000000, 0x00000000, 0xFFFFFFFF, 0x7F7FFFFF], 0b100000)] // -z
+#[case([0x7FFFFFFF, 0x00000000, 0x00000000, 0x7F800000], 0b000001)] // Fmax is an ordinary number
+fn clip_judgment(#[case] vertex: [u32; 4], #[case] expected: u8) {
+    assert_eq!(ClipJudgment::of(&Ps2Vec4::from_bits(vertex)).bits(), expected);
+}
+
+#[test]
+fn clip_flags_shift_register() {
+    let mut flags = ClipFlags::default();
+    for bits in [0b000001, 0b000010, 0b000100, 0b001000, 0b010000] {
+        flags.push(ClipJudgment::from_bits(bits));
+    }
+
+    assert_eq!(flags.bits(), 0b000010_000100_001000_010000);
+    assert_eq!(flags.judgment(0).bits(), 0b010000);
+    assert_eq!(flags.judgment(3).bits(), 0b000010);
+}
+
+#[rstest]
+#[case([0x00000000, 0x3F000000, 0xBF000000], Containment::Inside)]
+#[case([0x40000000, 0x40400000, 0x40800000], Containment::Outside)]
+#[case([0x00000000, 0x40400000, 0x40800000], Containment::Straddling)]
+#[case([0xC0000000, 0x40400000, 0x40800000], Containment::Straddling)] // Beyond opposite planes
+fn clip_classify_triangle(#[case] xs: [u32; 3], #[case] expected: Containment) {
+    let triangle = xs.map(|x| Ps2Vec4::from_bits([x, 0x00000000, 0x00000000, 0x3F800000]));
+
+    assert_eq!(classify(&triangle), expected);
+}
+
+#[test]
+fn clip_judgment_display() {
+    assert_eq!(format!("{}", ClipJudgment::from_bits(0b100001)), "+x -z");
+    assert_eq!(format!("{}", ClipJudgment::default()), "-");
+}
@@ -0,0 +1,276 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::convert::{
+    convert_f32_slice, convert_f32_slice_with, from_fixed_1_15_16, from_fixed_1_3_12,
+    to_fixed_1_15_16, to_fixed_1_3_12, ConversionReport, NonFinitePolicy, TryFromF32Error,
+    TryFromPs2FloatError,
+};
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(NonFinitePolicy::Clamp, [0x7FFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF], 3)]
+#[case(NonFinitePolicy::Preserve, [0x7F800000, 0xFF800000, 0xFFC00000], 0)]
+#[case(NonFinitePolicy::Zero, [0x00000000, 0x80000000, 0x80000000], 3)]
+fn convert_f32_slice_non_finite(
+    #[case] policy: NonFinitePolicy,
+    #[case] expected: [u32; 3],
+    #[case] mapped: usize,
+) {
+    let values = [f32::INFINITY, f32::NEG_INFINITY, -f32::NAN];
+
+    let (floats, report) = convert_f32_slice_with(&values, policy);
+
+    assert_eq!(floats.iter().map(|float| float.as_u32()).collect::<Vec<_>>(), expected);
+    assert_eq!(report.infinities_mapped + report.nans_mapped, mapped);
+    assert_eq!(report.denormals_flushed, 0);
+}
+
+#[test]
+fn convert_f32_slice_report() {
+    let values = [1.5, -1e-40, f32::MIN_POSITIVE, 0.0, -0.0, f32::NAN, f32::INFINITY];
+
+    let (floats, report) = convert_f32_slice(&values);
+
+    let bits: Vec<u32> = floats.iter().map(|float| float.as_u32()).collect();
+    assert_eq!(
+        bits,
+        [0x3FC00000, 0x80000000, 0x00800000, 0x00000000, 0x80000000, 0x7FFFFFFF, 0x7FFFFFFF]
+    );
+    assert_eq!(
+        report,
+        ConversionReport { total: 7, denormals_flushed: 1, infinities_mapped: 1, nans_mapped: 1 }
+    );
+    assert_eq!(
+        report.to_string(),
+        "3 of 7 values altered: 1 denormals flushed, 1 infinities and 1 NaNs mapped"
+    );
+}
+
+#[rstest]
+#[case(0x00000000, Ok(0))] // 0.00
+#[case(0x80000000, Ok(0))] // -0.00
+#[case(0x00400000, Ok(0))] // Denormalized
+#[case(0x42280000, Ok(42))] // 42.00
+#[case(0x4EFFFFFF, Ok(0x7FFFFF80))] // Largest float below 2^31
+#[case(0xCF000000, Ok(i32::MIN))] // -2^31
+#[case(0x3F000000, Err("0x3F000000 (0.50) is not an integer"))]
+#[case(0xC0200000, Err("0xC0200000 (-2.50) is not an integer"))]
+#[case(0x4F000000, Err("0x4F000000 (2147483648.00) is out of range"))]
+#[case(0x5F000000, Err("0x5F000000 (9223372036854775808.00) is out of range"))]
+fn ps2float_try_into_i32(#[case] value: u32, #[case] expected: Result<i32, &str>) {
+    let result = i32::try_from(Ps2Float::new(value)).map_err(|error| error.to_string());
+
+    assert_eq!(result, expected.map_err(str::to_string));
+}
+
+#[rstest]
+#[case(0x00000000, Some(0))] // 0.00
+#[case(0x80400000, Some(0))] // -Denormalized
+#[case(0x3F800000, Some(1))] // 1.00
+#[case(0xC2280000, Some(-42))] // -42.00
+#[case(0x4B7FFFFF, Some(0xFFFFFF))] // 2^24 - 1
+#[case(0x5EFFFFFF, Some(0x7FFFFF8000000000))] // Largest float below 2^63
+#[case(0xDF000000, Some(i64::MIN))] // -2^63
+#[case(0x3F000000, None)] // 0.50
+#[case(0x3FC00000, None)] // 1.50
+#[case(0x4AFFFFFF, None)] // 2^23 - 0.5
+#[case(0x5F000000, None)] // 2^63
+#[case(0x7FFFFFFF, None)] // MAX
+fn ps2float_to_exact_int(#[case] value: u32, #[case] expected: Option<i64>) {
+    let value = Ps2Float::new(value);
+
+    assert_eq!(value.to_exact_int(), expected);
+    // Floats from 2^63 up are integers too big for an i64.
+    assert_eq!(value.is_integer(), expected.is_some() || value.exponent() >= 190);
+}
+
+#[rstest]
+#[case(0x00000000, 0)] // 0.00
+#[case(0x80000001, 0)] // -Denormalized
+#[case(0x3F7FFFFF, 0)] // 1.00 - 1 ULP
+#[case(0x3FC00000, 1)] // 1.50
+#[case(0xC0F00000, -7)] // -7.50
+#[case(0x4EFFFFFF, 0x7FFFFF80)] // Largest float below 2^31
+#[case(0x4F000000, i32::MAX)] // 2^31
+#[case(0xCF000000, i32::MIN)] // -2^31
+#[case(0xCF000001, i32::MIN)] // Below -2^31
+#[case(0x7F800000, i32::MAX)] // Inf
+#[case(0xFFC00000, i32::MIN)] // -NaN
+#[case(0x7FFFFFFF, i32::MAX)] // MAX
+#[case(0xFFFFFFFF, i32::MIN)] // -MAX
+fn ps2float_to_i32_cvtw(#[case] value: u32, #[case] expected: i32) {
+    assert_eq!(Ps2Float::new(value).to_i32_cvtw(), expected);
+}
+
+#[rstest]
+#[case(0, 0x00000000)]
+#[case(1, 0x3F800000)]
+#[case(-42, 0xC2280000)]
+#[case(0xFFFFFF, 0x4B7FFFFF)] // 2^24 - 1, exact
+#[case(0x1000001, 0x4B800000)] // 2^24 + 1 truncates
+#[case(0x1000003, 0x4B800001)] // 2^24 + 3 truncates, a host rounds up
+#[case(-0x1000003, 0xCB800001)] // Truncates toward zero
+#[case(i32::MAX, 0x4EFFFFFF)]
+#[case(i32::MIN, 0xCF000000)]
+fn ps2float_from_i32_cvtsw(#[case] value: i32, #[case] expected: u32) {
+    let float = Ps2Float::from_i32_cvtsw(value);
+
+    assert_eq!(float.as_u32(), expected);
+    // Truncating never moves away from zero.
+    assert!(float.to_i32_cvtw().unsigned_abs() <= value.unsigned_abs());
+}
+
+#[rstest]
+#[case(1.0, NonFinitePolicy::Clamp, 0x3F800000)]
+#[case(-1e-40, NonFinitePolicy::Clamp, 0x80000000)]
+#[case(f32::MIN_POSITIVE, NonFinitePolicy::Zero, 0x00800000)]
+#[case(f32::INFINITY, NonFinitePolicy::Clamp, 0x7FFFFFFF)]
+#[case(f32::NEG_INFINITY, NonFinitePolicy::Preserve, 0xFF800000)]
+#[case(f32::NEG_INFINITY, NonFinitePolicy::Zero, 0x80000000)]
+#[case(-f32::NAN, NonFinitePolicy::Clamp, 0xFFFFFFFF)]
+#[case(f32::NAN, NonFinitePolicy::Preserve, 0x7FC00000)]
+fn ps2float_from_f32_with_policy(
+    #[case] value: f32,
+    #[case] policy: NonFinitePolicy,
+    #[case] expected: u32,
+) {
+    assert_eq!(Ps2Float::from_f32_with_policy(value, policy).as_u32(), expected);
+
+    let checked = Ps2Float::try_from_f32(value, policy).map(|float| float.as_u32());
+    if value.is_nan() {
+        assert_eq!(checked, Err(TryFromF32Error { bits: value.to_bits() }));
+    } else {
+        assert_eq!(checked, Ok(expected));
+    }
+}
+
+#[rstest]
+#[case(0.5, 0x3F000000)]
+#[case(-0.0, 0x80000000)]
+#[case(1e-40, 0x00000000)]
+#[case(f32::MAX, 0x7F7FFFFF)]
+#[case(f32::NEG_INFINITY, 0xFFFFFFFF)]
+#[case(f32::NAN, 0x7FFFFFFF)]
+fn ps2float_from_f32(#[case] value: f32, #[case] expected: u32) {
+    assert_eq!(Ps2Float::from(value).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, NonFinitePolicy::Clamp, 0x3F800000)]
+#[case(0x80400000, NonFinitePolicy::Clamp, 0x80000000)] // Denormalized
+#[case(0x7F7FFFFF, NonFinitePolicy::Zero, 0x7F7FFFFF)]
+#[case(0x7FFFFFFF, NonFinitePolicy::Clamp, 0x7F7FFFFF)] // Fmax
+#[case(0xFF800000, NonFinitePolicy::Clamp, 0xFF7FFFFF)]
+#[case(0xFF800000, NonFinitePolicy::Preserve, 0xFF800000)]
+#[case(0x7FC00000, NonFinitePolicy::Preserve, 0x7FC00000)]
+#[case(0xFFFFFFFF, NonFinitePolicy::Zero, 0x80000000)] // -Fmax
+fn ps2float_to_f32_with_policy(
+    #[case] value: u32,
+    #[case] policy: NonFinitePolicy,
+    #[case] expected: u32,
+) {
+    let float = Ps2Float::new(value);
+
+    assert_eq!(float.to_f32_with_policy(policy).to_bits(), expected);
+
+    let checked = float.try_to_f32().map(f32::to_bits);
+    if float.exponent() == 255 {
+        assert_eq!(checked, Err(TryFromPs2FloatError::OutOfRange { value: float }));
+    } else {
+        assert_eq!(checked, Ok(expected));
+    }
+}
+
+#[rstest]
+#[case(0x3F000000)]
+#[case(0x80000000)]
+#[case(0x00800000)]
+#[case(0xC2280000)]
+#[case(0x7F7FFFFF)]
+fn ps2float_to_f32_round_trip(#[case] value: u32) {
+    let float = Ps2Float::new(value);
+
+    assert_eq!(Ps2Float::from(float.to_f32()), float);
+}
+
+#[rstest]
+#[case(1.0, 0x3F800000)]
+#[case(-0.0, 0x80000000)]
+#[case(0.1, 0x3DCCCCCC)] // 0x3DCCCCCD as an f32
+#[case(-0.1, 0xBDCCCCCC)]
+#[case(1e-39, 0x00000000)] // Below the normalized range
+#[case(-1e-300, 0x80000000)]
+#[case(2f64.powi(128), 0x7F800000)] // Past f32::MAX, still in range
+#[case(2f64.powi(129) - 2f64.powi(105), 0x7FFFFFFF)] // Fmax
+#[case(2f64.powi(129), 0x7FFFFFFF)]
+#[case(f64::NEG_INFINITY, 0xFFFFFFFF)]
+#[case(f64::NAN, 0x7FFFFFFF)]
+fn ps2float_from_f64(#[case] value: f64, #[case] expected: u32) {
+    assert_eq!(Ps2Float::from_f64(value).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 1.0)]
+#[case(0x80400000, -0.0)] // Denormalized
+#[case(0x3DCCCCCC, 13421772.0 / 134217728.0)]
+#[case(0x7F800000, 2f64.powi(128))]
+#[case(0xFFFFFFFF, 2f64.powi(105) - 2f64.powi(129))] // -Fmax
+fn ps2float_to_f64(#[case] value: u32, #[case] expected: f64) {
+    let float = Ps2Float::new(value);
+
+    assert_eq!(float.to_f64().to_bits(), expected.to_bits());
+    if float.exponent() != 0 {
+        assert_eq!(Ps2Float::from_f64(float.to_f64()), float);
+    }
+}
+
+#[test]
+fn try_from_f32_error_display() {
+    let error = Ps2Float::try_from_f32(-f32::NAN, NonFinitePolicy::Clamp).unwrap_err();
+
+    assert_eq!(error.to_string(), "0xFFC00000 is a NaN");
+}
+
+#[rstest]
+#[case(0x0000, 0x00000000)] // 0.00
+#[case(0x1000, 0x3F800000)] // 1.00
+#[case(-0x0800, 0xBF000000)] // -0.50
+#[case(0x0001, 0x39800000)] // 2^-12
+#[case(i16::MAX, 0x40FFFE00)] // 8 - 2^-12
+#[case(i16::MIN, 0xC1000000)] // -8.00
+fn fixed_1_3_12_round_trip(#[case] fixed: i16, #[case] float: u32) {
+    assert_eq!(from_fixed_1_3_12(fixed).as_u32(), float);
+    assert_eq!(to_fixed_1_3_12(Ps2Float::new(float)), fixed);
+}
+
+#[rstest]
+#[case(0x00000000, 0x00000000)] // 0.00
+#[case(0x00010000, 0x3F800000)] // 1.00
+#[case(-0x00018000, 0xBFC00000)] // -1.50
+#[case(0x00000001, 0x37800000)] // 2^-16
+#[case(0x00FFFFFF, 0x437FFFFF)] // 256 - 2^-16
+#[case(i32::MIN, 0xC7000000)] // -32768.00
+fn fixed_1_15_16_round_trip(#[case] fixed: i32, #[case] float: u32) {
+    assert_eq!(from_fixed_1_15_16(fixed).as_u32(), float);
+    assert_eq!(to_fixed_1_15_16(Ps2Float::new(float)), fixed);
+}
+
+#[rstest]
+#[case(0x40FFFFFF, 0x7FFF)] // Just below 8, truncated
+#[case(0x41000000, i16::MAX)] // 8.00, saturated
+#[case(0xC1800000, i16::MIN)] // -16.00, saturated
+#[case(0xB97FFFFF, 0x0000)] // Just above -2^-12, truncated toward zero
+#[case(0xB9FFFFFF, -0x0001)] // Just above -2^-11, truncated toward zero
+#[case(0x00400000, 0x0000)] // Denormalized
+#[case(0xFFFFFFFF, i16::MIN)] // -MAX
+fn to_fixed_1_3_12_truncates_and_saturates(#[case] float: u32, #[case] expected: i16) {
+    assert_eq!(to_fixed_1_3_12(Ps2Float::new(float)), expected);
+}
+
+#[test]
+fn from_fixed_1_15_16_truncates() {
+    // 32767 + 65535/65536 needs 31 significant bits.
+    assert_eq!(from_fixed_1_15_16(i32::MAX).as_u32(), 0x46FFFFFF);
+    assert_eq!(from_fixed_1_15_16(-i32::MAX).as_u32(), 0xC6FFFFFF);
+    assert_eq!(to_fixed_1_15_16(Ps2Float::new(0x47000000)), i32::MAX);
+}
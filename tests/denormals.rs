@@ -0,0 +1,100 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::denormals::{denormal_mode, with_denormal_mode, DenormalMode};
+use ps2_floating_point::explain::SpecialCase;
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(0x00000003, 0x00000001, 0x00000000, 0x00000004)]
+#[case(0x00400000, 0x00400000, 0x00000000, 0x00800000)] // Carries into the normalized range
+#[case(0x00800000, 0x80000001, 0x00800000, 0x007FFFFF)] // Drops out of it
+#[case(0x00800000, 0x80800001, 0x80000000, 0x80000001)] // Normalized operands underflow
+#[case(0x00000001, 0x80000001, 0x00000000, 0x00000000)] // Cancellation is +0
+#[case(0x3F800000, 0x00000001, 0x3F800000, 0x3F800000)] // Shifted out
+#[case(0x7FFFFFFF, 0x007FFFFF, 0x7FFFFFFF, 0x7FFFFFFF)]
+#[case(0x80000000, 0x80000000, 0x80000000, 0x80000000)] // Zeros aren't affected
+#[case(0x3F800000, 0x3F800000, 0x40000000, 0x40000000)]
+fn denormals_add(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] flushed: u32,
+    #[case] passed_through: u32,
+) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.add(b).as_u32(), flushed);
+    let carried = with_denormal_mode(DenormalMode::PassThrough, || a.add(b));
+    assert_eq!(carried.as_u32(), passed_through);
+}
+
+#[rstest]
+#[case(0x00000001, 0x80000001, 0x00000000, 0x00000002)]
+#[case(0x00800001, 0x00800000, 0x00000000, 0x00000001)] // Normalized operands underflow
+#[case(0x00000001, 0x00800000, 0x80800000, 0x807FFFFF)]
+#[case(0x80000005, 0x80000005, 0x80000000, 0x80000000)] // Equal operands keep the sign
+#[case(0x00000000, 0x00000000, 0x00000000, 0x00000000)]
+fn denormals_sub(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] flushed: u32,
+    #[case] passed_through: u32,
+) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.sub(b).as_u32(), flushed);
+    let carried = with_denormal_mode(DenormalMode::PassThrough, || a.sub(b));
+    assert_eq!(carried.as_u32(), passed_through);
+}
+
+#[test]
+fn denormals_pass_through_matches_truncated_ieee_sum() {
+    let mut seed = 0x9E3779B9u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        // Positive floats with exponents 0 to 3, mixing denormalized floats with the
+        // smallest normalized ones
+        seed & 0x01FFFFFF
+    };
+
+    with_denormal_mode(DenormalMode::PassThrough, || {
+        for _ in 0..10_000 {
+            let (a, b) = (next(), next());
+            // Sums this small are exact in an f64, truncate them to an f32.
+            let sum = f32::from_bits(a) as f64 + f32::from_bits(b) as f64;
+            let mut truncated = sum as f32;
+            if truncated as f64 > sum {
+                truncated = f32::from_bits(truncated.to_bits() - 1);
+            }
+
+            let result = Ps2Float::new(a).add(Ps2Float::new(b));
+            assert_eq!(result.as_u32(), truncated.to_bits(), "0x{:08X} + 0x{:08X}", a, b);
+        }
+    });
+}
+
+#[test]
+fn denormals_pass_through_traces_operand() {
+    let a = Ps2Float::new(0x00000003);
+
+    let explanation =
+        with_denormal_mode(DenormalMode::PassThrough, || a.add_explained(Ps2Float::new(1)));
+
+    assert_eq!(explanation.result.as_u32(), 0x00000004);
+    assert_eq!(explanation.special_case, Some(SpecialCase::DenormalizedOperand));
+}
+
+#[test]
+fn denormal_mode_is_restored() {
+    assert_eq!(denormal_mode(), DenormalMode::Flush);
+
+    let nested = with_denormal_mode(DenormalMode::PassThrough, || {
+        with_denormal_mode(DenormalMode::Flush, denormal_mode)
+    });
+    assert_eq!(nested, DenormalMode::Flush);
+
+    let panicked = std::panic::catch_unwind(|| {
+        with_denormal_mode(DenormalMode::PassThrough, || panic!("restored anyway"))
+    });
+    assert!(panicked.is_err());
+    assert_eq!(denormal_mode(), DenormalMode::Flush);
+}
@@ -0,0 +1,64 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::divergence::{
+    compare, find_divergences, run_ee, run_vu, Divergence, UnitOutcome,
+};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+fn f(bits: u32) -> Ps2Float {
+    Ps2Float::new(bits)
+}
+
+#[rstest]
+#[case(Operation::Add, 0x3F800000, 0x40000000)] // 1.00 + 2.00
+#[case(Operation::Add, 0x7FFFFFFE, 0x7FFFFFFE)] // Overflows on both
+#[case(Operation::Sub, 0x00800001, 0x00800000)] // Underflows on both
+#[case(Operation::Mul, 0x40000000, 0x40400000)] // 2.00 * 3.00
+#[case(Operation::Mul, 0x7FFFFFFF, 0x40000000)] // Overflows on both
+#[case(Operation::Mul, 0x00800000, 0x00800000)] // Underflows on both
+#[case(Operation::Mul, 0x200007FF, 0x1FFFF003)] // Underflows once Booth drops the carry
+#[case(Operation::Div, 0x3F800000, 0x00000000)] // Divides by zero on both
+#[case(Operation::Div, 0x00000000, 0x00000000)] // Invalid on both
+fn units_agree(#[case] operation: Operation, #[case] a: u32, #[case] b: u32) {
+    assert_eq!(run_ee(operation, f(a), f(b)), run_vu(operation, f(a), f(b)));
+    assert_eq!(compare(operation, f(a), f(b)), None);
+}
+
+#[rstest]
+#[case(0x7FFFFFFF, 0x40000000, 0x7FFFFFFF, UnitOutcome { overflow: true, ..Default::default() })]
+#[case(0x00800000, 0x00800000, 0x00000000, UnitOutcome { underflow: true, ..Default::default() })]
+fn units_raise_mul_flags(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] result: u32,
+    #[case] flags: UnitOutcome,
+) {
+    let expected = UnitOutcome { result: f(result), ..flags };
+
+    assert_eq!(run_ee(Operation::Mul, f(a), f(b)), expected);
+    assert_eq!(run_vu(Operation::Mul, f(a), f(b)), expected);
+}
+
+#[test]
+fn find_divergences_none_when_units_agree() {
+    let pairs = [(0x3F800000, 0x40000000), (0xFFFFFFFF, 0x40000000), (0x00800000, 0x00800000)]
+        .map(|(a, b)| (f(a), f(b)));
+
+    assert_eq!(find_divergences(Operation::Mul, pairs), []);
+}
+
+#[test]
+fn divergence_display() {
+    let divergence = Divergence {
+        operation: Operation::Mul,
+        a: f(0xFFFFFFFF),
+        b: f(0x40000000),
+        ee: UnitOutcome { result: f(0xFFFFFFFF), overflow: true, ..Default::default() },
+        vu: UnitOutcome { result: f(0xFFFFFFFF), ..Default::default() },
+    };
+
+    assert_eq!(
+        divergence.to_string(),
+        "0xFFFFFFFF * 0x40000000: EE 0xFFFFFFFF [O], VU 0xFFFFFFFF [-]"
+    );
+}
@@ -0,0 +1,40 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{FloatOps, Ps2Dual, Ps2Float};
+use rstest::*;
+
+/// Returns `3x - 1`, written once for any numeric model.
+fn three_x_minus_one<T: FloatOps>(x: T) -> T {
+    x.add(x).add(x).sub(T::ONE)
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000)] // 1.00
+#[case(0xC0400000, 0xC1200000)] // -3.00
+#[case(0x7F7FFFFF, 0x7FFFFFFF)] // Saturates at Fmax
+fn ps2dual_generic(#[case] x: u32, #[case] expected: u32) {
+    let y = three_x_minus_one(Ps2Dual::variable(Ps2Float::new(x)));
+
+    assert_eq!(y.value(), three_x_minus_one(Ps2Float::new(x)));
+    assert_eq!(y.value().as_u32(), expected);
+    assert_eq!(y.derivative().as_u32(), 0x40400000); // 3.00
+}
+
+#[test]
+fn ps2dual_derivative_truncates() {
+    let a = Ps2Dual::new(Ps2Float::new(0x3F800000), Ps2Float::new(0x3F800000));
+    let b = Ps2Dual::new(Ps2Float::new(0x3F800000), Ps2Float::new(0x33FFFFFF));
+
+    let sum = a + b;
+
+    assert_eq!(sum.derivative().as_u32(), 0x3F800000);
+    assert_eq!((1.0f32 + f32::from_bits(0x33FFFFFF)).to_bits(), 0x3F800001);
+}
+
+#[test]
+fn ps2dual_constant() {
+    let c = Ps2Dual::from(Ps2Float::new(0x40A00000));
+
+    assert_eq!(c.derivative().as_u32(), 0);
+    assert_eq!((c + c).to_string(), "10.00 + Denormalized(0.00)ε");
+    assert_eq!(Ps2Dual::variable(Ps2Float::new(0x40A00000)).to_string(), "5.00 + 1.00ε");
+}
@@ -0,0 +1,140 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::explain::{NormalizationStep, SpecialCase};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+#[test]
+fn explain_add_with_alignment_and_normalization() {
+    let a = Ps2Float::new(0x40400000); // 3.00
+    let b = Ps2Float::new(0x3FC00001); // 1.50 + 1 ULP
+
+    let explanation = a.add_explained(b);
+
+    assert_eq!(explanation.operation, Operation::Add);
+    assert_eq!(explanation.special_case, None);
+    assert!(explanation.magnitude_add);
+    assert_eq!(explanation.alignment_shift, 1);
+    assert_eq!(explanation.aligned_mantissas, (0xC00000, 0x600000));
+    assert_eq!(explanation.truncated_bits, 1);
+    assert_eq!(explanation.raw_mantissa, 0x1200000);
+    assert_eq!(
+        explanation.normalization,
+        vec![NormalizationStep::ShiftRight { mantissa: 0x900000, exponent: 0x81 }]
+    );
+    assert_eq!(explanation.result, a.add(b));
+    assert_eq!(explanation.result.as_u32(), 0x40900000); // 4.50
+}
+
+#[test]
+fn explain_sub_with_left_shifts() {
+    let a = Ps2Float::new(0x3F800001); // 1.00 + 1 ULP
+    let b = Ps2Float::new(0x3F800000); // 1.00
+
+    let explanation = a.sub_explained(b);
+
+    assert!(!explanation.magnitude_add);
+    assert_eq!(explanation.raw_mantissa, 1);
+    assert_eq!(explanation.normalization.len(), 23);
+    assert_eq!(
+        explanation.normalization.last(),
+        Some(&NormalizationStep::ShiftLeft { mantissa: 0x800000, exponent: 0x68 })
+    );
+    assert_eq!(explanation.result, a.sub(b));
+}
+
+#[rstest]
+#[case(0x00000001, 0x3F800000, Operation::Add, SpecialCase::DenormalizedOperand)]
+#[case(0x7FFFFFFF, 0x7FFFFFFF, Operation::Add, SpecialCase::AbnormalOperands)]
+#[case(0x3F800000, 0x3F800000, Operation::Sub, SpecialCase::EqualOperands)]
+#[case(0x3F800000, 0xBF800000, Operation::Add, SpecialCase::Cancellation)]
+fn explain_special_cases(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] operation: Operation,
+    #[case] expected: SpecialCase,
+) {
+    let a = Ps2Float::new(a);
+    let b = Ps2Float::new(b);
+
+    let explanation = match operation {
+        Operation::Add => a.add_explained(b),
+        _ => a.sub_explained(b),
+    };
+
+    assert_eq!(explanation.special_case, Some(expected));
+    assert_eq!(explanation.result, operation.apply(a, b));
+}
+
+#[test]
+fn explain_overflow_display() {
+    let a = Ps2Float::new(0x7FFFFFFE);
+
+    let explanation = a.add_explained(a);
+
+    assert_eq!(explanation.normalization, vec![NormalizationStep::Overflow]);
+    assert_eq!(
+        explanation.to_string(),
+        "0x7FFFFFFE + 0x7FFFFFFE\n\
+         1. classify: a is IEEE 754 NaN pattern, b is IEEE 754 NaN pattern\n\
+         2. align: shift smaller mantissa right by 0, truncating bits 0x0\n\
+         3. add magnitudes: 0xFFFFFE + 0xFFFFFE = 0x1FFFFFC\n\
+         4. normalize: exponent overflow, clamp to Fmax\n\
+         5. result: 0x7FFFFFFF (Fmax(680564693277057719623408366969033850880.00))"
+    );
+}
+
+#[test]
+fn explain_markdown_derivation() {
+    let a = Ps2Float::new(0x40400000); // 3.00
+    let b = Ps2Float::new(0x3FC00001); // 1.50 + 1 ULP
+
+    assert_eq!(
+        a.add_explained(b).to_markdown(),
+        "**`0x40400000` + `0x3FC00001`**\n\
+         \n\
+         | | Sign | Exponent | Mantissa | Value |\n\
+         |---|---|---|---|---|\n\
+         | a | `0` | `10000000` | `10000000000000000000000` | 3.00 |\n\
+         | b | `0` | `01111111` | `10000000000000000000001` | 1.50 |\n\
+         | result | `0` | `10000001` | `00100000000000000000000` | 4.50 |\n\
+         \n\
+         1. **Classify:** a is normalized, b is normalized.\n\
+         \n\
+         2. **Align:** shift the smaller mantissa right by 1, truncating bits 1.\n\
+         \n\
+         3. **Add magnitudes:**\n\
+         \n   ```text\n\
+         \x20     110000000000000000000000\n\
+         \x20  +   11000000000000000000000\n\
+         \x20  = 1001000000000000000000000\n\
+         \x20  ```\n\
+         \n\
+         4. **Normalize:** shift right to 0x900000, exponent 0x81.\n\
+         \n\
+         5. **Result:** 0x40900000 (4.50).\n"
+    );
+}
+
+#[test]
+fn explain_latex_derivation() {
+    let a = Ps2Float::new(0x3F800000); // 1.00
+    let b = Ps2Float::new(0x3F800000); // 1.00
+
+    assert_eq!(
+        a.sub_explained(b).to_latex(),
+        "\\[ \\texttt{0x3F800000} - \\texttt{0x3F800000} \\]\n\
+         \\begin{tabular}{lllll}\n\
+         \x20& Sign & Exponent & Mantissa & Value \\\\\n\
+         \\hline\n\
+         a & \\texttt{0} & \\texttt{01111111} & \\texttt{00000000000000000000000} & 1.00 \\\\\n\
+         b & \\texttt{0} & \\texttt{01111111} & \\texttt{00000000000000000000000} & 1.00 \\\\\n\
+         result & \\texttt{0} & \\texttt{00000000} & \\texttt{00000000000000000000000}\
+         \x20& Denormalized(0.00) \\\\\n\
+         \\end{tabular}\n\
+         \\begin{enumerate}\n\
+         \\item \\textbf{Classify:} a is normalized, b is normalized.\n\
+         \\item \\textbf{Special case:} equal operands, difference is zero.\n\
+         \\item \\textbf{Result:} 0x00000000 (Denormalized(0.00)).\n\
+         \\end{enumerate}\n"
+    );
+}
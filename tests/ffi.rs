@@ -0,0 +1,96 @@
+#![cfg(feature = "ffi")]
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::ffi::*;
+use rstest::*;
+
+#[rstest]
+#[case(0x3F800000, 0x3F800000, 0x40000000)] // 1.00 + 1.00 = 2.00
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF)] // MAX + MAX = MAX
+fn ffi_add(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    assert_eq!(ps2float_add(a, b), expected);
+}
+
+#[rstest]
+#[case(0x40400000, 0x3F800000, 0x40000000)] // 3.00 - 1.00 = 2.00
+#[case(0xFF800000, 0x7F800000, 0xFFFFFFFF)] // -INF - INF = -MAX
+fn ffi_sub(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    assert_eq!(ps2float_sub(a, b), expected);
+}
+
+#[rstest]
+#[case(5.3, 0x40A9999A)]
+#[case(f32::from_bits(0x00000001), 0x00000000)] // Denormalized
+#[case(f32::from_bits(0x807FFFFF), 0x80000000)] // -Denormalized
+#[case(f32::INFINITY, 0x7F800000)]
+#[case(-f32::NAN, 0xFFC00000)]
+fn ffi_from_f32(#[case] value: f32, #[case] expected: u32) {
+    assert_eq!(ps2float_from_f32(value), expected);
+}
+
+#[rstest]
+#[case(0x40A9999A, 5.300000190734863)]
+#[case(0x7F800000, 2f64.powi(128))] // INF
+#[case(0xFFFFFFFF, -(2f64.powi(129) - 2f64.powi(105)))] // -MAX
+fn ffi_to_f64(#[case] value: u32, #[case] expected: f64) {
+    assert_eq!(ps2float_to_f64(value), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, -1)] // 1.00 < 2.00
+#[case(0x00000000, 0x80000000, 0)] // 0.00 == -0.00
+#[case(0x7FFFFFFF, 0x7F800000, 1)] // MAX > INF
+fn ffi_cmp(#[case] a: u32, #[case] b: u32, #[case] expected: i32) {
+    assert_eq!(ps2float_cmp(a, b), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x40400000, Ps2FloatFlags::default())] // 1.00 + 2.00
+#[case(0x7FFFFFFE, 0x7FFFFFFE, 0x7FFFFFFF, Ps2FloatFlags { overflow: true, ..Default::default() })]
+#[case(
+    0x00800001,
+    0x80800000,
+    0x00000000,
+    Ps2FloatFlags { zero: true, underflow: true, ..Default::default() }
+)]
+fn ffi_add_with_flags(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] expected: u32,
+    #[case] expected_flags: Ps2FloatFlags,
+) {
+    let mut flags = Ps2FloatFlags::default();
+
+    assert_eq!(ps2float_add_with_flags(a, b, Some(&mut flags)), expected);
+    assert_eq!(flags, expected_flags);
+    assert_eq!(ps2float_add_with_flags(a, b, None), expected);
+}
+
+#[test]
+fn ffi_sub_mul_div_with_flags() {
+    let mut flags = Ps2FloatFlags::default();
+
+    assert_eq!(ps2float_sub_with_flags(0x3F800000, 0x40000000, Some(&mut flags)), 0xBF800000);
+    assert_eq!(flags, Ps2FloatFlags { sign: true, ..Default::default() });
+    assert_eq!(ps2float_mul_with_flags(0x7FFFFFFF, 0xC0000000, Some(&mut flags)), 0xFFFFFFFF);
+    assert_eq!(flags, Ps2FloatFlags { sign: true, overflow: true, ..Default::default() });
+    // Dividing by zero saturates without raising O.
+    assert_eq!(ps2float_div_with_flags(0x3F800000, 0x00000000, Some(&mut flags)), 0x7FFFFFFF);
+    assert_eq!(flags, Ps2FloatFlags::default());
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x3F000000, Ps2FloatDivideFlags::default())] // 1.00 / 2.00
+#[case(0xBF800000, 0x00000000, 0xFFFFFFFF, Ps2FloatDivideFlags { invalid: false, divide: true })]
+#[case(0x00000000, 0x80000000, 0xFFFFFFFF, Ps2FloatDivideFlags { invalid: true, divide: false })]
+fn ffi_div_with_divide_flags(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] expected: u32,
+    #[case] expected_flags: Ps2FloatDivideFlags,
+) {
+    let mut flags = Ps2FloatDivideFlags::default();
+
+    assert_eq!(ps2float_div_with_divide_flags(a, b, Some(&mut flags)), expected);
+    assert_eq!(flags, expected_flags);
+}
@@ -0,0 +1,35 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{FloatOps, Ps2Float};
+use rstest::*;
+
+fn running_sum<T: FloatOps>(values: &[u32]) -> u32 {
+    values.iter().fold(T::ZERO, |total, value| total.add(T::from_bits(*value))).to_bits()
+}
+
+#[rstest]
+#[case(&[0x3F800000, 0x3DCCCCCD], 0x3F8CCCCC, 0x3F8CCCCD)] // 1.0 + 0.1
+#[case(&[0x7F7FFFFF, 0x7F7FFFFF], 0x7FFFFFFF, 0x7F800000)] // Fmax vs infinity
+#[case(&[0x3F800000, 0xBF800000], 0x00000000, 0x00000000)] // 1.0 - 1.0
+fn float_ops_generic_sum(#[case] values: &[u32], #[case] ps2: u32, #[case] ieee: u32) {
+    assert_eq!(running_sum::<Ps2Float>(values), ps2);
+    assert_eq!(running_sum::<f32>(values), ieee);
+}
+
+#[rstest]
+#[case(Ps2Float::ZERO.to_bits(), 0x00000000)]
+#[case(Ps2Float::ONE.to_bits(), 0x3F800000)]
+#[case(Ps2Float::MAX.to_bits(), 0x7FFFFFFF)]
+#[case(Ps2Float::MIN.to_bits(), 0xFFFFFFFF)]
+#[case(<f32 as FloatOps>::MAX.to_bits(), 0x7F7FFFFF)]
+#[case(<f32 as FloatOps>::MIN.to_bits(), 0xFF7FFFFF)]
+fn float_ops_constants(#[case] bits: u32, #[case] expected: u32) {
+    assert_eq!(bits, expected);
+}
+
+#[test]
+fn float_ops_sub() {
+    let (a, b) = (Ps2Float::new(0x3F800000), Ps2Float::new(0x3DCCCCCD));
+
+    assert_eq!(FloatOps::sub(a, b), a.sub(b));
+    assert_eq!(FloatOps::sub(1.0f32, 0.1), 0.9);
+}
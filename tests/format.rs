@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::format::FormatBuffer;
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(0x40A9999A)] // 5.3
+#[case(0x80000001)] // -Denormalized
+#[case(0x7FFFFFFF)] // MAX
+#[case(0xFFFFFFFF)] // -MAX
+#[case(0xFF800000)] // -INF
+#[case(0xFF7FFFFF)] // Largest negative regular value
+fn format_buffer_fits_every_display(#[case] value: u32) {
+    let value = Ps2Float::new(value);
+
+    let buffer = value.to_format_buffer::<64>();
+
+    assert!(!buffer.is_truncated());
+    assert_eq!(buffer.as_str(), value.to_string());
+}
+
+#[test]
+fn format_buffer_write_to() {
+    let mut buffer = FormatBuffer::<16>::new();
+
+    Ps2Float::new(0x3F800000).write_to(&mut buffer).unwrap();
+    buffer.write_str(", ").unwrap();
+    Ps2Float::new(0xC0000000).write_to(&mut buffer).unwrap();
+
+    assert_eq!(buffer.as_str(), "1.00, -2.00");
+    assert_eq!(buffer.len(), 11);
+}
+
+#[test]
+fn format_buffer_truncates_at_char_boundary() {
+    let mut buffer = FormatBuffer::<5>::new();
+
+    assert!(buffer.write_str("ab").is_ok());
+    assert!(buffer.write_str("cdé").is_err());
+
+    assert_eq!(buffer.as_str(), "abcd");
+    assert!(buffer.is_truncated());
+
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert!(!buffer.is_truncated());
+}
@@ -0,0 +1,196 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::fpu::{
+    BranchOutcome, FpuBranch, FpuCompare, FpuContext, FCR0_REVISION, FCR31_C, FCR31_D, FCR31_I,
+    FCR31_O, FCR31_SD, FCR31_SI, FCR31_SO, FCR31_SU, FCR31_U,
+};
+use ps2_floating_point::vu::instruction::UnsupportedInstruction;
+use ps2_floating_point::{Ps2Accumulator, Ps2Float};
+use rstest::*;
+
+#[rstest]
+#[case(0x45000003, FpuBranch::Bc1f { offset: 3 })]
+#[case(0x45010003, FpuBranch::Bc1t { offset: 3 })]
+#[case(0x4502FFFE, FpuBranch::Bc1fl { offset: -2 })]
+#[case(0x45038000, FpuBranch::Bc1tl { offset: -0x8000 })]
+fn fpu_branch_decode(#[case] word: u32, #[case] branch: FpuBranch) {
+    assert_eq!(FpuBranch::decode(word), Ok(branch));
+}
+
+#[rstest]
+#[case(0x45040003)] // Reserved condition
+#[case(0x44020800)] // CFC1
+#[case(0x46010032)] // C.EQ.S
+#[case(0x41000003)] // BC0F
+fn fpu_branch_decode_unsupported(#[case] word: u32) {
+    assert_eq!(FpuBranch::decode(word), Err(UnsupportedInstruction(word)));
+}
+
+#[rstest]
+#[case(FpuBranch::Bc1f { offset: 0 }, 0, true, true)]
+#[case(FpuBranch::Bc1f { offset: 0 }, FCR31_C, false, true)]
+#[case(FpuBranch::Bc1t { offset: 0 }, FCR31_C, true, true)]
+#[case(FpuBranch::Bc1t { offset: 0 }, 0, false, true)]
+#[case(FpuBranch::Bc1fl { offset: 0 }, 0, true, true)]
+#[case(FpuBranch::Bc1fl { offset: 0 }, FCR31_C, false, false)]
+#[case(FpuBranch::Bc1tl { offset: 0 }, FCR31_C, true, true)]
+#[case(FpuBranch::Bc1tl { offset: 0 }, 0, false, false)]
+#[case(FpuBranch::Bc1t { offset: 0 }, !FCR31_C, false, true)] // Other FCR31 bits are ignored
+fn fpu_branch_evaluate(
+    #[case] branch: FpuBranch,
+    #[case] fcr31: u32,
+    #[case] taken: bool,
+    #[case] executes_delay_slot: bool,
+) {
+    assert_eq!(branch.evaluate(fcr31), BranchOutcome { taken, executes_delay_slot });
+}
+
+#[rstest]
+#[case(3, 0x00100000, 0x00100010)]
+#[case(-1, 0x00100000, 0x00100000)]
+#[case(-2, 0x00000000, 0xFFFFFFFC)]
+fn fpu_branch_target(#[case] offset: i16, #[case] pc: u32, #[case] target: u32) {
+    assert_eq!(FpuBranch::Bc1f { offset }.target(pc), target);
+}
+
+/// The bits of FCR31 that always read as set.
+const FCR31_FIXED: u32 = 0x01000001;
+
+fn f(bits: u32) -> Ps2Float {
+    Ps2Float::new(bits)
+}
+
+#[rstest]
+#[case(0, FCR0_REVISION)]
+#[case(1, 0)]
+#[case(31, FCR31_FIXED)]
+fn fpu_context_cfc1_after_reset(#[case] fs: u8, #[case] value: u32) {
+    assert_eq!(FpuContext::new().cfc1(fs), value);
+    assert_eq!(FpuContext::default().cfc1(fs), value);
+}
+
+#[rstest]
+#[case(31, 0xFFFFFFFF, 0x0183C079)] // Only C and the flags are writable
+#[case(31, FCR31_C | FCR31_SO, FCR31_FIXED | FCR31_C | FCR31_SO)]
+#[case(31, 0, FCR31_FIXED)]
+#[case(0, 0xFFFFFFFF, FCR31_FIXED)] // FCR0 is read-only
+fn fpu_context_ctc1(#[case] fs: u8, #[case] value: u32, #[case] fcr31: u32) {
+    let mut fpu = FpuContext::new();
+    fpu.ctc1(fs, value);
+
+    assert_eq!(fpu.cfc1(31), fcr31);
+    assert_eq!(fpu.cfc1(0), FCR0_REVISION);
+}
+
+#[test]
+fn fpu_context_cause_flags_are_replaced_and_sticky_flags_kept() {
+    let mut fpu = FpuContext::new();
+
+    assert_eq!(fpu.add(f(0x7FFFFFFE), f(0x7FFFFFFE)), Ps2Float::max());
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_O | FCR31_SO);
+
+    assert_eq!(fpu.sub(f(0x00800001), f(0x00800000)).as_u32(), 0x00000000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_U | FCR31_SO | FCR31_SU);
+
+    assert_eq!(fpu.mul(f(0x40000000), f(0x40400000)).as_u32(), 0x40C00000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_SO | FCR31_SU);
+}
+
+#[test]
+fn fpu_context_divide_flags() {
+    let mut fpu = FpuContext::new();
+
+    assert_eq!(fpu.div(f(0x3F800000), f(0x00000000)), Ps2Float::max());
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_D | FCR31_SD);
+
+    assert_eq!(fpu.sqrt(f(0xC0800000)).as_u32(), 0x40000000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_I | FCR31_SI | FCR31_SD);
+
+    assert_eq!(fpu.rsqrt(f(0x3F800000), f(0x40800000)).as_u32(), 0x3F000000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_SI | FCR31_SD);
+
+    // Division doesn't touch the overflow flags.
+    assert_eq!(fpu.div(Ps2Float::max(), f(0x3F000000)), Ps2Float::max());
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_SI | FCR31_SD);
+}
+
+#[test]
+fn fpu_context_madd_and_msub() {
+    let mut fpu = FpuContext::new();
+    let one = f(0x3F800000);
+
+    let result = fpu.madd(f(0x3F800001), f(0x3F800001), f(0xBF800000));
+    assert_eq!(result, f(0x3F800001).madd(f(0x3F800001), f(0xBF800000)));
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED);
+
+    assert_eq!(fpu.msub(Ps2Float::max(), f(0x40000000), f(0xFFFFFFFE)), Ps2Float::min());
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_O | FCR31_SO);
+
+    assert_eq!(fpu.msub(one, one, one).as_u32(), 0x00000000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_SO);
+}
+
+#[test]
+fn fpu_context_madd_product_overflow() {
+    let (max, two) = (Ps2Float::max(), f(0x40000000));
+
+    // MAX * 2.00 saturates to MAX, then the sums of MAX and -MAX come from the
+    // abnormal table without overflowing.
+    let mut fpu = FpuContext::new();
+    assert_eq!(fpu.madd(max, two, Ps2Float::min()), max);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_O | FCR31_SO);
+    assert!(Ps2Accumulator::new(Ps2Float::min()).madda(max, two).overflowed());
+
+    let mut fpu = FpuContext::new();
+    assert_eq!(fpu.msub(max, two, max).as_u32(), 0x00000000);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_O | FCR31_SO);
+    assert!(Ps2Accumulator::new(max).msuba(max, two).overflowed());
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, false, true, true)] // 1.00, 2.00
+#[case(0x40000000, 0x3F800000, false, false, false)] // 2.00, 1.00
+#[case(0x3F800000, 0x3F800000, true, false, true)] // 1.00, 1.00
+#[case(0xC0000000, 0xBF800000, false, true, true)] // -2.00, -1.00
+#[case(0x00000000, 0x80000000, true, false, true)] // 0.00, -0.00
+#[case(0x80000001, 0x00000000, true, false, true)] // -Denormalized, 0.00
+#[case(0x00000001, 0x00800000, false, true, true)] // Denormalized, Smallest normalized
+#[case(0x7F800000, 0x7FFFFFFF, false, true, true)] // Inf, MAX
+#[case(0x7FFFFFFF, 0x7FFFFFFF, true, false, true)] // MAX, MAX
+#[case(0xFFFFFFFF, 0xFF7FFFFF, false, true, true)] // -MAX, -2^128
+#[case(0x7FC00000, 0x7FC00000, true, false, true)] // NaN patterns are ordinary numbers
+fn fpu_compare(
+    #[case] fs: u32,
+    #[case] ft: u32,
+    #[case] eq: bool,
+    #[case] lt: bool,
+    #[case] le: bool,
+) {
+    let (fs, ft) = (f(fs), f(ft));
+
+    assert!(!FpuCompare::F.evaluate(fs, ft));
+    assert_eq!(FpuCompare::Eq.evaluate(fs, ft), eq, "Testing C.EQ.S {} {}", fs, ft);
+    assert_eq!(FpuCompare::Lt.evaluate(fs, ft), lt, "Testing C.LT.S {} {}", fs, ft);
+    assert_eq!(FpuCompare::Le.evaluate(fs, ft), le, "Testing C.LE.S {} {}", fs, ft);
+}
+
+#[test]
+fn fpu_context_condition() {
+    let mut fpu = FpuContext::new();
+    let (one, two) = (f(0x3F800000), f(0x40000000));
+
+    assert!(!fpu.condition());
+    assert!(fpu.compare(FpuCompare::Lt, one, two));
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED | FCR31_C);
+    assert_eq!(
+        fpu.evaluate_branch(FpuBranch::Bc1fl { offset: 1 }),
+        BranchOutcome { taken: false, executes_delay_slot: false }
+    );
+
+    assert!(!fpu.compare(FpuCompare::F, one, one));
+    assert!(!fpu.condition());
+
+    fpu.set_condition(true);
+    assert!(fpu.condition());
+    fpu.set_condition(false);
+    assert_eq!(fpu.cfc1(31), FCR31_FIXED);
+}
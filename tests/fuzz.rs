@@ -0,0 +1,59 @@
+#![cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[cfg(feature = "arbitrary")]
+#[rstest]
+#[case(&[0x00, 0x0E], 0x7FFFFFFF)] // MAX
+#[case(&[0x3F, 0x0A], 0x7F800000)] // INF
+#[case(&[0x40, 0x9A, 0x99, 0xA9, 0x40], 0x40A9999A)] // 5.3
+fn ps2float_arbitrary(#[case] data: &[u8], #[case] expected: u32) {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut u = Unstructured::new(data);
+
+    let ps2float = Ps2Float::arbitrary(&mut u).unwrap();
+
+    assert_eq!(ps2float.as_u32(), expected);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn ps2vec4_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use ps2_floating_point::Ps2Vec4;
+
+    // MAX, INF, 5.3 and 1.0, each after the byte deciding if it is special
+    let data = [0x00, 0x0E, 0x3F, 0x0A, 0x40, 0x9A, 0x99, 0xA9, 0x40, 0x40, 0x00, 0x00, 0x80, 0x3F];
+    let mut u = Unstructured::new(&data);
+
+    let vector = Ps2Vec4::arbitrary(&mut u).unwrap();
+
+    assert_eq!(vector.to_bits(), [0x7FFFFFFF, 0x7F800000, 0x40A9999A, 0x3F800000]);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn ps2matrix_arbitrary_size_hint() {
+    use arbitrary::Arbitrary;
+    use ps2_floating_point::{Ps2Matrix, Ps2Vec4};
+
+    assert_eq!(Ps2Vec4::size_hint(0), (4, Some(20)));
+    assert_eq!(Ps2Matrix::size_hint(0), (16, Some(80)));
+}
+
+#[cfg(feature = "quickcheck")]
+#[rstest]
+#[case(0x40A9999A, vec![0x00000000, 0x40800000])] // 5.3
+#[case(0xC0A9999A, vec![0x80000000, 0xC0800000])] // -5.3
+#[case(0x40800000, vec![0x00000000])] // 4.00
+#[case(0x80000000, vec![])] // -0.00
+fn ps2float_quickcheck_shrink(#[case] value: u32, #[case] expected: Vec<u32>) {
+    use quickcheck::Arbitrary;
+
+    let shrunk: Vec<u32> = Ps2Float::new(value).shrink().map(|f| f.as_u32()).collect();
+
+    assert_eq!(shrunk, expected);
+}
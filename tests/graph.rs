@@ -0,0 +1,65 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::graph::{Node, OpGraph};
+use ps2_floating_point::trace::TraceRecord;
+use ps2_floating_point::{Operation, Ps2Float};
+
+#[test]
+fn graph_to_dot() {
+    let mut graph = OpGraph::new();
+    let one = graph.input(Ps2Float::new(0x3F800000));
+    let three = graph.input(Ps2Float::new(0x40400000));
+    let two = graph.apply(Operation::Sub, three, one);
+    let four = graph.record(Operation::Add, two, two, Ps2Float::new(0x40800000), 0x02);
+    graph.highlight(four);
+
+    assert_eq!(
+        graph.to_dot(),
+        r##"digraph ops {
+    node [fontname="monospace"];
+    n0 [shape=ellipse, label="input\n0x3F800000 (1.00)"];
+    n1 [shape=ellipse, label="input\n0x40400000 (3.00)"];
+    n2 [shape=box, label="#2 sub\n0x40000000 (2.00)"];
+    n3 [shape=box, label="#3 add\n0x40800000 (4.00)\nflags 0x02", color=red, fontcolor=red];
+    n1 -> n2 [label="a"];
+    n0 -> n2 [label="b"];
+    n2 -> n3 [label="a"];
+    n2 -> n3 [label="b"];
+}
+"##
+    );
+}
+
+#[test]
+fn graph_from_trace_links_results() {
+    let one = Ps2Float::new(0x3F800000);
+    let two = Ps2Float::new(0x40000000);
+    let records = [
+        TraceRecord::capture(Operation::Add, &one, &one, 0),
+        TraceRecord::capture(Operation::Add, &two, &one, 0),
+        TraceRecord {
+            operation: Operation::Add,
+            a: Ps2Float::max(),
+            b: Ps2Float::max(),
+            result: Ps2Float::new(0x7F800000), // IEEE 754 capture
+            flags: 0,
+        },
+    ];
+
+    let graph = OpGraph::from_trace(&records);
+
+    assert_eq!(graph.len(), 5);
+    match graph.nodes()[2] {
+        Node::Operation { a, b, value, .. } => {
+            assert_eq!(value.as_u32(), 0x40400000);
+            assert_eq!(a.index(), 1); // The result of 1.00 + 1.00
+            assert_eq!(b.index(), 0); // The 1.00 input
+        }
+        other => panic!("Expected an operation, got {:?}", other),
+    }
+    assert!(matches!(graph.nodes()[3], Node::Input { .. }));
+
+    let dot = graph.to_dot();
+    let highlighted: Vec<&str> = dot.lines().filter(|line| line.contains("color=red")).collect();
+    assert_eq!(highlighted.len(), 1);
+    assert!(highlighted[0].starts_with("    n4 [shape=box, label=\"#4 add\\n0x7F800000 (Inf("));
+}
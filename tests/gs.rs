@@ -0,0 +1,51 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::gs::{depth_to_z, to_z24, to_z32, DepthFormat, VertexRegister};
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(0x00000000, 0)]
+#[case(0x3F800000, 1)] // 1.0
+#[case(0x4B7FFFFF, 0xFFFFFF)] // 16777215.0
+#[case(0x4B800000, 0xFFFFFF)] // 16777216.0, clamped
+#[case(0x7FFFFFFF, 0xFFFFFF)] // Fmax, saturated then clamped
+#[case(0xBF800000, 0xFFFFFF)] // -1.0 is 0xFFFFFFFF
+#[case(0x00400000, 0)] // Denormalized
+fn gs_to_z24(#[case] depth: u32, #[case] z: u32) {
+    assert_eq!(to_z24(Ps2Float::new(depth)), z);
+}
+
+#[rstest]
+#[case(0x3F800000, 1)]
+#[case(0x4B800001, 0x01000002)] // 16777218.0
+#[case(0x4EFFFFFF, 0x7FFFFF80)]
+#[case(0x7FFFFFFF, 0x7FFFFFFF)] // Saturated
+#[case(0xBF800000, 0xFFFFFFFF)]
+#[case(0xFFFFFFFF, 0x80000000)]
+fn gs_to_z32(#[case] depth: u32, #[case] z: u32) {
+    assert_eq!(to_z32(Ps2Float::new(depth)), z);
+}
+
+#[rstest]
+#[case(0x3FC00000, VertexRegister::Xyz2, DepthFormat::Z32, 24)] // 1.5 in 28.4
+#[case(0x3FC00000, VertexRegister::Xyzf2, DepthFormat::Z32, 1)]
+#[case(0x4B000001, VertexRegister::Xyzf2, DepthFormat::Z24, 0x800001)]
+#[case(0x4B800000, VertexRegister::Xyzf2, DepthFormat::Z24, 0)] // Bit 24 isn't packed
+#[case(0x477FFF00, VertexRegister::Xyzf2, DepthFormat::Z16, 0xFFFF)] // 65535.0
+#[case(0x47800000, VertexRegister::Xyzf2, DepthFormat::Z16, 0xFFFF)] // 65536.0, clamped
+fn gs_depth_to_z_ftoi4(
+    #[case] depth: u32,
+    #[case] register: VertexRegister,
+    #[case] format: DepthFormat,
+    #[case] z: u32,
+) {
+    assert_eq!(depth_to_z(Ps2Float::new(depth), 4, register, format), z);
+}
+
+#[rstest]
+#[case(DepthFormat::Z32, 0xFFFFFFFF)]
+#[case(DepthFormat::Z24, 0x00FFFFFF)]
+#[case(DepthFormat::Z16, 0x0000FFFF)]
+fn gs_depth_format_max_value(#[case] format: DepthFormat, #[case] max: u32) {
+    assert_eq!(format.max_value(), max);
+}
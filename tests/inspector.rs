@@ -0,0 +1,49 @@
+#![cfg(feature = "egui")]
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::inspector::{Ps2FloatInspector, VuRegisterInspector};
+use ps2_floating_point::vu::VuRegisterFile;
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+/// Shows a widget for a single frame, returning if it reported a change.
+fn show_frame(add: impl FnOnce(&mut egui::Ui) -> egui::Response) -> bool {
+    let mut add = Some(add);
+    let mut changed = false;
+    let _ = egui::Context::default().run(egui::RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if let Some(add) = add.take() {
+                changed = add(ui).changed();
+            }
+        });
+    });
+    changed
+}
+
+#[rstest]
+#[case(0x40A9999A)] // 5.3
+#[case(0x80000000)] // -0.00
+#[case(0x00400000)] // Denormalized
+#[case(0xFFFFFFFF)] // -MAX
+fn ps2float_inspector_keeps_value(#[case] bits: u32) {
+    let mut value = Ps2Float::new(bits);
+
+    let changed = show_frame(|ui| ui.add(Ps2FloatInspector::new(&mut value)));
+
+    assert!(!changed);
+    assert_eq!(value.as_u32(), bits);
+}
+
+#[test]
+fn vu_register_inspector_keeps_registers() {
+    let mut registers = VuRegisterFile::new();
+    registers.set_vf(1, Ps2Vec4::from_bits([0x3F800000, 0x7F800000, 0xFFFFFFFF, 0x00400000]));
+    registers.set_vi(3, 0xBEEF);
+    registers.q = Ps2Float::new(0x40000000);
+    let before = registers.clone();
+
+    let changed = show_frame(|ui| ui.add(VuRegisterInspector::new(&mut registers)));
+
+    assert!(!changed);
+    assert_eq!(registers, before);
+}
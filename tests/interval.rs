@@ -0,0 +1,91 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{Ps2Float, Ps2Interval};
+use rstest::*;
+
+#[rstest]
+#[case(0x3F800000, 0x3DCCCCCD)] // 1.00 - 0.1
+#[case(0x40400000, 0x3F800000)] // 3.00 - 1.00
+#[case(0x3F800000, 0x40400000)] // 1.00 - 3.00
+#[case(0x4B000001, 0x3F7FFFFF)] // 2^23 + 1 - 0.99999994
+fn ps2interval_sub_bounds_ps2_and_ieee(#[case] a: u32, #[case] b: u32) {
+    let interval = Ps2Interval::point(Ps2Float::new(a)).sub(&Ps2Float::new(b).into());
+
+    let ps2 = Ps2Float::new(a).sub(Ps2Float::new(b));
+    let ieee = f32::from_bits(a) - f32::from_bits(b);
+
+    assert!(interval.lo() <= ps2 && ps2 <= interval.hi());
+    assert!(interval.contains_f32(ieee), "{} doesn't contain {}", interval, ieee);
+}
+
+#[test]
+fn ps2interval_accumulated_sum_bounds_ieee() {
+    let tenth = Ps2Float::new(0x3DCCCCCD); // 0.1
+
+    let mut interval = Ps2Interval::point(Ps2Float::new(0));
+    let mut ps2 = Ps2Float::new(0);
+    let mut ieee = 0f32;
+    for _ in 0..1000 {
+        interval = interval.add(&tenth.into());
+        ps2 = ps2.add(tenth);
+        ieee += f32::from_bits(tenth.as_u32());
+    }
+
+    assert_ne!(ps2.as_u32(), ieee.to_bits());
+    assert!(interval.lo() <= ps2 && ps2 <= interval.hi());
+    assert!(interval.contains_f32(ieee));
+    assert!(interval.contains(100.0));
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x3F800000, 0x40000000)] // Already ordered
+#[case(0x40000000, 0x3F800000, 0x3F800000, 0x40000000)] // Reversed
+#[case(0x3F800000, 0xBF800000, 0xBF800000, 0x3F800000)] // Mixed signs
+fn ps2interval_new_orders_bounds(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] expected_lo: u32,
+    #[case] expected_hi: u32,
+) {
+    let interval = Ps2Interval::new(Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(interval.lo().as_u32(), expected_lo);
+    assert_eq!(interval.hi().as_u32(), expected_hi);
+}
+
+#[test]
+fn ps2interval_add_widens_outward() {
+    let one = Ps2Interval::point(Ps2Float::new(0x3F800000));
+
+    let sum = one.add(&one);
+
+    assert_eq!(sum.lo().as_u32(), 0x3FFFFFFD);
+    assert_eq!(sum.hi().as_u32(), 0x40000003);
+    assert_eq!(sum.width_ulps(), 6);
+}
+
+#[test]
+fn ps2interval_saturates_at_fmax() {
+    let max = Ps2Interval::point(Ps2Float::max());
+
+    let sum = max.add(&max);
+
+    assert_eq!(sum.hi(), Ps2Float::max());
+    assert!(!sum.contains_f32(f32::INFINITY));
+}
+
+#[test]
+fn ps2interval_div_by_interval_containing_zero() {
+    let one = Ps2Interval::point(Ps2Float::new(0x3F800000));
+    let around_zero = Ps2Interval::new(Ps2Float::new(0xBF800000), Ps2Float::new(0x3F800000));
+
+    let quotient = one.div(&around_zero);
+
+    assert_eq!(quotient, Ps2Interval::new(Ps2Float::min(), Ps2Float::max()));
+}
+
+#[test]
+fn ps2interval_display() {
+    let interval = Ps2Interval::new(Ps2Float::new(0x40000000), Ps2Float::new(0xBF800000));
+
+    assert_eq!(format!("{}", interval), "[-1.00, 2.00]");
+}
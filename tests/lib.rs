@@ -1,6 +1,9 @@
 use pretty_assertions::assert_eq;
+use ps2_floating_point::{
+    assert_ps2_ulp_eq, classify_pair, ps2_expr, Abnormal, Classification, Operation, PairClass,
+    Ps2Float, Ps2FloatError,
+};
 use rstest::*;
-use ps2_floating_point::Ps2Float;
 
 #[rstest]
 #[case(0x40A9999A)] // 5.3
@@ -33,6 +36,23 @@ fn ps2float_from_params(
     assert_eq!(ps2float.as_u32(), expected);
 }
 
+#[rstest]
+#[case(0x40A9999A, false, 0x81, 0x29999A)] // 5.3
+#[case(0x80000000, true, 0, 0)] // -0.00
+#[case(0xFFFFFFFF, true, 0xFF, 0x7FFFFF)] // -MAX
+fn ps2float_fields(
+    #[case] value: u32,
+    #[case] sign: bool,
+    #[case] exponent: u8,
+    #[case] mantissa: u32,
+) {
+    let ps2float = Ps2Float::new(value);
+
+    assert_eq!(ps2float.sign(), sign);
+    assert_eq!(ps2float.exponent(), exponent);
+    assert_eq!(ps2float.mantissa(), mantissa);
+}
+
 // Adapted from unknownbrackets/ps2autotests FPU arithmetic test: https://bit.ly/3sdgA6g
 #[rstest]
 #[case(0x00000000, 0x00000000, 0x00000000)] // 0.00 + 0.00 = 0.00
@@ -54,11 +74,19 @@ fn ps2float_from_params(
 #[case(0x00000000, 0x7F800000, 0x7F800000)] // 0.00 + INF = INF
 #[case(0x7F800000, 0x7F800000, 0x7FFFFFFF)] // INF + INF = MAX
 #[case(0xFF800000, 0x7F800000, 0x00000000)] // -INF + INF = 0.00
+#[case(0x40400000, 0xBF800000, 0x40000000)] // 3.00 + -1.00 = 2.00
+#[case(0xBF800000, 0x40400000, 0x40000000)] // -1.00 + 3.00 = 2.00
+#[case(0x3F800000, 0xC0400000, 0xC0000000)] // 1.00 + -3.00 = -2.00
+#[case(0x3F800000, 0xBF800000, 0x00000000)] // 1.00 + -1.00 = 0.00
+#[case(0x7FFFFFFF, 0x7F800000, 0x7FFFFFFF)] // MAX + INF = MAX
+#[case(0x7F800000, 0xFF800000, 0x00000000)] // INF + -INF = 0.00
+#[case(0x7F800000, 0x3F800000, 0x7F800000)] // INF + 1.00 = INF
+#[case(0x3F800000, 0x0B800000, 0x3F800000)] // 1.00 + 2^-104 = 1.00
 fn ps2float_add(#[case] a_addend: u32, #[case] b_addend: u32, #[case] expected: u32) {
     let a = Ps2Float::new(a_addend);
     let b = Ps2Float::new(b_addend);
 
-    let result = a.add(&b);
+    let result = a.add(b);
 
     assert_eq!(
         result.as_u32(),
@@ -91,11 +119,18 @@ fn ps2float_add(#[case] a_addend: u32, #[case] b_addend: u32, #[case] expected:
 #[case(0x00000000, 0x7F800000, 0xFF800000)] // 0.00 - INF = -INF
 #[case(0x7F800000, 0x7F800000, 0x00000000)] // INF - INF = 0.00
 #[case(0xFF800000, 0x7F800000, 0xFFFFFFFF)] // -INF - INF = -MAX
+#[case(0x3F800000, 0x40400000, 0xC0000000)] // 1.00 - 3.00 = -2.00
+#[case(0x40400000, 0xBF800000, 0x40800000)] // 3.00 - -1.00 = 4.00
+#[case(0xBF800000, 0x40400000, 0xC0800000)] // -1.00 - 3.00 = -4.00
+#[case(0xBF800000, 0xC0400000, 0x40000000)] // -1.00 - -3.00 = 2.00
+#[case(0x7FFFFFFF, 0x7F800000, 0x7F7FFFFE)] // MAX - INF = 0x7F7FFFFE
+#[case(0x00800001, 0x00800000, 0x00000000)] // 2^-149 flushes to 0.00
+#[case(0x80800001, 0x80800000, 0x80000000)] // -2^-149 flushes to -0.00
 fn ps2float_sub(#[case] a_subtrahend: u32, #[case] b_subtrahend: u32, #[case] expected: u32) {
     let a = Ps2Float::new(a_subtrahend);
     let b = Ps2Float::new(b_subtrahend);
 
-    let result = a.sub(&b);
+    let result = a.sub(b);
 
     assert_eq!(
         result.as_u32(),
@@ -106,3 +141,532 @@ fn ps2float_sub(#[case] a_subtrahend: u32, #[case] b_subtrahend: u32, #[case] ex
         expected
     );
 }
+
+#[rstest]
+#[case(0x3F800000, 0x3F800000, 0x3F800000)] // 1.00 * 1.00 = 1.00
+#[case(0x40000000, 0x40400000, 0x40C00000)] // 2.00 * 3.00 = 6.00
+#[case(0xC0000000, 0x40400000, 0xC0C00000)] // -2.00 * 3.00 = -6.00
+#[case(0xC0000000, 0xC0400000, 0x40C00000)] // -2.00 * -3.00 = 6.00
+#[case(0x3FC00000, 0x3FC00000, 0x40100000)] // 1.50 * 1.50 = 2.25
+#[case(0x00000000, 0xBF800000, 0x80000000)] // 0.00 * -1.00 = -0.00
+#[case(0x80000000, 0x80000000, 0x00000000)] // -0.00 * -0.00 = 0.00
+#[case(0x00000001, 0x3F800000, 0x00000000)] // Denormalized * 1.00 = 0.00
+#[case(0x80000001, 0x3F800000, 0x80000000)] // -Denormalized * 1.00 = -0.00
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF)] // MAX * MAX = MAX
+#[case(0x7FFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF)] // MAX * -MAX = -MAX
+#[case(0x7F800000, 0x7F800000, 0x7FFFFFFF)] // INF * INF = MAX
+#[case(0x7FFFFFFF, 0x00000000, 0x00000000)] // MAX * 0.00 = 0.00
+#[case(0x7FFFFFFF, 0x3F800000, 0x7FFFFFFF)] // MAX * 1.00 = MAX
+#[case(0x7F800000, 0x3F800000, 0x7F800000)] // INF * 1.00 = INF
+#[case(0x7F000000, 0x40000000, 0x7F800000)] // 2^127 * 2.00 = INF pattern
+#[case(0x7F800000, 0xC0000000, 0xFFFFFFFF)] // INF * -2.00 = -MAX
+#[case(0x00800000, 0x3F000000, 0x00000000)] // Underflow flushes to 0.00
+#[case(0x80800000, 0x3F000000, 0x80000000)] // Underflow keeps the sign
+#[case(0x3F800001, 0x3F800001, 0x3F800002)] // Truncated
+#[case(0x3FD093DB, 0x3FD2B77E, 0x402BAEC9)] // Booth multiplier, one ULP below truncation
+#[case(0x3F9C7A57, 0x3F9ED3CA, 0x3FC229EB)] // Booth multiplier, one ULP below truncation
+fn ps2float_mul(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).mul(Ps2Float::new(b));
+
+    assert_eq!(result.as_u32(), expected, "Testing multiplying floats {:08X} and {:08X}", a, b);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40400000, 0x3EAAAAAA)] // 1.00 / 3.00 = 0.33, truncated
+#[case(0x40C00000, 0x40400000, 0x40000000)] // 6.00 / 3.00 = 2.00
+#[case(0xC0C00000, 0x40400000, 0xC0000000)] // -6.00 / 3.00 = -2.00
+#[case(0x3F800000, 0x40000000, 0x3F000000)] // 1.00 / 2.00 = 0.50
+#[case(0x3F800000, 0x3F800001, 0x3F7FFFFE)] // Truncated
+#[case(0x3F800000, 0x00000000, 0x7FFFFFFF)] // 1.00 / 0.00 = MAX
+#[case(0xBF800000, 0x00000000, 0xFFFFFFFF)] // -1.00 / 0.00 = -MAX
+#[case(0x3F800000, 0x80000000, 0xFFFFFFFF)] // 1.00 / -0.00 = -MAX
+#[case(0x00000000, 0x00000000, 0x7FFFFFFF)] // 0.00 / 0.00 = MAX
+#[case(0x80000000, 0x00000000, 0xFFFFFFFF)] // -0.00 / 0.00 = -MAX
+#[case(0x3F800000, 0x00000001, 0x7FFFFFFF)] // 1.00 / Denormalized = MAX
+#[case(0x00000000, 0x3F800000, 0x00000000)] // 0.00 / 1.00 = 0.00
+#[case(0x00000000, 0xBF800000, 0x80000000)] // 0.00 / -1.00 = -0.00
+#[case(0x80000001, 0x3F800000, 0x80000000)] // -Denormalized / 1.00 = -0.00
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x3F800000)] // MAX / MAX = 1.00
+#[case(0x7FFFFFFF, 0x3F000000, 0x7FFFFFFF)] // MAX / 0.50 = MAX
+#[case(0x7F800000, 0x3F7FFFFF, 0x7F800000)] // INF / 0.99 = INF
+#[case(0x00800000, 0x40000000, 0x00000000)] // Underflow flushes to 0.00
+#[case(0x80800000, 0x3F800001, 0x80000000)] // Underflow keeps the sign
+fn ps2float_div(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).div(Ps2Float::new(b));
+
+    assert_eq!(result.as_u32(), expected, "Testing dividing floats {:08X} and {:08X}", a, b);
+}
+
+#[test]
+fn ps2float_div_matches_from_ratio() {
+    for (numerator, denominator) in [(1, 3), (2, 3), (1, 60), (-7, 9), (1000, 7)] {
+        let (a, b) = (Ps2Float::from_ratio(numerator, 1), Ps2Float::from_ratio(denominator, 1));
+
+        assert_eq!(a.div(b), Ps2Float::from_ratio(numerator, denominator));
+    }
+}
+
+#[rstest]
+#[case(0x40800000, 0x40000000)] // sqrt(4.00) = 2.00
+#[case(0x3F800000, 0x3F800000)] // sqrt(1.00) = 1.00
+#[case(0x3E800000, 0x3F000000)] // sqrt(0.25) = 0.50
+#[case(0x40000000, 0x3FB504F3)] // sqrt(2.00) = 1.41
+#[case(0x40A00000, 0x400F1BBC)] // sqrt(5.00) = 2.24, truncated
+#[case(0xC0800000, 0x40000000)] // sqrt(-4.00) = 2.00
+#[case(0x00000000, 0x00000000)] // sqrt(0.00) = 0.00
+#[case(0x80000000, 0x80000000)] // sqrt(-0.00) = -0.00
+#[case(0x00000001, 0x00000000)] // sqrt(Denormalized) = 0.00
+#[case(0x80000001, 0x80000000)] // sqrt(-Denormalized) = -0.00
+#[case(0x00800000, 0x20000000)] // Smallest normalized
+#[case(0x7F800000, 0x5F800000)] // sqrt(INF) = 2^64
+#[case(0x7FFFFFFF, 0x5FB504F2)] // sqrt(MAX)
+#[case(0xFFFFFFFF, 0x5FB504F2)] // sqrt(-MAX)
+fn ps2float_sqrt(#[case] value: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(value).sqrt();
+
+    assert_eq!(result.as_u32(), expected, "Testing the square root of {:08X}", value);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40800000, 0x3F000000)] // 1.00 / sqrt(4.00) = 0.50
+#[case(0x3F800000, 0x40000000, 0x3F3504F3)] // 1.00 / sqrt(2.00) = 0.71
+#[case(0x40400000, 0x40A00000, 0x3FABBAE3)] // 3.00 / sqrt(5.00) = 1.34, truncated twice
+#[case(0x40400000, 0x41100000, 0x3F800000)] // 3.00 / sqrt(9.00) = 1.00
+#[case(0x3F800000, 0xC0800000, 0x3F000000)] // 1.00 / sqrt(-4.00) = 0.50
+#[case(0xBF800000, 0x40800000, 0xBF000000)] // -1.00 / sqrt(4.00) = -0.50
+#[case(0x3F800000, 0x00000000, 0x7FFFFFFF)] // 1.00 / sqrt(0.00) = MAX
+#[case(0x3F800000, 0x80000000, 0x7FFFFFFF)] // 1.00 / sqrt(-0.00) = MAX
+#[case(0xBF800000, 0x00000000, 0xFFFFFFFF)] // -1.00 / sqrt(0.00) = -MAX
+#[case(0x00000000, 0x00000000, 0x7FFFFFFF)] // 0.00 / sqrt(0.00) = MAX
+#[case(0x3F800000, 0x80000001, 0x7FFFFFFF)] // 1.00 / sqrt(-Denormalized) = MAX
+#[case(0x00000000, 0x40800000, 0x00000000)] // 0.00 / sqrt(4.00) = 0.00
+#[case(0x7FFFFFFF, 0x3E800000, 0x7FFFFFFF)] // MAX / sqrt(0.25) = MAX
+fn ps2float_rsqrt(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).rsqrt(Ps2Float::new(b));
+
+    assert_eq!(result.as_u32(), expected, "Testing {:08X} / sqrt({:08X})", a, b);
+}
+
+#[rstest]
+#[case(0x40400000, 1, 0x40C00000)] // 3.00 * 2^1 = 6.00
+#[case(0x40400000, -2, 0x3F400000)] // 3.00 * 2^-2 = 0.75
+#[case(0xC0400000, 0, 0xC0400000)] // -3.00 * 2^0 = -3.00
+#[case(0x3F800001, 127, 0x7F000001)] // 1.00 * 2^127, mantissa kept
+#[case(0x3F800000, 128, 0x7F800000)] // 1.00 * 2^128 = Inf pattern, still in range
+#[case(0x3F800000, 129, 0x7FFFFFFF)] // 1.00 * 2^129 = MAX
+#[case(0xBF800000, i32::MAX, 0xFFFFFFFF)] // -1.00 * 2^MAX = -MAX
+#[case(0x3F800000, -126, 0x00800000)] // 1.00 * 2^-126, smallest normalized
+#[case(0x3F800000, -127, 0x00000000)] // 1.00 * 2^-127 = 0.00
+#[case(0xBF800000, i32::MIN, 0x80000000)] // -1.00 * 2^MIN = -0.00
+#[case(0x80000001, 10, 0x80000000)] // -Denormalized * 2^10 = -0.00
+#[case(0x7FFFFFFF, -1, 0x7F7FFFFF)] // MAX * 2^-1
+fn ps2float_scale_by_pow2(#[case] a: u32, #[case] n: i32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).scale_by_pow2(n);
+
+    assert_eq!(result.as_u32(), expected, "Testing {:08X} * 2^{}", a, n);
+}
+
+#[rstest]
+#[case(0x40000000, 0x40400000, 0x3F800000, 0x40E00000)] // 1.00 + 2.00 * 3.00 = 7.00
+#[case(0x40000000, 0x40400000, 0xBF800000, 0x40A00000)] // -1.00 + 2.00 * 3.00 = 5.00
+#[case(0x3F800001, 0x3F800001, 0xBF800000, 0x34800000)] // -1.00 + 1.00^2, product truncated first
+#[case(0x00000001, 0x40000000, 0x3F800000, 0x3F800000)] // 1.00 + Denormalized * 2.00 = 1.00
+#[case(0x7FFFFFFF, 0x40000000, 0x00000000, 0x7FFFFFFF)] // 0.00 + MAX * 2.00 = MAX
+#[case(0x7FFFFFFF, 0x40000000, 0x7FFFFFFF, 0x7FFFFFFF)] // MAX + MAX * 2.00 = MAX
+fn ps2float_madd(#[case] a: u32, #[case] b: u32, #[case] acc: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).madd(Ps2Float::new(b), Ps2Float::new(acc));
+
+    assert_eq!(result.as_u32(), expected, "Testing {:08X} + {:08X} * {:08X}", acc, a, b);
+}
+
+#[rstest]
+#[case(0x40000000, 0x40400000, 0x3F800000, 0xC0A00000)] // 1.00 - 2.00 * 3.00 = -5.00
+#[case(0x40000000, 0x40400000, 0x40C00000, 0x00000000)] // 6.00 - 2.00 * 3.00 = 0.00
+#[case(0x3F800001, 0x3F800001, 0x3F800000, 0xB4800000)] // 1.00 - 1.00^2, product truncated first
+#[case(0x7FFFFFFF, 0x40000000, 0x00000000, 0xFFFFFFFF)] // 0.00 - MAX * 2.00 = -MAX
+#[case(0x3F800000, 0x00000001, 0x40000000, 0x40000000)] // 2.00 - 1.00 * Denormalized = 2.00
+fn ps2float_msub(#[case] a: u32, #[case] b: u32, #[case] acc: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).msub(Ps2Float::new(b), Ps2Float::new(acc));
+
+    assert_eq!(result.as_u32(), expected, "Testing {:08X} - {:08X} * {:08X}", acc, a, b);
+}
+
+#[rstest]
+#[case(0xBF800000, 0x3F800000, 0x3F800000)] // -1.00
+#[case(0x3F800000, 0x3F800000, 0xBF800000)] // 1.00
+#[case(0x00000000, 0x00000000, 0x80000000)] // 0.00
+#[case(0x80000000, 0x00000000, 0x00000000)] // -0.00
+#[case(0x80000001, 0x00000001, 0x00000001)] // -Denormalized
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0xFFFFFFFF)] // MAX
+#[case(0xFFFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF)] // -MAX
+#[case(0xFF800000, 0x7F800000, 0x7F800000)] // -Inf
+#[case(0x7FC00000, 0x7FC00000, 0xFFC00000)] // NaN
+fn ps2float_abs_and_neg(#[case] a: u32, #[case] abs: u32, #[case] neg: u32) {
+    let value = Ps2Float::new(a);
+
+    assert_eq!(value.abs().as_u32(), abs, "Testing abs({:08X})", a);
+    assert_eq!(value.neg().as_u32(), neg, "Testing neg({:08X})", a);
+    assert_eq!((-value).as_u32(), neg, "Testing -{:08X}", a);
+    assert_eq!(value.neg().neg(), value, "Testing neg(neg({:08X}))", a);
+}
+
+#[rstest]
+#[case(0x40000000, 0x80000000, 0xC0000000)] // 2.00, -0.00
+#[case(0xC0000000, 0x3F800000, 0x40000000)] // -2.00, 1.00
+#[case(0xC0000000, 0xBF800000, 0xC0000000)] // -2.00, -1.00
+#[case(0x00000000, 0xFFFFFFFF, 0x80000000)] // 0.00, -MAX
+#[case(0x7FFFFFFF, 0x80000001, 0xFFFFFFFF)] // MAX, -Denormalized
+#[case(0x80000001, 0x00000000, 0x00000001)] // -Denormalized, 0.00
+fn ps2float_copysign(#[case] a: u32, #[case] sign: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).copysign(Ps2Float::new(sign));
+
+    assert_eq!(result.as_u32(), expected, "Testing copysign({:08X}, {:08X})", a, sign);
+}
+
+#[rstest]
+#[case(0x42C80000, 0x3F800000)] // 100.00
+#[case(0xBE800000, 0xBF800000)] // -0.25
+#[case(0x00000000, 0x3F800000)] // 0.00
+#[case(0x80000000, 0xBF800000)] // -0.00
+#[case(0x80000001, 0xBF800000)] // -Denormalized
+#[case(0x7FFFFFFF, 0x3F800000)] // MAX
+#[case(0xFFFFFFFF, 0xBF800000)] // -MAX
+fn ps2float_signum(#[case] a: u32, #[case] expected: u32) {
+    let result = Ps2Float::new(a).signum();
+
+    assert_eq!(result.as_u32(), expected, "Testing signum({:08X})", a);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x40000000, 0x3F800000)] // 1.00, 2.00
+#[case(0xBF800000, 0xC0000000, 0xBF800000, 0xC0000000)] // -1.00, -2.00
+#[case(0xBF800000, 0x3F800000, 0x3F800000, 0xBF800000)] // -1.00, 1.00
+#[case(0x00000000, 0x80000000, 0x00000000, 0x80000000)] // 0.00, -0.00
+#[case(0x80000000, 0x00000000, 0x00000000, 0x80000000)] // -0.00, 0.00
+#[case(0x00000001, 0x00000000, 0x00000001, 0x00000000)] // Denormalized, 0.00
+#[case(0x7FFFFFFF, 0x7F800000, 0x7FFFFFFF, 0x7F800000)] // MAX, Inf
+#[case(0x7FC00000, 0x3F800000, 0x7FC00000, 0x3F800000)] // NaN, 1.00
+#[case(0xFFC00000, 0xFFFFFFFF, 0xFFC00000, 0xFFFFFFFF)] // -NaN, -MAX
+fn ps2float_max_of_and_min_of(#[case] a: u32, #[case] b: u32, #[case] max: u32, #[case] min: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.max_of(b).as_u32(), max, "Testing max({}, {})", a, b);
+    assert_eq!(b.max_of(a).as_u32(), max, "Testing max({}, {})", b, a);
+    assert_eq!(a.min_of(b).as_u32(), min, "Testing min({}, {})", a, b);
+    assert_eq!(b.min_of(a).as_u32(), min, "Testing min({}, {})", b, a);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x3DCCCCCD, 0x3F8CCCCC, 0x3F666668)] // 1.00 and 0.10
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF, 0x00000000)] // MAX and MAX
+#[case(0xFF800000, 0x7F800000, 0x00000000, 0xFFFFFFFF)] // -INF and INF
+fn ps2float_operators(#[case] a: u32, #[case] b: u32, #[case] sum: u32, #[case] difference: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+    let (a_ref, b_ref) = (&a, &b);
+
+    assert_eq!((a + b).as_u32(), sum);
+    assert_eq!((a_ref + b).as_u32(), sum);
+    assert_eq!((a + b_ref).as_u32(), sum);
+    assert_eq!((a_ref + b_ref).as_u32(), sum);
+    assert_eq!((a - b).as_u32(), difference);
+    assert_eq!((a_ref - b_ref).as_u32(), difference);
+
+    let mut accumulator = a;
+    accumulator += b;
+    assert_eq!(accumulator.as_u32(), sum);
+    accumulator = a;
+    accumulator -= b_ref;
+    assert_eq!(accumulator.as_u32(), difference);
+}
+
+#[rstest]
+#[case(0x40400000, 0x40000000, 0x40C00000, 0x3FC00000)] // 3.00 and 2.00
+#[case(0x3F800000, 0xC0400000, 0xC0400000, 0xBEAAAAAA)] // 1.00 and -3.00
+#[case(0x7FFFFFFF, 0x00000000, 0x00000000, 0x7FFFFFFF)] // MAX and 0.00
+fn ps2float_mul_div_neg_operators(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] product: u32,
+    #[case] quotient: u32,
+) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+    let (a_ref, b_ref) = (&a, &b);
+
+    assert_eq!((a * b).as_u32(), product);
+    assert_eq!((a_ref * b).as_u32(), product);
+    assert_eq!((a * b_ref).as_u32(), product);
+    assert_eq!((a_ref * b_ref).as_u32(), product);
+    assert_eq!((a / b).as_u32(), quotient);
+    assert_eq!((a_ref / b_ref).as_u32(), quotient);
+    assert_eq!((-a).as_u32(), a.as_u32() ^ 0x80000000);
+    assert_eq!((-a_ref).as_u32(), a.as_u32() ^ 0x80000000);
+    assert_eq!((-(a * b)).as_u32(), product ^ 0x80000000);
+}
+
+#[rstest]
+#[case(0x40400000, 0x40000000, 0x40C00000, 0x3FC00000)] // 3.00 and 2.00
+#[case(0x3F800000, 0xC0400000, 0xC0400000, 0xBEAAAAAA)] // 1.00 and -3.00
+fn ps2float_mul_div_assign_operators(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] product: u32,
+    #[case] quotient: u32,
+) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    let mut accumulator = a;
+    accumulator *= b;
+    assert_eq!(accumulator.as_u32(), product);
+    accumulator = a;
+    accumulator *= &b;
+    assert_eq!(accumulator.as_u32(), product);
+    accumulator = a;
+    accumulator /= b;
+    assert_eq!(accumulator.as_u32(), quotient);
+    accumulator = a;
+    accumulator /= &b;
+    assert_eq!(accumulator.as_u32(), quotient);
+}
+
+#[test]
+fn ps2float_add_assign_loop() {
+    let step = Ps2Float::new(0x3C888889); // 1/60
+    let mut total = Ps2Float::new(0);
+
+    for _ in 0..60 {
+        total += step;
+    }
+
+    // Every step truncates, so a second of frames falls short of 1.0.
+    assert_eq!(total.as_u32(), 0x3F7FFFF4);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x3F800000, 0)] // 1.00 and 1.00
+#[case(0x3F800000, 0x3F800001, 1)] // 1.00 and 1.00 + 1 ULP
+#[case(0x3F800001, 0x3F800000, 1)] // 1.00 + 1 ULP and 1.00
+#[case(0x00000000, 0x80000000, 0)] // 0.00 and -0.00
+#[case(0x00000000, 0x00000001, 0)] // 0.00 and Denormalized
+#[case(0x00000000, 0x00800000, 1)] // 0.00 and Smallest normalized
+#[case(0x80800000, 0x00800000, 2)] // -Smallest normalized and Smallest normalized
+#[case(0x7FFFFFFE, 0x7FFFFFFF, 1)] // MAX - 1 ULP and MAX
+#[case(0xFFFFFFFF, 0x7FFFFFFF, 0xFF000000)] // -MAX and MAX
+fn ps2float_ulp_distance(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let a = Ps2Float::new(a);
+    let b = Ps2Float::new(b);
+
+    let result = a.ulp_distance(b);
+
+    assert_eq!(result, expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x3F800001)] // 1.00 toward 2.00
+#[case(0x3F800000, 0x00000000, 0x3F7FFFFF)] // 1.00 toward 0.00
+#[case(0xBF800000, 0x00000000, 0xBF7FFFFF)] // -1.00 toward 0.00
+#[case(0x00000000, 0x3F800000, 0x00800000)] // 0.00 toward 1.00, skipping denormalized
+#[case(0x80000000, 0xBF800000, 0x80800000)] // -0.00 toward -1.00
+#[case(0x00800000, 0x80000000, 0x00000000)] // Smallest normalized toward -0.00
+#[case(0x80800000, 0x3F800000, 0x00000000)] // -Smallest normalized toward 1.00
+#[case(0x00000001, 0x3F800000, 0x00800000)] // Denormalized toward 1.00
+#[case(0x7FFFFFFE, 0x7FFFFFFF, 0x7FFFFFFF)] // MAX - 1 ULP toward MAX
+#[case(0x3F800000, 0x3F800000, 0x3F800000)] // 1.00 toward 1.00
+#[case(0x00000000, 0x80000001, 0x80000001)] // 0.00 toward -Denormalized
+fn ps2float_next_toward(#[case] value: u32, #[case] target: u32, #[case] expected: u32) {
+    let value = Ps2Float::new(value);
+    let target = Ps2Float::new(target);
+
+    let result = value.next_toward(target);
+
+    assert_eq!(result.as_u32(), expected);
+}
+
+#[test]
+fn assert_ps2_ulp_eq_within_ulps() {
+    let a = Ps2Float::new(0x40A9999A); // 5.3
+    let b = Ps2Float::new(0x40A9999C); // 5.3 + 2 ULP
+
+    assert_ps2_ulp_eq!(a, b, 2);
+    assert_ps2_ulp_eq!(a, b, 3, "comparing {} and {}", a, b);
+}
+
+#[test]
+#[should_panic(expected = "left: 0x40A9999A (5.30)\n right: 0x40A9999C (5.30)\n  ulps: 2 (max 1)")]
+fn assert_ps2_ulp_eq_outside_ulps() {
+    let a = Ps2Float::new(0x40A9999A); // 5.3
+    let b = Ps2Float::new(0x40A9999C); // 5.3 + 2 ULP
+
+    assert_ps2_ulp_eq!(a, b, 1);
+}
+
+#[test]
+fn ps2_expr_evaluates_left_to_right() {
+    let one = Ps2Float::new(0x3F800000); // 1.00
+    let tiny = Ps2Float::new(0x33800000); // 2^-24, half an ULP of 1.00
+
+    assert_eq!(ps2_expr!(one + tiny + tiny).as_u32(), 0x3F800000);
+    assert_eq!(ps2_expr!(one + (tiny + tiny)).as_u32(), 0x3F800001);
+}
+
+#[rstest]
+#[case(ps2_expr!(1.5), 0x3FC00000)]
+#[case(ps2_expr!(-1.5), 0xBFC00000)]
+#[case(ps2_expr!(2 - 0.5), 0x3FC00000)]
+#[case(ps2_expr!(1 - -0.5), 0x3FC00000)]
+#[case(ps2_expr!(0.1), 0x3DCCCCCD)]
+#[case(ps2_expr!(Ps2Float::new(0x40000000) - (1.0 + 0.5)), 0x3F000000)]
+#[case(ps2_expr!((0.75 + 0.75).max(Ps2Float::max()) - 1), 0x7FFFFFFF)]
+fn ps2_expr_operands(#[case] result: Ps2Float, #[case] expected: u32) {
+    assert_eq!(result.as_u32(), expected);
+}
+
+#[rstest]
+#[case("add", Operation::Add)]
+#[case("SUB", Operation::Sub)]
+#[case("*", Operation::Mul)]
+#[case(" / ", Operation::Div)]
+fn operation_from_str(#[case] s: &str, #[case] expected: Operation) {
+    assert_eq!(s.parse::<Operation>(), Ok(expected));
+}
+
+#[rstest]
+#[case(1, 3, 0x3EAAAAAA)]
+#[case(2, 3, 0x3F2AAAAA)]
+#[case(-7, 10, 0xBF333333)]
+#[case(-5, 9, 0xBF0E38E3)]
+#[case(22, 7, 0x40492492)]
+#[case(100, 3, 0x42055555)]
+#[case(1, 1, 0x3F800000)]
+#[case(i32::MAX, 1, 0x4EFFFFFF)]
+#[case(i32::MIN, 1, 0xCF000000)]
+#[case(1, i32::MIN, 0xB0000000)]
+#[case(1, i32::MAX, 0x30000000)]
+#[case(0, -5, 0x80000000)]
+#[case(3, 0, 0x7FFFFFFF)]
+#[case(-3, 0, 0xFFFFFFFF)]
+fn ps2float_from_ratio(#[case] numerator: i32, #[case] denominator: i32, #[case] expected: u32) {
+    assert_eq!(Ps2Float::from_ratio(numerator, denominator).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x00000000, Classification::Zero)] // 0.00
+#[case(0x80000001, Classification::Denormalized)] // -Denormalized
+#[case(0x40A9999A, Classification::Normalized)] // 5.3
+#[case(0xFFFFFFFF, Classification::Fmax)] // -MAX
+#[case(0x7F800000, Classification::Infinity)] // INF
+#[case(0x7FC00000, Classification::Nan)] // NaN
+fn ps2float_classify(#[case] value: u32, #[case] expected: Classification) {
+    assert_eq!(Ps2Float::new(value).classify(), expected);
+}
+
+#[rstest]
+#[case(0x40A9999A, 0x3F800000, PairClass::Regular)] // 5.3, 1.0
+#[case(0x7FFFFFFF, 0x3F800000, PairClass::Regular)] // MAX, 1.0
+#[case(0x7FC00000, 0x7F800000, PairClass::Regular)] // NaN, INF
+#[case(0x00000000, 0x7FFFFFFF, PairClass::Denormalized)] // 0.00, MAX
+#[case(0x7F800000, 0x80000001, PairClass::Denormalized)] // INF, -Denormalized
+#[case(0x7FFFFFFF, 0xFFFFFFFF, PairClass::Abnormal(Abnormal::Max, Abnormal::Min))] // MAX, -MAX
+#[case(
+    0xFF800000,
+    0x7F800000,
+    PairClass::Abnormal(Abnormal::NegativeInfinity, Abnormal::PositiveInfinity)
+)] // -INF, INF
+fn ps2float_classify_pair(#[case] a: u32, #[case] b: u32, #[case] expected: PairClass) {
+    assert_eq!(classify_pair(Ps2Float::new(a), Ps2Float::new(b)), expected);
+}
+
+#[rstest]
+#[case(
+    0x40A9999A,
+    "0x40A9999A  5.300000190734863\n  sign: 0 (+)  exponent: 0x81 (2^2)  mantissa: 0x29999A  [normalized]"
+)] // 5.3
+#[case(
+    0xFFFFFFFF,
+    "0xFFFFFFFF  -6.805646932770577e38\n  sign: 1 (-)  exponent: 0xFF (2^128)  mantissa: 0x7FFFFF  [Fmax]"
+)] // -MAX
+fn ps2float_breakdown(#[case] value: u32, #[case] expected: &str) {
+    assert_eq!(Ps2Float::new(value).breakdown().to_string(), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, (false, 0, 0x800000))] // 1.0
+#[case(0xC0A00000, (true, 2, 0xA00000))] // -5.0
+#[case(0x00800000, (false, -126, 0x800000))] // Smallest normalized
+#[case(0x7FFFFFFF, (false, 128, 0xFFFFFF))] // MAX
+#[case(0x00000000, (false, -127, 0))] // 0.00
+#[case(0x80000001, (true, -127, 0))] // -Denormalized
+fn ps2float_to_parts(#[case] value: u32, #[case] expected: (bool, i16, u32)) {
+    let value = Ps2Float::new(value);
+    let (sign, exponent, significand) = expected;
+
+    assert_eq!(value.to_parts(), expected);
+    assert_eq!(value.unbiased_exponent(), exponent);
+    assert_eq!(value.significand(), significand);
+    assert_eq!(
+        Ps2Float::from_parts_unbiased(sign, exponent, significand).map(|float| float.as_u32()),
+        Some(value.as_u32() & if significand == 0 { 0x80000000 } else { 0xFFFFFFFF })
+    );
+}
+
+#[rstest]
+#[case(false, 23, 3, Some(0x40400000))] // 3.0
+#[case(false, 0, 0x1000000, Some(0x40000000))] // 2.0
+#[case(true, 0, 0x1000001, None)] // 25 significant bits
+#[case(false, 128, 0x1000000, None)] // Past MAX
+#[case(false, -126, 0x400000, None)] // Below the smallest normalized
+#[case(true, 1000, 0, Some(0x80000000))] // -0.00
+fn ps2float_from_parts_unbiased(
+    #[case] sign: bool,
+    #[case] exponent: i16,
+    #[case] significand: u32,
+    #[case] expected: Option<u32>,
+) {
+    assert_eq!(
+        Ps2Float::from_parts_unbiased(sign, exponent, significand).map(|float| float.as_u32()),
+        expected
+    );
+}
+
+#[rstest]
+#[case(0x7FFFFFFE, 0x7FFFFFFE, 0x7FFFFFFF, true)]
+#[case(0xFFFFFFFE, 0xFF7FFFFF, 0xFFFFFFFF, true)] // -MAX
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF, true)] // MAX + MAX, from the abnormal table
+#[case(0x7F800000, 0x7F800000, 0x7FFFFFFF, true)] // INF + INF
+#[case(0xFFFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF, false)] // -MAX + MAX
+#[case(0x7FFFFFFF, 0x3F800000, 0x7FFFFFFF, false)] // Truncated, not saturated
+#[case(0x7F7FFFFF, 0x7F7FFFFF, 0x7FFFFFFF, false)] // Exponent 255 is ordinary
+#[case(0x3F800000, 0x3F800000, 0x40000000, false)] // 1.0 + 1.0
+fn ps2float_overflowing_add(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] expected: u32,
+    #[case] overflow: bool,
+) {
+    let (result, overflowed) = Ps2Float::new(a).overflowing_add(Ps2Float::new(b));
+
+    assert_eq!((result.as_u32(), overflowed), (expected, overflow));
+    // The traced checked operation agrees.
+    let checked = Ps2Float::new(a).checked_add(Ps2Float::new(b));
+    assert_eq!(matches!(checked, Err(Ps2FloatError::Overflow { .. })), overflow);
+}
+
+#[rstest]
+#[case(0x7FFFFFFE, 0xFFFFFFFE, 0x7FFFFFFF, true)]
+#[case(0xFFFFFFFE, 0x7F7FFFFF, 0xFFFFFFFF, true)] // -MAX
+#[case(0x7FFFFFFF, 0x7FFFFFFE, 0x74000000, false)] // One ULP at exponent 255
+#[case(0x7FFFFFFF, 0xFFFFFFFF, 0x7FFFFFFF, true)] // MAX - -MAX
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x00000000, false)] // MAX - MAX
+#[case(0x00800001, 0x00800000, 0x00000000, false)] // Underflow isn't overflow
+fn ps2float_overflowing_sub(
+    #[case] a: u32,
+    #[case] b: u32,
+    #[case] expected: u32,
+    #[case] overflow: bool,
+) {
+    let (result, overflowed) = Ps2Float::new(a).overflowing_sub(Ps2Float::new(b));
+
+    assert_eq!((result.as_u32(), overflowed), (expected, overflow));
+    // The traced checked operation agrees.
+    let checked = Ps2Float::new(a).checked_sub(Ps2Float::new(b));
+    assert_eq!(matches!(checked, Err(Ps2FloatError::Overflow { .. })), overflow);
+}
@@ -0,0 +1,161 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::math::{atan2f, atanf, cosf, expf, logf, powf, sinf, sqrtf, Aabb};
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+#[rstest]
+#[case(0x00000000, 0x00000000)] // sin(0) = 0
+#[case(0x80000000, 0x80000000)] // sin(-0) = -0
+#[case(0x31800000, 0x31800000)] // sin(x) = x for |x| < 2^-27
+#[case(0x00400000, 0x00400000)] // Denormalized
+fn math_sinf_tiny(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(sinf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x3F576AA7)] // sin(1), IEEE 754 rounds to 0x3F576AA4
+#[case(0xBF800000, 0xBF576AA7)] // sin(-1)
+fn math_sinf_golden(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(sinf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x00000000)] // cos(0) = 1
+#[case(0x80000000)] // cos(-0) = 1
+#[case(0xB1800000)] // cos(x) = 1 for |x| < 2^-27
+fn math_cosf_tiny(#[case] x: u32) {
+    assert_eq!(cosf(Ps2Float::new(x)).as_u32(), 0x3F800000);
+}
+
+#[rstest]
+#[case(0x00000000, 0x00000000)] // atan(0) = 0
+#[case(0x30800000, 0x30800000)] // atan(x) = x for |x| < 2^-29
+#[case(0x53800000, 0x3FC90FDA)] // atan(2^40) truncates pi/2, IEEE 754 rounds to 0x3FC90FDB
+#[case(0xD3800000, 0xBFC90FDA)] // atan(-2^40)
+fn math_atanf_without_polynomial(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(atanf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x3F000000, 0x3EED6338)] // atan(0.5)
+#[case(0xBF000000, 0xBEED6338)] // atan(-0.5)
+fn math_atanf_golden(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(atanf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x00000000, 0x3F800000, 0x00000000)] // atan2(0, 1) = 0
+#[case(0x80000000, 0x40000000, 0x80000000)] // atan2(-0, 2) = -0
+#[case(0x00000000, 0xBF800000, 0x40490FDB)] // atan2(0, -1) = pi
+#[case(0x80000000, 0xBF800000, 0xC0490FDB)] // atan2(-0, -1) = -pi
+#[case(0x3F800000, 0x00000000, 0x3FC90FDB)] // atan2(1, 0) = pi/2
+#[case(0xBF800000, 0x80000000, 0xBFC90FDB)] // atan2(-1, -0) = -pi/2
+#[case(0x7F800000, 0x7F800000, 0x3F490FDB)] // atan2(inf, inf) = pi/4
+#[case(0xFF800000, 0xFF800000, 0xC016CBE4)] // atan2(-inf, -inf) = -3pi/4
+#[case(0x3F800000, 0xFF800000, 0x40490FDB)] // atan2(1, -inf) = pi
+#[case(0x30000000, 0x3F800000, 0x30000000)] // atan2(y, 1) = atan(y)
+fn math_atan2f_special_cases(#[case] y: u32, #[case] x: u32, #[case] expected: u32) {
+    assert_eq!(atan2f(Ps2Float::new(y), Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x00000000)] // sqrt(0) = 0
+#[case(0x80000000)] // sqrt(-0) = -0
+fn math_sqrtf_zero(#[case] x: u32) {
+    assert_eq!(sqrtf(Ps2Float::new(x)).as_u32(), x);
+}
+
+#[rstest]
+#[case(0x40000000, 0x3FB504F3)] // sqrt(2)
+#[case(0x40800000, 0x40000000)] // sqrt(4) = 2
+fn math_sqrtf_golden(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(sqrtf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x00000000, 0x3F800000)] // exp(0) = 1
+#[case(0x31000000, 0x3F800000)] // exp(x) = 1 + x truncates to 1 for |x| < 2^-28
+#[case(0x7F800000, 0x7F800000)] // exp(inf pattern) = inf pattern
+#[case(0xFF800000, 0x00000000)] // exp(-inf pattern) = 0
+fn math_expf_without_polynomial(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(expf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x402DF854)] // exp(1)
+#[case(0xBF800000, 0x3EBC5AB2)] // exp(-1)
+fn math_expf_golden(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(expf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[test]
+fn math_logf_one() {
+    assert_eq!(logf(Ps2Float::new(0x3F800000)).as_u32(), 0x00000000);
+}
+
+#[rstest]
+#[case(0x40000000, 0x3F317216)] // ln(2), IEEE 754 rounds to 0x3F317218
+#[case(0x3F000000, 0xBF317216)] // ln(0.5)
+fn math_logf_golden(#[case] x: u32, #[case] expected: u32) {
+    assert_eq!(logf(Ps2Float::new(x)).as_u32(), expected);
+}
+
+#[rstest]
+#[case(0x40A9999A, 0x00000000)] // 5.3^0 = 1
+#[case(0x00000000, 0x80000000)] // 0^-0 = 1
+#[case(0x3F800000, 0x7FFFFFFF)] // 1^MAX = 1
+#[case(0x3F800000, 0xC0A00000)] // 1^-5 = 1
+fn math_powf_without_eexp(#[case] x: u32, #[case] y: u32) {
+    assert_eq!(powf(Ps2Float::new(x), Ps2Float::new(y)).as_u32(), 0x3F800000);
+}
+
+#[rstest]
+#[case(0x40000000, 0x40400000, 0x41000000)] // 2^3 = 8
+#[case(0x41200000, 0x40000000, 0x42C7FFFC)] // 10^2, IEEE 754 gives 0x42C80000
+#[case(0x40400000, 0xC0000000, 0x3DE38E4B)] // 3^-2
+#[case(0x40000000, 0x43000000, 0x7F7FFF0E)] // 2^128 stays under Fmax
+#[case(0x40000000, 0x43480000, 0x7FFFFFFF)] // 2^200 saturates to Fmax
+#[case(0x40000000, 0xC2FE0000, 0x00000000)] // 2^-127 flushes to 0
+#[case(0x00000000, 0x40000000, 0x00000000)] // 0^2 = 0
+fn math_powf_golden(#[case] x: u32, #[case] y: u32, #[case] expected: u32) {
+    assert_eq!(powf(Ps2Float::new(x), Ps2Float::new(y)).as_u32(), expected);
+}
+
+#[rstest]
+#[case([0x3F800000, 0x3F800000, 0x3F800000], true)] // Corner
+#[case([0x00000000, 0x80000000, 0x3F000000], true)] // Inside
+#[case([0x3F800001, 0x00000000, 0x00000000], false)] // 1 ULP beyond
+#[case([0x00000000, 0x7FFFFFFF, 0x00000000], false)] // Fmax is an ordinary number
+fn math_aabb_contains_point(#[case] point: [u32; 3], #[case] expected: bool) {
+    let aabb = Aabb::new(
+        Ps2Vec4::from_bits([0xBF800000, 0xBF800000, 0xBF800000, 0]),
+        Ps2Vec4::from_bits([0x3F800000, 0x3F800000, 0x3F800000, 0]),
+    );
+    let point = Ps2Vec4::from_bits([point[0], point[1], point[2], 0]);
+
+    assert_eq!(aabb.contains_point(&point), expected);
+}
+
+#[rstest]
+#[case([0x3F800000, 0x00000000, 0x00000000], true)] // Touching faces
+#[case([0x3F800001, 0x00000000, 0x00000000], false)] // 1 ULP apart
+#[case([0xBF000000, 0xBF000000, 0xBF000000], true)] // Overlapping
+fn math_aabb_intersects_aabb(#[case] min: [u32; 3], #[case] expected: bool) {
+    let unit = |min: [u32; 3]| {
+        let min = Ps2Vec4::from_bits([min[0], min[1], min[2], 0]);
+        Aabb::new(min, min.add(&Ps2Vec4::from_bits([0x3F800000; 4])))
+    };
+
+    assert_eq!(unit([0, 0, 0]).intersects_aabb(&unit(min)), expected);
+}
+
+#[test]
+fn math_aabb_new_orders_corners() {
+    let aabb = Aabb::new(
+        Ps2Vec4::from_bits([0x3F800000, 0xBF800000, 0x3F800000, 0]),
+        Ps2Vec4::from_bits([0xBF800000, 0x3F800000, 0x00000000, 0]),
+    );
+
+    assert_eq!(aabb.min.to_bits(), [0xBF800000, 0xBF800000, 0x00000000, 0]);
+    assert_eq!(aabb.max.to_bits(), [0x3F800000, 0x3F800000, 0x3F800000, 0]);
+}
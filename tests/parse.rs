@@ -0,0 +1,38 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::{Notation, Ps2Float};
+use rstest::*;
+
+#[rstest]
+#[case("0x40A9999A", 0x40A9999A, Notation::HexBits)]
+#[case("0x7fffffff", 0x7FFFFFFF, Notation::HexBits)]
+#[case(" 5.3 ", 0x40A9999A, Notation::Decimal)]
+#[case("-1e-3", 0xBA83126F, Notation::Decimal)]
+#[case("inf", 0x7F800000, Notation::Decimal)] // The IEEE 754 infinity pattern
+#[case("5e38", 0x7FBC1440, Notation::Decimal)] // Past the IEEE 754 range
+#[case("1e39", 0x7FFFFFFF, Notation::Decimal)] // Past Fmax
+#[case("0x1.533334p+2", 0x40A9999A, Notation::HexFloat)]
+#[case("-0x1p-1", 0xBF000000, Notation::HexFloat)]
+#[case("0x1.fffffeP128", 0x7FFFFFFF, Notation::HexFloat)]
+#[case("0:0x81:0x29999A", 0x40A9999A, Notation::Fields)]
+#[case("(1, 127, 0)", 0xBF800000, Notation::Fields)]
+#[case("0 0xFF 0x7FFFFF", 0x7FFFFFFF, Notation::Fields)]
+fn parse_any(#[case] s: &str, #[case] bits: u32, #[case] notation: Notation) {
+    assert_eq!(Ps2Float::parse_any(s), Ok((Ps2Float::new(bits), notation)));
+}
+
+#[rstest]
+#[case("0x123456789", "invalid float '0x123456789': hex bits must have 1 to 8 digits")]
+#[case("-0x3F800000", "invalid float '-0x3F800000': hex bits can't have a sign")]
+#[case("0:256:0", "invalid float '0:256:0': field out of range")]
+#[case("0x1.gp1", "invalid float '0x1.gp1': invalid hex digit")]
+#[case("0x2234567890ABCDp0", "invalid float '0x2234567890ABCDp0': too many significant hex digits")]
+#[case("five", "invalid float 'five': not a float in any known notation")]
+fn parse_any_errors(#[case] s: &str, #[case] message: &str) {
+    assert_eq!(Ps2Float::parse_any(s).unwrap_err().to_string(), message);
+}
+
+#[test]
+fn parse_from_str() {
+    assert_eq!("5.3".parse::<Ps2Float>(), Ok(Ps2Float::new(0x40A9999A)));
+    assert!("".parse::<Ps2Float>().is_err());
+}
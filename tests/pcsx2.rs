@@ -0,0 +1,39 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::pcsx2::PS2Float;
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(0x3F800000, 0x3F800000, 0x40000000)] // 1.00 + 1.00 = 2.00
+#[case(0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF)] // Fmax + Fmax = Fmax
+#[case(0xBF800000, 0x3F800000, 0x00000000)] // -1.00 + 1.00 = 0
+fn pcsx2_add(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let result = PS2Float::new(a).Add(PS2Float::new(b));
+
+    assert_eq!(result.AsUInt32(), expected);
+    assert_eq!(Ps2Float::from(result), Ps2Float::new(a).add(Ps2Float::new(b)));
+}
+
+#[test]
+fn pcsx2_sub() {
+    assert_eq!(PS2Float::One().Sub(PS2Float::new(0x40400000)).AsUInt32(), 0xC0000000);
+}
+
+#[test]
+fn pcsx2_helpers() {
+    let min_one = PS2Float::MinOne();
+
+    assert!(min_one.Sign);
+    assert_eq!(min_one.Exponent, 127);
+    assert_eq!(min_one.Mantissa, 0);
+    assert_eq!(min_one.Abs(), PS2Float::ONE);
+    assert_eq!(min_one.Negate(), PS2Float::One());
+    assert_eq!(min_one.CompareTo(PS2Float::One()), -1);
+    assert_eq!(min_one.CompareOperands(PS2Float::One()), 0);
+    assert_eq!(min_one.ToDouble(), -1.0);
+    assert_eq!(min_one.ToString(), "-1.00");
+    assert_eq!(PS2Float::Max().AsUInt32(), 0x7FFFFFFF);
+    assert_eq!(PS2Float::Min().AsUInt32(), 0xFFFFFFFF);
+    assert!(PS2Float::new(0x00000001).IsDenormalized());
+    assert!(PS2Float::new(0x80000000).IsZero());
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "psp")]
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::psp::PspFloat;
+use ps2_floating_point::FloatOps;
+use rstest::*;
+
+#[rstest]
+#[case(0x3F800000, 0x40000000, 0x40400000)] // 1 + 2 = 3
+#[case(0x00400000, 0x3F800000, 0x3F800000)] // Denormalized + 1 = 1
+#[case(0x00800001, 0x80800000, 0x00000000)] // Denormalized difference flushed
+#[case(0x7F800000, 0xFF800000, 0xFFC00000)] // INF - INF = NaN
+#[case(0x7F7FFFFF, 0x7F7FFFFF, 0x7F800000)] // Overflow to INF
+fn psp_float_add(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    let result = PspFloat::new(a) + PspFloat::new(b);
+
+    // The sign of the default NaN depends on the host.
+    let mask = if expected & 0x7FFFFFFF > 0x7F800000 { 0x7FFFFFFF } else { 0xFFFFFFFF };
+    assert_eq!(result.as_u32() & mask, expected & mask);
+}
+
+#[rstest]
+#[case(0x00800000, 0x3F000000, 0x00000000)] // Denormalized product flushed
+#[case(0x80800000, 0x3F000000, 0x80000000)] // Keeping its sign
+#[case(0x40400000, 0x40000000, 0x40C00000)] // 3 * 2 = 6
+fn psp_float_mul(#[case] a: u32, #[case] b: u32, #[case] expected: u32) {
+    assert_eq!((PspFloat::new(a) * PspFloat::new(b)).as_u32(), expected);
+}
+
+#[test]
+fn psp_float_div_and_sqrt() {
+    assert_eq!((PspFloat::new(0x3F800000) / PspFloat::new(0x40400000)).as_u32(), 0x3EAAAAAB);
+    assert_eq!((PspFloat::new(0x3F800000) / PspFloat::new(0)).as_u32(), 0x7F800000);
+    assert_eq!(PspFloat::new(0x40800000).sqrt().as_u32(), 0x40000000);
+    assert_eq!(PspFloat::new(0x80000001).sqrt().as_u32(), 0x80000000);
+}
+
+#[test]
+fn psp_float_with_generic_code() {
+    fn halve_until_zero<T: FloatOps>(mut value: T) -> u32 {
+        let half = T::from_bits(0x3F000000);
+        let mut steps = 0;
+        while value.to_bits() != 0 {
+            value = value.mul(half);
+            steps += 1;
+        }
+        steps
+    }
+
+    // The PSP flushes below the smallest normalized float while f32 keeps
+    // halving through the denormals.
+    assert_eq!(halve_until_zero(PspFloat::ONE), 127);
+    assert_eq!(halve_until_zero(1.0f32), 150);
+}
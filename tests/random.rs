@@ -0,0 +1,88 @@
+#![cfg(feature = "rand")]
+
+use ps2_floating_point::random::UnitInterval;
+use ps2_floating_point::Ps2Float;
+use rand::distributions::Uniform;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::*;
+
+#[test]
+fn ps2float_sample_standard() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let samples: Vec<Ps2Float> = (0..1000).map(|_| rng.gen()).collect();
+
+    assert!(samples.iter().any(|f| f.as_u32() >> 31 == 1));
+    assert!(samples.iter().any(|f| f.as_u32() >> 31 == 0));
+}
+
+#[test]
+fn ps2float_sample_unit_interval() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let zero = Ps2Float::new(0x00000000);
+    let one = Ps2Float::new(0x3F800000);
+
+    for value in (&mut rng).sample_iter(UnitInterval).take(1000) {
+        assert!(value >= zero && value < one, "{} is not in [0, 1)", value);
+    }
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40000000)] // [1.00, 2.00]
+#[case(0xBF800000, 0x3F800000)] // [-1.00, 1.00]
+#[case(0x7F7FFFFF, 0x7FFFFFFF)] // [Biggest IEEE 754 float, MAX]
+#[case(0x00000000, 0x00800001)] // [0.00, One ULP above smallest normalized]
+fn ps2float_sample_uniform(#[case] low: u32, #[case] high: u32) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let low = Ps2Float::new(low);
+    let high = Ps2Float::new(high);
+
+    for value in (&mut rng).sample_iter(Uniform::new_inclusive(low, high)).take(1000) {
+        assert!(value >= low && value <= high, "{} is not in [{}, {}]", value, low, high);
+        assert!(value.as_u32() & 0x7FFFFFFF == 0 || value.as_u32() & 0x7F800000 != 0);
+    }
+}
+
+#[test]
+fn ps2float_sample_uniform_hits_every_value() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let low = Ps2Float::new(0x3F800000); // 1.00
+    let high = Ps2Float::new(0x3F800003); // 1.00 + 3 ULP
+
+    let mut seen: Vec<u32> = (0..1000).map(|_| rng.gen_range(low..=high).as_u32()).collect();
+    seen.sort();
+    seen.dedup();
+
+    assert_eq!(seen, vec![0x3F800000, 0x3F800001, 0x3F800002, 0x3F800003]);
+}
+
+#[test]
+#[should_panic]
+fn ps2float_sample_uniform_signed_zeros_is_empty() {
+    let _ = Uniform::new(Ps2Float::new(0x80000000), Ps2Float::new(0x00000000));
+}
+
+#[rstest]
+#[case(0x80000000, 0x00000000)] // [-0.00, +0.00]
+#[case(0x00000000, 0x007FFFFF)] // [+0.00, Biggest denormalized]
+fn ps2float_sample_uniform_inclusive_zeros(#[case] low: u32, #[case] high: u32) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let range = Uniform::new_inclusive(Ps2Float::new(low), Ps2Float::new(high));
+
+    for value in (&mut rng).sample_iter(range).take(100) {
+        assert_eq!(value.as_u32(), 0x00000000);
+    }
+}
+
+#[test]
+fn ps2float_sample_edge_cases() {
+    use ps2_floating_point::random::EdgeCases;
+
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let max_hits =
+        (&mut rng).sample_iter(EdgeCases).take(1000).filter(|f| f.as_u32() == 0x7FFFFFFF).count();
+
+    assert!(max_hits > 0);
+}
@@ -0,0 +1,107 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::explain::NormalizationStep;
+use ps2_floating_point::rounding::{rounding_mode, with_rounding_mode, RoundingMode};
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(0x3F800000, 0xBDCCCCCD, 0x3F666668, 0x3F666666)] // 1.0 - 0.1
+#[case(0x3F800000, 0x33800000, 0x3F800000, 0x3F800000)] // Tie rounds down to even
+#[case(0x3F800000, 0x34400000, 0x3F800001, 0x3F800002)] // Tie rounds up to even
+#[case(0x3F800000, 0x337FFFFF, 0x3F800000, 0x3F800000)] // Just below the tie
+#[case(0x7F7FFFFF, 0x7F7FFFFF, 0x7FFFFFFF, 0x7FFFFFFF)] // Exponent 255 is ordinary
+#[case(0x00800000, 0x80800001, 0x80000000, 0x80000000)] // Underflow keeps the sign
+#[case(0xBF800000, 0x3F800000, 0x00000000, 0x00000000)] // Cancellation is +0
+fn rounding_add(#[case] a: u32, #[case] b: u32, #[case] truncated: u32, #[case] nearest_even: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.add(b).as_u32(), truncated);
+    let rounded = with_rounding_mode(RoundingMode::NearestEven, || a.add(b));
+    assert_eq!(rounded.as_u32(), nearest_even);
+}
+
+#[rstest]
+#[case(0x3FD093DB, 0x3FD2B77E, 0x402BAEC9, 0x402BAECA)]
+#[case(0x3F800001, 0x3F800001, 0x3F800002, 0x3F800002)]
+#[case(0x3F800001, 0x3F7FFFFF, 0x3F800000, 0x3F800000)] // Just below the tie
+#[case(0x00800000, 0x3F000000, 0x00000000, 0x00000000)] // Underflow still flushes
+#[case(0x7F000000, 0x7F000000, 0x7FFFFFFF, 0x7FFFFFFF)]
+fn rounding_mul(#[case] a: u32, #[case] b: u32, #[case] truncated: u32, #[case] nearest_even: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.mul(b).as_u32(), truncated);
+    let rounded = with_rounding_mode(RoundingMode::NearestEven, || a.mul(b));
+    assert_eq!(rounded.as_u32(), nearest_even);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x40400000, 0x3EAAAAAA, 0x3EAAAAAB)] // 1/3
+#[case(0x3F800000, 0x3F7FFFFF, 0x3F800000, 0x3F800001)] // Just above the tie
+#[case(0x3F800000, 0x00000000, 0x7FFFFFFF, 0x7FFFFFFF)] // Dividing by zero
+fn rounding_div(#[case] a: u32, #[case] b: u32, #[case] truncated: u32, #[case] nearest_even: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    assert_eq!(a.div(b).as_u32(), truncated);
+    let rounded = with_rounding_mode(RoundingMode::NearestEven, || a.div(b));
+    assert_eq!(rounded.as_u32(), nearest_even);
+}
+
+#[rstest]
+#[case(0x40A00000, 0x400F1BBC, 0x400F1BBD)] // sqrt(5.00)
+#[case(0x40000000, 0x3FB504F3, 0x3FB504F3)] // sqrt(2.00)
+#[case(0xC0A00000, 0x400F1BBC, 0x400F1BBD)] // sqrt(-5.00)
+fn rounding_sqrt(#[case] value: u32, #[case] truncated: u32, #[case] nearest_even: u32) {
+    let value = Ps2Float::new(value);
+
+    assert_eq!(value.sqrt().as_u32(), truncated);
+    let rounded = with_rounding_mode(RoundingMode::NearestEven, || value.sqrt());
+    assert_eq!(rounded.as_u32(), nearest_even);
+}
+
+#[test]
+fn rounding_nearest_even_matches_ieee_for_ordinary_values() {
+    let mut seed = 0x12345678u32;
+    let mut next = || {
+        seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        // Exponents 64 to 191 keep the results far from overflow and underflow
+        (seed & 0x80FFFFFF) | (0x40 + (seed >> 25)) << 23
+    };
+
+    with_rounding_mode(RoundingMode::NearestEven, || {
+        for _ in 0..10_000 {
+            let (a, b) = (next(), next());
+            let (x, y) = (f32::from_bits(a), f32::from_bits(b));
+            let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+            assert_eq!(a.add(b).as_u32(), (x + y).to_bits(), "{:?} + {:?}", x, y);
+            assert_eq!(a.sub(b).as_u32(), (x - y).to_bits(), "{:?} - {:?}", x, y);
+        }
+    });
+}
+
+#[test]
+fn rounding_nearest_even_traces_overflow() {
+    let a = Ps2Float::new(0x7FFFFFFF);
+    let b = Ps2Float::new(0x7FFFFFFE);
+
+    let explanation = with_rounding_mode(RoundingMode::NearestEven, || a.add_explained(b));
+
+    assert_eq!(explanation.result.as_u32(), 0x7FFFFFFF);
+    assert_eq!(explanation.normalization, [NormalizationStep::Overflow]);
+}
+
+#[test]
+fn rounding_mode_is_restored() {
+    assert_eq!(rounding_mode(), RoundingMode::Truncate);
+
+    let nested = with_rounding_mode(RoundingMode::NearestEven, || {
+        with_rounding_mode(RoundingMode::Truncate, rounding_mode)
+    });
+    assert_eq!(nested, RoundingMode::Truncate);
+
+    let panicked = std::panic::catch_unwind(|| {
+        with_rounding_mode(RoundingMode::NearestEven, || panic!("restored anyway"))
+    });
+    assert!(panicked.is_err());
+    assert_eq!(rounding_mode(), RoundingMode::Truncate);
+}
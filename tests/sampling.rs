@@ -0,0 +1,41 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::sampling::{edge_case_from_bits, EdgeCaseGenerator};
+use rstest::*;
+
+#[rstest]
+#[case(0x000000E0_40A9999A, 0x7FFFFFFF)] // Special encoding MAX
+#[case(0x000000C0_40A9999A, 0x7FFFFFFE)] // Special encoding one ULP below MAX
+#[case(0x00000004_40A9999A, 0x0029999A)] // Edge exponent 0x00
+#[case(0x000000C4_40A9999A, 0x7FA9999A)] // Edge exponent 0xFF
+#[case(0x00000024_C0A9999A, 0xC0800000)] // Edge mantissa all-zeros
+#[case(0x000003F4_40A9999A, 0x7FFFFFFF)] // Edge exponent 0xFF and mantissa all-ones
+#[case(0x0000000A_40A9999A, 0x40A9999A)] // Plain pattern 5.3
+fn sampling_edge_case_from_bits(#[case] bits: u64, #[case] expected: u32) {
+    let result = edge_case_from_bits(bits);
+
+    assert_eq!(result.as_u32(), expected);
+}
+
+#[test]
+fn sampling_edge_case_generator_is_deterministic() {
+    let a: Vec<u32> = EdgeCaseGenerator::new(1234).take(100).map(|f| f.as_u32()).collect();
+    let b: Vec<u32> = EdgeCaseGenerator::new(1234).take(100).map(|f| f.as_u32()).collect();
+    let c: Vec<u32> = EdgeCaseGenerator::new(4321).take(100).map(|f| f.as_u32()).collect();
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[rstest]
+#[case(0x00)]
+#[case(0x01)]
+#[case(0xFE)]
+#[case(0xFF)]
+fn sampling_edge_case_generator_hits_edge_exponents(#[case] exponent: u32) {
+    let hits = EdgeCaseGenerator::new(0)
+        .take(1000)
+        .filter(|f| (f.as_u32() >> 23) & 0xFF == exponent)
+        .count();
+
+    assert!(hits >= 50, "Only {} floats with exponent {:X}", hits, exponent);
+}
@@ -0,0 +1,105 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::clip::ClipFlags;
+use ps2_floating_point::savestate::{
+    read_vu_block, write_vu_block, FpuRegisters, FPU_BLOCK_SIZE, VU_BLOCK_SIZE,
+};
+use ps2_floating_point::vu::{MacFlags, StatusFlags, VuRegisterFile};
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+fn word(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn set_word(bytes: &mut [u8], offset: usize, value: u32) {
+    bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+#[test]
+fn savestate_fpu_block_layout() {
+    let mut fpu = FpuRegisters::default();
+    fpu.fpr[31] = Ps2Float::new(0x7FFFFFFF);
+    fpu.fcr[0] = 0x2E30;
+    fpu.fcr[31] = 0x01000001;
+    fpu.acc = Ps2Float::new(0xBF800000);
+    fpu.acc_flag = 1;
+
+    let bytes = fpu.to_bytes();
+
+    assert_eq!(word(&bytes, 124), 0x7FFFFFFF);
+    assert_eq!(word(&bytes, 128), 0x2E30);
+    assert_eq!(word(&bytes, 252), 0x01000001);
+    assert_eq!(word(&bytes, 256), 0xBF800000);
+    assert_eq!(word(&bytes, 260), 1);
+    assert_eq!(FpuRegisters::from_bytes(&bytes), fpu);
+}
+
+#[rstest]
+#[case(0, 0x00000000)]
+#[case(4, 0x3F800000)]
+#[case(124, 0xFF7FFFFF)]
+fn savestate_fpu_block_reads_fpr(#[case] offset: usize, #[case] bits: u32) {
+    let mut block = [0; FPU_BLOCK_SIZE];
+    set_word(&mut block, offset, bits);
+
+    let fpu = FpuRegisters::from_bytes(&block);
+
+    assert_eq!(fpu.fpr[offset / 4].as_u32(), bits);
+}
+
+#[test]
+fn savestate_vu_block_reads_special_registers() {
+    let mut block = [0; VU_BLOCK_SIZE];
+    set_word(&mut block, 2 * 16 + 4, 0x40000000); // vf2.y
+    set_word(&mut block, 512 + 3 * 16, 0x12345); // vi3, upper half ignored
+    set_word(&mut block, 512 + 16 * 16, 0x0C3); // status
+    set_word(&mut block, 512 + 17 * 16, 0x8421); // MAC
+    set_word(&mut block, 512 + 18 * 16, 0x00ABCDEF); // clip
+    set_word(&mut block, 512 + 20 * 16, 0x3F812345); // R
+    set_word(&mut block, 512 + 21 * 16, 0x3F800000); // I
+    set_word(&mut block, 512 + 22 * 16, 0x40400000); // Q
+    set_word(&mut block, 512 + 23 * 16, 0x40800000); // P
+    set_word(&mut block, 1024 + 12, 0xBF800000); // ACC.w
+    set_word(&mut block, 1040, 0x41000000); // Pending Q, not read
+
+    let registers = read_vu_block(&block);
+
+    assert_eq!(registers.vf(2).lanes()[1].as_u32(), 0x40000000);
+    assert_eq!(registers.vi(3), 0x2345);
+    assert_eq!(registers.status_flags, StatusFlags::from_bits(0x0C3));
+    assert_eq!(registers.mac_flags, MacFlags::from_bits(0x8421));
+    assert_eq!(registers.clip_flags, ClipFlags::from_bits(0x00ABCDEF));
+    assert_eq!(registers.r.rget().as_u32(), 0x3F812345);
+    assert_eq!(registers.i.as_u32(), 0x3F800000);
+    assert_eq!(registers.q.as_u32(), 0x40400000);
+    assert_eq!(registers.p.as_u32(), 0x40800000);
+    assert_eq!(registers.acc.to_bits(), [0, 0, 0, 0xBF800000]);
+}
+
+#[test]
+fn savestate_vu_block_keeps_hardwired_registers() {
+    let mut block = [0xFF; VU_BLOCK_SIZE];
+    set_word(&mut block, 512 + 20 * 16, 0x3F800000); // R
+
+    let registers = read_vu_block(&block);
+
+    assert_eq!(registers.vf(0).to_bits(), VuRegisterFile::VF0);
+    assert_eq!(registers.vi(0), 0);
+}
+
+#[test]
+fn savestate_vu_block_round_trip_keeps_other_slots() {
+    let mut registers = VuRegisterFile::new();
+    registers.set_vf(31, Ps2Vec4::splat(Ps2Float::new(0xC0000000)));
+    registers.set_vi(15, 0xBEEF);
+    registers.q = Ps2Float::new(0x3F000000);
+    registers.status_flags = StatusFlags::from_bits(0x041);
+
+    let mut block = [0; VU_BLOCK_SIZE];
+    set_word(&mut block, 512 + 26 * 16, 0x1234); // TPC
+    write_vu_block(&registers, &mut block);
+
+    assert_eq!(word(&block, 512 + 26 * 16), 0x1234);
+    assert_eq!(word(&block, 1040), 0x3F000000);
+    assert_eq!(read_vu_block(&block), registers);
+}
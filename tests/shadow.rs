@@ -0,0 +1,55 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::shadow::{Divergence, ShadowExecutor};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+#[test]
+fn shadow_reports_divergences_above_threshold() {
+    let one = Ps2Float::new(0x3F800000); // 1.00
+    let big = Ps2Float::new(0x7F7FFFFF); // Biggest IEEE 754 float
+    let tenth = Ps2Float::new(0x3DCCCCCD); // 0.1
+
+    let mut divergences: Vec<Divergence> = Vec::new();
+    {
+        let mut executor = ShadowExecutor::new(2, |d| divergences.push(*d));
+
+        assert_eq!(executor.add(one, one).as_u32(), 0x40000000); // Matches IEEE 754
+        assert_eq!(executor.add(big, big).as_u32(), 0x7FFFFFFF); // IEEE 754 overflows to INF
+        assert_eq!(executor.sub(one, tenth).as_u32(), 0x3F666668); // Truncation is 2 ULPs off
+        assert_eq!(executor.operations(), 3);
+    }
+
+    assert_eq!(divergences.len(), 1);
+    assert_eq!(divergences[0].index, 1);
+    assert_eq!(divergences[0].operation, Operation::Add);
+    assert_eq!(divergences[0].ieee, f32::INFINITY);
+    assert_eq!(divergences[0].ulps, 0x7FFFFF);
+}
+
+#[test]
+fn shadow_zero_threshold_reports_truncation() {
+    let mut divergences: Vec<Divergence> = Vec::new();
+    {
+        let mut executor = ShadowExecutor::new(0, |d| divergences.push(*d));
+        executor.sub(Ps2Float::new(0x3F800000), Ps2Float::new(0x3DCCCCCD)); // 1.00 - 0.1
+    }
+
+    assert_eq!(divergences.len(), 1);
+    assert_eq!(divergences[0].ulps, 2);
+    assert_eq!(divergences[0].ps2.as_u32(), 0x3F666668);
+    assert_eq!(divergences[0].ieee.to_bits(), 0x3F666666);
+}
+
+#[rstest]
+#[case(0x3F800000, 0x3DCCCCCD, 0x3F666668, 0x3F666666)] // 1.00 - 0.1
+#[case(0x7F800000, 0x3F800000, 0x7F800000, 0x7F800000)] // Same bits, but a big number on the PS2
+#[case(0x00000001, 0x00000000, 0x00000000, 0x00000001)] // Denormals are flushed on the PS2
+fn shadow_sub_both(#[case] a: u32, #[case] b: u32, #[case] ps2: u32, #[case] ieee: u32) {
+    let (a, b) = (Ps2Float::new(a), Ps2Float::new(b));
+
+    let (ps2_result, ieee_result) = a.sub_both(b);
+
+    assert_eq!(ps2_result.as_u32(), ps2);
+    assert_eq!(ieee_result.to_bits(), ieee);
+    assert_eq!((ps2_result, ieee_result), Operation::Sub.apply_both(a, b));
+}
@@ -0,0 +1,48 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::stress::StressTest;
+use ps2_floating_point::{Operation, Ps2Float};
+
+#[test]
+fn stress_test_matching_reference() {
+    let report = StressTest::new(42).iterations(10_000).run(|op, a, b| op.apply(a, b));
+
+    assert_eq!(report.unwrap().cases, 10_000);
+}
+
+#[test]
+fn stress_test_mismatch_replays() {
+    let stress_test = StressTest::new(42).iterations(10_000).operations(&[Operation::Add]);
+
+    // A reference that disagrees whenever the sum is MAX.
+    let mismatch = stress_test
+        .run(|op, a, b| {
+            let result = op.apply(a, b);
+            if result == Ps2Float::max() {
+                Ps2Float::new(0)
+            } else {
+                result
+            }
+        })
+        .unwrap_err();
+
+    let replayed = stress_test.case(mismatch.case.index);
+    assert_eq!(replayed, mismatch.case);
+    assert_eq!(replayed.operation.apply(replayed.a, replayed.b), Ps2Float::max());
+    assert_eq!(mismatch.actual, Ps2Float::max());
+    assert_eq!(mismatch.expected, Ps2Float::new(0));
+}
+
+#[test]
+fn stress_test_mismatch_prints_reproducer() {
+    let stress_test = StressTest::new(7).operations(&[Operation::Sub]);
+
+    let mismatch = stress_test.run(|_, _, _| Ps2Float::new(0x3F800000)).unwrap_err();
+    let case = mismatch.case;
+
+    assert_eq!(case.index, 0);
+    assert!(mismatch.to_string().contains(&format!(
+        "#[case(0x{:08X}, 0x{:08X}, 0x3F800000)]",
+        case.a.as_u32(),
+        case.b.as_u32()
+    )));
+}
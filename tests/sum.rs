@@ -0,0 +1,99 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::sum::{compare_sums, sum_with, Accumulation, TrySum};
+use ps2_floating_point::vu::StatusFlags;
+use ps2_floating_point::Ps2Float;
+use rstest::*;
+
+#[rstest]
+#[case(&[], 0x00000000, 0x000)]
+#[case(&[0x3F800000, 0x40000000, 0x40400000], 0x40C00000, 0x000)] // 1 + 2 + 3 = 6
+#[case(&[0xC0000000], 0xC0000000, 0x000)] // -2
+#[case(&[0x3F800000, 0xC0400000, 0xC0000000], 0xC0800000, 0x082)] // 1 - 3 - 2 = -4
+#[case(&[0x3F800000, 0xBF800000, 0x3F800000], 0x3F800000, 0x040)] // 1 - 1 + 1 = 1
+#[case(&[0x00800001, 0x80800000], 0x00000000, 0x145)] // Flushes to zero
+#[case(&[0x7FFFFFFF, 0x7F7FFFFF, 0xFF7FFFFF], 0x7F800000, 0x200)] // Saturates to Fmax
+#[case(&[0x00000001], 0x00000000, 0x000)] // A lone denormalized float is zero
+#[case(&[0x80400000], 0x80000000, 0x000)]
+fn try_sum(#[case] values: &[u32], #[case] sum: u32, #[case] flags: u16) {
+    let values: Vec<Ps2Float> = values.iter().copied().map(Ps2Float::new).collect();
+
+    let result = values.into_iter().try_sum();
+
+    assert_eq!(result.sum.as_u32(), sum);
+    assert_eq!(result.flags, StatusFlags::from_bits(flags));
+    assert_eq!(result.overflowed(), flags & (StatusFlags::STICKY_OVERFLOW) != 0);
+    assert_eq!(result.underflowed(), flags & (StatusFlags::STICKY_UNDERFLOW) != 0);
+}
+
+#[rstest]
+#[case(&[], 0x00000000)]
+#[case(&[0x80000000], 0x80000000)] // -0 isn't added to +0
+#[case(&[0x3F800000, 0x40000000, 0x40400000], 0x40C00000)] // 1 + 2 + 3 = 6
+#[case(&[0x4B800000, 0x3FC00000, 0x3FC00000], 0x4B800000)] // 2^24 + 1.5 + 1.5, in order
+#[case(&[0x3FC00000, 0x3FC00000, 0x4B800000], 0x4B800001)] // 1.5 + 1.5 + 2^24, in order
+#[case(&[0x7FFFFFFF, 0x7F7FFFFF, 0xFF7FFFFF], 0x7F800000)] // Saturates to Fmax
+fn sum_iterator(#[case] values: &[u32], #[case] expected: u32) {
+    let values: Vec<Ps2Float> = values.iter().copied().map(Ps2Float::new).collect();
+
+    assert_eq!(values.iter().sum::<Ps2Float>().as_u32(), expected);
+    assert_eq!(values.iter().copied().sum::<Ps2Float>().as_u32(), expected);
+    assert_eq!(values.iter().try_sum().sum.as_u32(), expected);
+}
+
+#[rstest]
+#[case(&[], 0x3F800000)]
+#[case(&[0x80000001], 0x80000001)] // A lone denormalized float isn't multiplied
+#[case(&[0x40000000, 0x40400000, 0xC0800000], 0xC1C00000)] // 2 * 3 * -4 = -24
+#[case(&[0x3F800001, 0x3F800001, 0x3F800001], 0x3F800003)] // (1 + 1 ULP)^3
+#[case(&[0x7F000000, 0x40000000, 0x3F000000], 0x7F000000)] // 2^127 * 2 * 0.5
+#[case(&[0x7F000000, 0x40800000, 0x3F000000], 0x7F7FFFFF)] // Saturates before halving
+#[case(&[0x40000000, 0x00000001], 0x00000000)] // Denormalized is zero
+fn product_iterator(#[case] values: &[u32], #[case] expected: u32) {
+    let values: Vec<Ps2Float> = values.iter().copied().map(Ps2Float::new).collect();
+
+    assert_eq!(values.iter().product::<Ps2Float>().as_u32(), expected);
+    assert_eq!(values.iter().copied().product::<Ps2Float>().as_u32(), expected);
+}
+
+#[rstest]
+#[case(&[], 0x00000000)]
+#[case(&[0x3F800000, 0x40000000, 0x40400000], 0x40C00000)] // 1 + 2 + 3 = 6
+#[case(&[0x7F000000, 0x3F800000, 0xFF000000], 0x3F800000)] // 2^127 + 1 - 2^127 = 1
+#[case(&[0x4B800000, 0x3F800000], 0x4B800000)] // 2^24 + 1 ties to even
+#[case(&[0x4B800000, 0x3FC00000], 0x4B800001)] // 2^24 + 1.5 rounds up
+#[case(&[0xC0000000, 0xBF800001], 0xC0400000)] // -2 - (1 + 1 ULP) rounds to -3
+#[case(&[0x00800001, 0x80800000], 0x00000000)] // Flushes to zero
+#[case(&[0x80800001, 0x00800000], 0x80000000)] // Flushes to -0
+#[case(&[0x7FFFFFFF, 0x7FFFFFFF], 0x7FFFFFFF)] // Saturates to Fmax
+#[case(&[0xFFFFFFFF, 0xFFFFFFFF, 0x7FFFFFFF], 0xFFFFFFFF)] // -Fmax
+#[case(&[0x00000001, 0x3F800000], 0x3F800000)] // Denormalized is zero
+fn sum_exact(#[case] values: &[u32], #[case] expected: u32) {
+    let values: Vec<Ps2Float> = values.iter().copied().map(Ps2Float::new).collect();
+
+    assert_eq!(sum_with(&values, Accumulation::Exact).as_u32(), expected);
+}
+
+#[rstest]
+#[case(&[], 0x00000000)]
+#[case(&[0xC0000000], 0xC0000000)] // -2
+#[case(&[0x4B800000, 0x3FC00000, 0x3FC00000], 0x4B800001)] // 2^24 + (1.5 + 1.5)
+#[case(&[0x3F800000, 0x3F800000, 0x40000000, 0x40800000], 0x41000000)] // (1 + 1) + (2 + 4)
+fn sum_pairwise(#[case] values: &[u32], #[case] expected: u32) {
+    let values: Vec<Ps2Float> = values.iter().copied().map(Ps2Float::new).collect();
+
+    assert_eq!(sum_with(&values, Accumulation::Pairwise).as_u32(), expected);
+}
+
+#[test]
+fn sum_comparison_errors() {
+    let mut values = vec![Ps2Float::new(0x4B800000)]; // 2^24
+    values.extend([Ps2Float::new(0x3FC00000); 8]); // 1.5
+
+    let sums = compare_sums(&values);
+
+    assert_eq!(sums.sequential, sum_with(&values, Accumulation::Sequential));
+    assert_eq!(sums.sequential.as_u32(), 0x4B800000);
+    assert_eq!(sums.exact.as_u32(), 0x4B800006); // 2^24 + 12
+    assert_eq!(sums.sequential_error(), 6);
+    assert_eq!(sums.pairwise_error(), sums.pairwise.ulp_distance(sums.exact));
+}
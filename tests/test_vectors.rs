@@ -0,0 +1,70 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::test_vectors::{run_test_vectors, TestVector, TestVectorError};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+const CSV: &str = "
+# Adapted from the ps2float_add and ps2float_sub tests.
+op, a, b, expected
+add, 0x3F800000, 0x3F800000, 0x40000000
+sub, 0x40400000, 0x3F800000, 0x40000000
++, 0x7FFFFFFF, 0x7FFFFFFF, 0x7FFFFFFF
+SUB, FF800000, 7F800000, 3F800000
+";
+
+#[test]
+fn test_vectors_parse_csv() {
+    let vectors = TestVector::parse_csv(CSV).unwrap();
+
+    assert_eq!(vectors.len(), 4);
+    assert_eq!(
+        vectors[0],
+        TestVector {
+            operation: Operation::Add,
+            a: Ps2Float::new(0x3F800000),
+            b: Ps2Float::new(0x3F800000),
+            expected: Ps2Float::new(0x40000000),
+        }
+    );
+}
+
+#[test]
+fn test_vectors_run() {
+    let vectors = TestVector::parse_csv(CSV).unwrap();
+
+    let report = run_test_vectors(&vectors);
+
+    assert_eq!(report.passed, 3);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].index, 3);
+    assert_eq!(report.failures[0].actual.as_u32(), 0xFFFFFFFF);
+    assert!(!report.is_success());
+}
+
+#[rstest]
+#[case("add, 0x3F800000, 0x3F800000", 1)] // Missing field
+#[case("\n\npow, 0x3F800000, 0x3F800000, 0x3F800000", 3)] // Unknown operation
+#[case("add, 0x3F800000, 1.0, 0x3F800000", 1)] // Not hex bits
+fn test_vectors_parse_csv_error(#[case] csv: &str, #[case] expected_line: usize) {
+    let result = TestVector::parse_csv(csv);
+
+    match result {
+        Err(TestVectorError::Parse { line, .. }) => assert_eq!(line, expected_line),
+        other => panic!("Expected a parse error, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_vectors_parse_json() {
+    let json = r#"[
+        { "op": "add", "a": "0x3F800000", "b": "0x3F800000", "expected": "0x40000000" },
+        { "op": "-", "a": 1077936128, "b": "3F800000", "expected": 1073741824 }
+    ]"#;
+
+    let vectors = TestVector::parse_json(json).unwrap();
+
+    assert_eq!(vectors.len(), 2);
+    assert_eq!(vectors[1].operation, Operation::Sub);
+    assert!(run_test_vectors(&vectors).is_success());
+}
@@ -0,0 +1,83 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::trace::{replay_trace, TraceError, TraceReader, TraceRecord, TraceWriter};
+use ps2_floating_point::{Operation, Ps2Float};
+use rstest::*;
+
+fn record(operation: Operation, a: u32, b: u32, result: u32, flags: u8) -> TraceRecord {
+    TraceRecord {
+        operation,
+        a: Ps2Float::new(a),
+        b: Ps2Float::new(b),
+        result: Ps2Float::new(result),
+        flags,
+    }
+}
+
+fn write_trace(records: &[TraceRecord]) -> Vec<u8> {
+    let mut writer = TraceWriter::new(Vec::new()).unwrap();
+    for record in records {
+        writer.write(record).unwrap();
+    }
+    writer.into_inner().unwrap()
+}
+
+#[test]
+fn trace_round_trip() {
+    let records = [
+        record(Operation::Add, 0x3F800000, 0x3F800000, 0x40000000, 0),
+        record(Operation::Sub, 0x3F800000, 0x40400000, 0xC0000000, 0x02),
+        record(Operation::Div, 0x3F800000, 0x00000000, 0x7FFFFFFF, 0x28),
+    ];
+
+    let bytes = write_trace(&records);
+    assert_eq!(bytes.len(), 5 + 3 * 14);
+    assert_eq!(&bytes[..5], b"PS2T\x01");
+    assert_eq!(&bytes[5..19], &[0, 0, 0, 0x80, 0x3F, 0, 0, 0x80, 0x3F, 0, 0, 0, 0x40, 0]);
+
+    let read: Vec<TraceRecord> =
+        TraceReader::new(bytes.as_slice()).unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(read, records);
+}
+
+#[test]
+fn trace_replay() {
+    let bytes = write_trace(&[
+        record(Operation::Add, 0x3F800000, 0x3F800000, 0x40000000, 0),
+        record(Operation::Add, 0x7FFFFFFF, 0x7FFFFFFF, 0x7F800000, 0), // IEEE 754 capture
+        TraceRecord::capture(Operation::Sub, &Ps2Float::new(0x40400000), &Ps2Float::max(), 0),
+    ]);
+
+    let report = replay_trace(TraceReader::new(bytes.as_slice()).unwrap()).unwrap();
+
+    assert_eq!(report.matched, 2);
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(report.mismatches[0].index, 1);
+    assert_eq!(report.mismatches[0].actual.as_u32(), 0x7FFFFFFF);
+    assert!(!report.is_success());
+}
+
+#[rstest]
+#[case(b"PS2X\x01".to_vec(), "not a trace, missing 'PS2T' magic")]
+#[case(b"PS2".to_vec(), "not a trace, missing 'PS2T' magic")]
+#[case(b"PS2T\x02".to_vec(), "unsupported trace version 2")]
+fn trace_reader_header_error(#[case] bytes: Vec<u8>, #[case] expected: &str) {
+    let error = TraceReader::new(bytes.as_slice()).unwrap_err();
+
+    assert_eq!(format!("{}", error), expected);
+}
+
+#[rstest]
+#[case(&[0, 0, 0, 0x80, 0x3F], "record 1: truncated")]
+#[case(&[9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], "record 1: invalid operation code 9")]
+fn trace_reader_record_error(#[case] tail: &[u8], #[case] expected: &str) {
+    let mut bytes = write_trace(&[record(Operation::Add, 0, 0, 0, 0)]);
+    bytes.extend_from_slice(tail);
+
+    let mut reader = TraceReader::new(bytes.as_slice()).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    let error = reader.next().unwrap().unwrap_err();
+
+    assert!(!matches!(error, TraceError::Io(_)));
+    assert_eq!(format!("{}", error), expected);
+    assert!(reader.next().is_none());
+}
@@ -0,0 +1,48 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::vu::MacFlags;
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+#[test]
+fn ps2vec4_add_sub() {
+    let a = Ps2Vec4::from_bits([0x3F800000, 0x40000000, 0x7FFFFFFF, 0x00000000]);
+    let b = Ps2Vec4::from_bits([0x3F800000, 0x40400000, 0x7FFFFFFF, 0x00400000]);
+
+    assert_eq!(a.add(&b).to_bits(), [0x40000000, 0x40A00000, 0x7FFFFFFF, 0x00000000]);
+    assert_eq!(a.sub(&b).to_bits(), [0x00000000, 0xBF800000, 0x00000000, 0x80000000]);
+}
+
+#[test]
+fn ps2vec4_sub_flagged() {
+    let a = Ps2Vec4::from_bits([0x00800000, 0x3F800000, 0x40000000, 0x3F800000]);
+    let b = Ps2Vec4::from_bits([0x00800001, 0x3F800000, 0x3F800000, 0x40400000]);
+
+    let (difference, flags) = a.sub_flagged(&b);
+
+    assert_eq!(difference, a.sub(&b));
+    assert_eq!(difference.to_bits(), [0x80000000, 0x00000000, 0x3F800000, 0xC0000000]);
+    assert_eq!(flags, MacFlags::from_bits(0x089C)); // Zx Ux Sx, Zy, Sw
+}
+
+#[rstest]
+#[case(0x3F800000, 16)] // 1.00
+#[case(0x3FC00000, 24)] // 1.50
+#[case(0xBF900000, -18)] // -1.125
+#[case(0x3D800000, 1)] // 0.0625, the smallest step
+#[case(0x3D000000, 0)] // 0.03125 truncates to zero
+#[case(0xBD000000, 0)] // -0.03125 truncates toward zero
+#[case(0x00400000, 0)] // Denormalized
+#[case(0x4F000000, 0x7FFFFFFF)] // 2^31 saturates
+#[case(0xFFFFFFFF, -0x80000000)] // -Fmax saturates
+fn ps2vec4_ftoi4(#[case] value: u32, #[case] expected: i32) {
+    let v = Ps2Vec4::splat(Ps2Float::new(value));
+
+    assert_eq!(v.ftoi4(), [expected; 4]);
+}
+
+#[test]
+fn ps2vec4_display() {
+    let v = Ps2Vec4::from_bits([0x3F800000, 0xC0000000, 0x40400000, 0x3F000000]);
+
+    assert_eq!(format!("{}", v), "(1.00, -2.00, 3.00, 0.50)");
+}
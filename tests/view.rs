@@ -0,0 +1,58 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::view::StridedView;
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+/// Returns `count` 32-byte vertices, each a position followed by a normal,
+/// with every lane holding the vertex index.
+fn vertex_buffer(count: u32) -> Vec<u8> {
+    (0..count)
+        .flat_map(|vertex| [vertex; 8])
+        .flat_map(|value| (0x3F800000 + value).to_le_bytes())
+        .collect()
+}
+
+#[test]
+fn strided_view_vec4() {
+    let memory = vertex_buffer(3);
+    let positions: StridedView<Ps2Vec4> = StridedView::new(&memory, 0, 32);
+
+    assert_eq!(positions.len(), 3);
+    assert_eq!(positions.get(2), Some(Ps2Vec4::from_bits([0x3F800002; 4])));
+    assert_eq!(positions.get(3), None);
+}
+
+#[test]
+fn strided_view_float_iter() {
+    let memory = vertex_buffer(3);
+    let normal_y: StridedView<Ps2Float> = StridedView::new(&memory, 20, 32);
+
+    let bits: Vec<u32> = normal_y.iter().map(|value| value.as_u32()).collect();
+    assert_eq!(bits, [0x3F800000, 0x3F800001, 0x3F800002]);
+
+    let reversed: Vec<u32> = normal_y.iter().rev().map(|value| value.as_u32()).collect();
+    assert_eq!(reversed, [0x3F800002, 0x3F800001, 0x3F800000]);
+    assert_eq!(normal_y.iter().nth(1), normal_y.get(1));
+    assert_eq!(normal_y.iter().len(), 3);
+}
+
+#[rstest]
+#[case(64, 0, 2)]
+#[case(79, 0, 2)] // 1 byte short of the third position
+#[case(80, 0, 3)] // The third position without its normal
+#[case(96, 80, 1)] // Only the last normal
+#[case(96, 84, 0)] // Too few bytes past the offset
+#[case(96, 200, 0)] // Offset past the end
+fn strided_view_len(#[case] size: usize, #[case] offset: usize, #[case] expected: usize) {
+    let memory = vertex_buffer(3);
+    let view: StridedView<Ps2Vec4> = StridedView::new(&memory[..size], offset, 32);
+
+    assert_eq!(view.len(), expected);
+    assert_eq!(view.iter().count(), expected);
+}
+
+#[test]
+#[should_panic(expected = "stride must not be zero")]
+fn strided_view_zero_stride() {
+    StridedView::<Ps2Float>::new(&[], 0, 0);
+}
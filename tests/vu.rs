@@ -0,0 +1,458 @@
+use pretty_assertions::assert_eq;
+use ps2_floating_point::clip::{ClipFlags, ClipJudgment};
+use ps2_floating_point::vu::instruction::{
+    decode_lower, decode_upper, FmacOperation, Lane, LowerInstruction, Operand,
+    UnsupportedInstruction, UpperInstruction,
+};
+use ps2_floating_point::vu::{
+    AtomicStatusFlags, BitChange, FlagMode, MacFlags, RRegister, RegisterChange, StatusFlags,
+    VuError, VuInterpreter, VuMem, VuRegister, VuRegisterFile, VuRegisterValue,
+};
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+const NOP_UPPER: u32 = 0x000002FF;
+const NOP_LOWER: u32 = 0x8000033C;
+const E_BIT: u32 = 0x40000000;
+
+fn pair(upper: u32, lower: u32) -> u64 {
+    (upper as u64) << 32 | lower as u64
+}
+
+fn vec(bits: [u32; 4]) -> Ps2Vec4 {
+    Ps2Vec4::from_bits(bits)
+}
+
+#[test]
+fn vu_r_register_sequence() {
+    let mut r = RRegister::new();
+    r.rinit(&Ps2Float::new(0xC0012345)); // Only the mantissa seeds
+
+    let sequence: Vec<u32> = (0..4).map(|_| r.rnext().as_u32()).collect();
+
+    assert_eq!(sequence, [0x3F82468A, 0x3F848D14, 0x3F891A29, 0x3F923452]);
+}
+
+#[rstest]
+#[case(0x3F800001, 0x3F800002)] // No taps set
+#[case(0x3F800010, 0x3F800021)] // Bit 4 feeds back
+#[case(0x3FC00000, 0x3F800001)] // Bit 22 feeds back and shifts out
+#[case(0x3FC00010, 0x3F800020)] // Both taps cancel out
+fn vu_r_register_rnext(#[case] seed: u32, #[case] expected: u32) {
+    let mut r = RRegister::new();
+    r.rinit(&Ps2Float::new(seed));
+
+    assert_eq!(r.rnext().as_u32(), expected);
+}
+
+#[test]
+fn vu_r_register_rxor_rget() {
+    let mut r = RRegister::default();
+    assert_eq!(r.rget().as_u32(), 0x3F800000);
+
+    r.rxor(&Ps2Float::new(0x40F0F0F0));
+    r.rxor(&Ps2Float::new(0x000000FF));
+
+    assert_eq!(r.rget().as_u32(), 0x3FF0F00F);
+    assert_eq!(r.rget().as_u32(), 0x3FF0F00F);
+}
+
+#[rstest]
+#[case(0x000002FF, UpperInstruction::Nop)]
+#[case(0x01E208E8, UpperInstruction::Fmac {
+    operation: FmacOperation::Add, dest: 0xF, fd: Some(3), fs: 1, operand: Operand::Register(2)
+})]
+#[case(0x01E209BC, UpperInstruction::Fmac {
+    operation: FmacOperation::Mul,
+    dest: 0xF,
+    fd: None,
+    fs: 1,
+    operand: Operand::Broadcast(2, Lane::X)
+})]
+#[case(0x008208A7, UpperInstruction::Fmac {
+    operation: FmacOperation::Msub, dest: 0x4, fd: Some(2), fs: 1, operand: Operand::I
+})]
+#[case(0x01E2097D, UpperInstruction::Ftoi { dest: 0xF, ft: 2, fs: 1, fraction_bits: 4 })]
+#[case(0x01C209FF, UpperInstruction::Clip { fs: 1, ft: 2 })]
+fn vu_decode_upper(#[case] word: u32, #[case] expected: UpperInstruction) {
+    assert_eq!(decode_upper(word), Ok(expected));
+}
+
+#[rstest]
+#[case(0x8000033C, LowerInstruction::Nop)]
+#[case(0x81E11B3C, LowerInstruction::Move { dest: 0xF, ft: 1, fs: 3 })]
+#[case(0x80820BBC, LowerInstruction::Div { fs: 1, fsf: Lane::X, ft: 2, ftf: Lane::Y })]
+#[case(0x2C01003F, LowerInstruction::Fsand { it: 1, imm: 0x3F })]
+#[case(0x03E00FFF, LowerInstruction::Sq { dest: 0xF, fs: 1, it: 0, offset: -1 })]
+fn vu_decode_lower(#[case] word: u32, #[case] expected: LowerInstruction) {
+    assert_eq!(decode_lower(word), Ok(expected));
+}
+
+#[test]
+fn vu_decode_unsupported() {
+    assert_eq!(decode_upper(0x00000030), Err(UnsupportedInstruction(0x00000030))); // Unused
+    assert_eq!(decode_lower(0x40000000), Err(UnsupportedInstruction(0x40000000)));
+    // B
+}
+
+#[test]
+fn vu_interpreter_mac_and_status_flags() {
+    // SUB.xyz vf3, vf1, vf2
+    let program = vec![pair(0x01C208EC | E_BIT, NOP_LOWER), pair(NOP_UPPER, NOP_LOWER)];
+    let mut vu = VuInterpreter::new(program);
+    vu.registers.set_vf(1, vec([0x7FFFFFFF, 0x3F800000, 0x3F800000, 0x3F800000]));
+    vu.registers.set_vf(2, vec([0xFFFFFFFE, 0x3F800000, 0x40000000, 0x3F800000]));
+
+    assert_eq!(vu.run(16), Ok(2));
+
+    assert_eq!(vu.registers.vf(3), vec([0x7FFFFFFF, 0x00000000, 0xBF800000, 0x00000000]));
+    assert_eq!(vu.registers.mac_flags.bits(), 0x8024); // x overflow, y zero, z sign
+    assert_eq!(vu.registers.mac_flags.lane(0), (false, false, false, true));
+    let summary = StatusFlags::OVERFLOW | StatusFlags::ZERO | StatusFlags::SIGN;
+    assert!(vu.registers.status_flags.contains(summary));
+    assert_eq!(vu.registers.status_flags.bits(), 0x2CB);
+}
+
+#[test]
+fn vu_interpreter_abnormal_overflow() {
+    // SUB.xyz vf3, vf1, vf2
+    let program = vec![pair(0x01C208EC | E_BIT, NOP_LOWER), pair(NOP_UPPER, NOP_LOWER)];
+    let mut vu = VuInterpreter::new(program);
+    vu.registers.set_vf(1, vec([0x7FFFFFFF, 0x7FFFFFFF, 0x3F800000, 0x3F800000]));
+    vu.registers.set_vf(2, vec([0xFFFFFFFF, 0x7FFFFFFF, 0x40000000, 0x3F800000]));
+
+    assert_eq!(vu.run(16), Ok(2));
+
+    // MAX - -MAX saturates from the abnormal table, MAX - MAX cancels out.
+    assert_eq!(vu.registers.vf(3), vec([0x7FFFFFFF, 0x00000000, 0xBF800000, 0x00000000]));
+    assert_eq!(vu.registers.mac_flags.bits(), 0x8024); // x overflow, y zero, z sign
+}
+
+#[rstest]
+#[case(FlagMode::Eager)]
+#[case(FlagMode::Deferred)]
+fn vu_interpreter_mul_flags(#[case] flag_mode: FlagMode) {
+    // MUL.xyz vf3, vf1, vf2
+    let mul = UpperInstruction::Fmac {
+        operation: FmacOperation::Mul,
+        dest: 0b1110,
+        fd: Some(3),
+        fs: 1,
+        operand: Operand::Register(2),
+    };
+    let mut vu = VuInterpreter::new(Vec::new());
+    vu.set_flag_mode(flag_mode);
+    vu.registers.set_vf(1, vec([0x7FFFFFFF, 0x00800000, 0x40000000, 0x3F800000]));
+    vu.registers.set_vf(2, vec([0x40000000, 0x00800000, 0xC0400000, 0x3F800000]));
+
+    vu.execute(mul, LowerInstruction::Nop);
+    vu.sync_flags();
+
+    assert_eq!(vu.registers.vf(3), vec([0x7FFFFFFF, 0x00000000, 0xC0C00000, 0x00000000]));
+    assert_eq!(vu.registers.mac_flags.bits(), 0x8424); // x overflow, y zero and underflow, z sign
+    let summary = StatusFlags::OVERFLOW | StatusFlags::UNDERFLOW;
+    assert!(vu.registers.status_flags.contains(summary));
+}
+
+#[test]
+fn vu_interpreter_pair_reads_registers_before_the_pair() {
+    let one = Ps2Vec4::splat(Ps2Float::new(0x3F800000));
+    let two = Ps2Vec4::splat(Ps2Float::new(0x40000000));
+    let mut vu = VuInterpreter::new(Vec::new());
+    vu.registers.set_vf(2, one);
+    vu.registers.set_vf(3, two);
+
+    // ADD.xyzw vf1, vf2, vf2 with MOVE.xyzw vf1, vf3, the upper write wins
+    vu.execute(decode_upper(0x01E21068).unwrap(), decode_lower(0x81E11B3C).unwrap());
+    assert_eq!(vu.registers.vf(1), two);
+
+    // ADD.xyzw vf2, vf2, vf2 with MOVE.xyzw vf1, vf2, the lower reads the old vf2
+    vu.registers.set_vf(1, Ps2Vec4::default());
+    vu.execute(decode_upper(0x01E210A8).unwrap(), decode_lower(0x81E1133C).unwrap());
+    assert_eq!(vu.registers.vf(1), one);
+    assert_eq!(vu.registers.vf(2), two);
+}
+
+#[test]
+fn vu_interpreter_conversions() {
+    // FTOI4.xyzw vf2, vf1 then ITOF4.xyzw vf3, vf2
+    let program = vec![
+        pair(0x01E2097D, NOP_LOWER),
+        pair(0x01E3113D | E_BIT, NOP_LOWER),
+        pair(NOP_UPPER, NOP_LOWER),
+    ];
+    let mut vu = VuInterpreter::new(program);
+    let value = vec([0x3FC00000, 0xC0100000, 0x00000000, 0x42C82000]); // 1.5, -2.25, 0, 100.0625
+    vu.registers.set_vf(1, value);
+
+    assert_eq!(vu.run(16), Ok(3));
+
+    assert_eq!(vu.registers.vf(2), vec([24, -36i32 as u32, 0, 1601]));
+    assert_eq!(vu.registers.vf(3), value);
+}
+
+#[test]
+fn vu_interpreter_memory_wraps_around() {
+    // SQ.xyzw vf1, -1(vi0) then LQ.xy vf2, -1(vi0)
+    let program = vec![
+        pair(NOP_UPPER, 0x03E00FFF),
+        pair(NOP_UPPER | E_BIT, 0x018207FF),
+        pair(NOP_UPPER, NOP_LOWER),
+    ];
+    let mut vu = VuInterpreter::new(program);
+    vu.registers.set_vf(1, vec([1, 2, 3, 4]));
+
+    assert_eq!(vu.run(16), Ok(3));
+
+    assert_eq!(vu.memory.load(255), vec([1, 2, 3, 4]));
+    assert_eq!(vu.registers.vf(2), vec([1, 2, 0, 0]));
+}
+
+#[rstest]
+#[case(VuMem::vu0(), 256)]
+#[case(VuMem::vu1(), 1024)]
+fn vu_mem_wraps_around(#[case] mut memory: VuMem, #[case] quadwords: usize) {
+    memory.store(quadwords + 1, vec([1, 2, 3, 4]));
+
+    assert_eq!(memory.quadwords(), quadwords);
+    assert_eq!(memory.load(1), vec([1, 2, 3, 4]));
+    assert_eq!(memory.load(2 * quadwords + 1), vec([1, 2, 3, 4]));
+    assert_eq!(memory.as_slice()[1], vec([1, 2, 3, 4]));
+}
+
+#[test]
+fn vu_mem_from_le_bytes() {
+    let bytes: Vec<u8> = (0..8u32).flat_map(|word| (0x3F800000 + word).to_le_bytes()).collect();
+
+    let memory = VuMem::from_le_bytes(&bytes);
+
+    assert_eq!(memory.quadwords(), 2);
+    assert_eq!(memory.load(1), vec([0x3F800004, 0x3F800005, 0x3F800006, 0x3F800007]));
+}
+
+#[test]
+#[should_panic(expected = "VU data memory dump isn't a whole number of quadwords")]
+fn vu_mem_from_partial_quadword() {
+    VuMem::from_le_bytes(&[0; 20]);
+}
+
+#[test]
+fn vu_interpreter_clip_and_flag_instructions() {
+    // CLIP vf1xyz, vf2w, then FCAND vi1, 0x3 and FSAND vi2, 0xFFF
+    let program = vec![
+        pair(0x01C209FF, NOP_LOWER),
+        pair(NOP_UPPER | E_BIT, 0x24000003),
+        pair(NOP_UPPER, 0x2C020FFF),
+    ];
+    let mut vu = VuInterpreter::new(program);
+    vu.registers.set_vf(1, vec([0x40000000, 0, 0, 0]));
+    vu.registers.set_vf(2, vec([0, 0, 0, 0x3F800000]));
+    vu.registers.status_flags = StatusFlags::from_bits(0x041);
+
+    assert_eq!(vu.run(16), Ok(3));
+
+    assert_eq!(vu.registers.clip_flags.bits(), 0x1); // +x
+    assert_eq!(vu.registers.vi(1), 1);
+    assert_eq!(vu.registers.vi(2), 0x041);
+}
+
+#[test]
+fn vu_interpreter_deferred_flags() {
+    let mut vu = VuInterpreter::new(Vec::new());
+    vu.set_flag_mode(FlagMode::Deferred);
+    vu.registers.set_vf(1, vec([0x7FFFFFFF, 0x3F800000, 0x3F800000, 0x3F800000]));
+    vu.registers.set_vf(2, vec([0xFFFFFFFE, 0x3F800000, 0x40000000, 0x3F800000]));
+
+    // SUB.xyz vf3, vf1, vf2 computes the result but not the flags
+    vu.execute(decode_upper(0x01C208EC).unwrap(), LowerInstruction::Nop);
+    assert_eq!(vu.registers.vf(3), vec([0x7FFFFFFF, 0x00000000, 0xBF800000, 0x00000000]));
+    assert_eq!(vu.registers.mac_flags.bits(), 0);
+
+    // FSAND vi2, 0xFFF reads the flags, computing them first
+    vu.execute(UpperInstruction::Nop, decode_lower(0x2C020FFF).unwrap());
+    assert_eq!(vu.registers.vi(2), 0x2CB);
+    assert_eq!(vu.registers.mac_flags.bits(), 0x8024);
+    assert_eq!(vu.flag_mode(), FlagMode::Deferred);
+}
+
+#[test]
+fn vu_interpreter_deferred_flags_batch() {
+    // ADD.xyzw vf3, vf1, vf2
+    let add = decode_upper(0x01E208E8).unwrap();
+    let mut vu = VuInterpreter::new(Vec::new());
+    vu.set_flag_mode(FlagMode::Deferred);
+
+    vu.registers.set_vf(1, Ps2Vec4::splat(Ps2Float::new(0x7FFFFFFE)));
+    vu.registers.set_vf(2, Ps2Vec4::splat(Ps2Float::new(0x7FFFFFFE)));
+    vu.execute(add, LowerInstruction::Nop);
+    vu.registers.set_vf(1, Ps2Vec4::splat(Ps2Float::new(0x3F800000)));
+    vu.registers.set_vf(2, Ps2Vec4::splat(Ps2Float::new(0x3F800000)));
+    for _ in 0..1023 {
+        vu.execute(add, LowerInstruction::Nop);
+    }
+    assert_eq!(vu.registers.status_flags.bits(), 0);
+
+    // The batch is computed once it's full, keeping the sticky overflow
+    vu.execute(add, LowerInstruction::Nop);
+    assert_eq!(vu.registers.status_flags.bits(), 0x200);
+
+    vu.set_flag_mode(FlagMode::Eager);
+    assert_eq!(vu.registers.status_flags.bits(), 0x200);
+    assert_eq!(vu.registers.mac_flags.bits(), 0);
+}
+
+#[test]
+fn vu_interpreter_errors() {
+    let mut vu = VuInterpreter::new(vec![pair(NOP_UPPER, NOP_LOWER), pair(NOP_UPPER, 0x40000000)]);
+    let instruction = UnsupportedInstruction(0x40000000);
+    assert_eq!(vu.run(16), Err(VuError::Unsupported { pc: 1, instruction }));
+
+    let mut vu = VuInterpreter::new(vec![pair(NOP_UPPER, NOP_LOWER)]);
+    assert_eq!(vu.run(16), Err(VuError::PcOutOfRange { pc: 1 }));
+
+    let mut vu = VuInterpreter::new(vec![pair(NOP_UPPER, NOP_LOWER); 4]);
+    assert_eq!(vu.run(2), Err(VuError::StepLimit { steps: 2 }));
+    assert_eq!(vu.pc(), 2);
+}
+
+#[test]
+fn vu_register_file_dump() {
+    let mut registers = VuRegisterFile::new();
+    registers.set_vf(1, vec([0x7FFFFFFF, 0x00000001, 0xC0A9999A, 0x3F800000]));
+    registers.q = Ps2Float::new(0x3E800000);
+    registers.set_vi(1, 0xFFFF);
+    registers.clip_flags.push(ClipJudgment::from_bits(ClipJudgment::POS_X | ClipJudgment::NEG_Z));
+
+    let dump = registers.dump();
+    let lines: Vec<&str> = dump.lines().collect();
+
+    assert_eq!(lines.len(), 57);
+    assert_eq!(dump, registers.to_string());
+    assert_eq!(
+        lines[2],
+        "VF01   7FFFFFFF 00000001 C0A9999A 3F800000    6.805647e38   1.401298e-45      \
+         -5.300000       1.000000  x:Fmax y:denormalized"
+    );
+    assert_eq!(lines[34], format!("Q      3E800000{:27}{:>15}", "", "0.250000"));
+    assert_eq!(lines[39], format!("VI01       FFFF{:27}{:>15}", "", "-1"));
+    assert_eq!(lines[56], format!("CLIP     000021{:89}+x -z | - | - | -", ""));
+}
+
+#[test]
+fn vu_register_file_diff() {
+    // ADD.x vf1, vf2, vf3 with the E bit, then NOP/NOP
+    let program = vec![pair(0x01031068 | E_BIT, NOP_LOWER), pair(NOP_UPPER, NOP_LOWER)];
+    let mut vu = VuInterpreter::new(program);
+    vu.registers.set_vf(2, vec([0x3F800000, 0, 0, 0]));
+    vu.registers.set_vf(3, vec([0xC0000000, 0, 0, 0]));
+    let before = vu.registers.clone();
+
+    vu.run(16).unwrap();
+    let changes = before.diff(&vu.registers);
+
+    assert_eq!(before.diff(&before), []);
+    assert_eq!(
+        changes,
+        [
+            RegisterChange {
+                register: VuRegister::Vf(1),
+                before: VuRegisterValue::Vector(vec([0, 0, 0, 0])),
+                after: VuRegisterValue::Vector(vec([0xBF800000, 0, 0, 0])),
+            },
+            RegisterChange {
+                register: VuRegister::Mac,
+                before: VuRegisterValue::Mac(MacFlags::from_bits(0)),
+                after: VuRegisterValue::Mac(MacFlags::from_bits(0x0080)),
+            },
+            RegisterChange {
+                register: VuRegister::Status,
+                before: VuRegisterValue::Status(StatusFlags::from_bits(0)),
+                after: VuRegisterValue::Status(StatusFlags::from_bits(0x082)),
+            },
+        ]
+    );
+    assert_eq!(changes[1].to_string(), "MAC: 0000 -> 0080");
+}
+
+#[test]
+fn vu_flags_diff() {
+    let before = StatusFlags::from_bits(0x041);
+    let after = StatusFlags::from_bits(0x0C2);
+
+    assert_eq!(
+        before.diff(&after),
+        [
+            BitChange { bit: 0, before: true },
+            BitChange { bit: 1, before: false },
+            BitChange { bit: 7, before: false },
+        ]
+    );
+    assert_eq!(MacFlags::from_bits(0x8000).diff(&MacFlags::from_bits(0x8000)), []);
+
+    let mut clip = ClipFlags::from_bits(0x1);
+    clip.push(ClipJudgment::default());
+    assert_eq!(
+        ClipFlags::from_bits(0x1).diff(&clip),
+        [BitChange { bit: 0, before: true }, BitChange { bit: 6, before: false }]
+    );
+}
+
+#[test]
+fn vu_atomic_status_flags_accumulate_across_threads() {
+    let shared = AtomicStatusFlags::new();
+
+    std::thread::scope(|scope| {
+        for bit in 0..6 {
+            let shared = &shared;
+            scope.spawn(move || {
+                for _ in 0..1000 {
+                    shared.record(StatusFlags::from_bits(1 << bit));
+                }
+            });
+        }
+    });
+    shared.record(StatusFlags::from_bits(StatusFlags::STICKY));
+
+    assert_eq!(shared.sticky().bits(), StatusFlags::STICKY);
+    assert_eq!(shared.take().bits(), StatusFlags::STICKY);
+    assert_eq!(shared.sticky().bits(), 0);
+    // Sticky bits alone aren't counted.
+    for bit in 0..6 {
+        assert_eq!(shared.count(1 << bit), 1000);
+    }
+    assert_eq!(shared.count(StatusFlags::STICKY_ZERO), 0);
+    assert_eq!(shared.count(StatusFlags::ZERO | StatusFlags::SIGN), 0);
+
+    shared.clear_counts();
+    assert_eq!(shared.count(StatusFlags::OVERFLOW), 0);
+}
+
+#[rstest]
+#[case(0x0000, "-")]
+#[case(0x00CC, "Zx Zy Sx Sy")]
+#[case(0x0011, "Zw Sw")]
+#[case(0x8421, "Zw Sz Uy Ox")]
+#[case(0xFFFF, "Zx Zy Zz Zw Sx Sy Sz Sw Ux Uy Uz Uw Ox Oy Oz Ow")]
+fn vu_mac_flags_display(#[case] bits: u16, #[case] expected: &str) {
+    assert_eq!(MacFlags::from_bits(bits).to_string(), expected);
+}
+
+#[rstest]
+#[case(0x000, "- | sticky: -")]
+#[case(0x003, "Z S | sticky: -")]
+#[case(0x0C3, "Z S | sticky: Z S")]
+#[case(0xC0C, "U O | sticky: I D")]
+#[case(0xFFF, "Z S U O I D | sticky: Z S U O I D")]
+fn vu_status_flags_display(#[case] bits: u16, #[case] expected: &str) {
+    assert_eq!(StatusFlags::from_bits(bits).to_string(), expected);
+}
+
+#[test]
+fn vu_clip_flags_display() {
+    let mut clip = ClipFlags::default();
+    assert_eq!(clip.to_string(), "- | - | - | -");
+
+    clip.push(ClipJudgment::from_bits(ClipJudgment::POS_Y));
+    clip.push(ClipJudgment::from_bits(ClipJudgment::POS_X | ClipJudgment::NEG_Z));
+    assert_eq!(clip.to_string(), "+x -z | +y | - | -");
+}
@@ -0,0 +1,247 @@
+//! Differential tests of the VU executor against the scalar and vector APIs.
+//!
+//! Random decoded instruction pairs run on random register states, and the
+//! registers they change are compared with a model built from direct calls,
+//! catching wiring bugs like wrong field masks or swapped operands.
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::sampling::EdgeCaseGenerator;
+use ps2_floating_point::vu::instruction::{
+    FmacOperation, Lane, LowerInstruction, Operand, UpperInstruction,
+};
+use ps2_floating_point::vu::{FlagMode, MacFlags, StatusFlags, VuInterpreter, VuRegisterFile};
+use ps2_floating_point::{Ps2Float, Ps2Vec4};
+use rstest::*;
+
+/// The number of random instruction pairs run per seed.
+const PAIRS: usize = 500;
+
+/// Returns a random number below `n`.
+fn below(generator: &mut EdgeCaseGenerator, n: u64) -> u64 {
+    generator.next_bits() % n
+}
+
+fn random_vector(generator: &mut EdgeCaseGenerator) -> Ps2Vec4 {
+    Ps2Vec4::from_lanes(std::array::from_fn(|_| generator.next().unwrap()))
+}
+
+fn random_lane(generator: &mut EdgeCaseGenerator) -> Lane {
+    Lane::from_bits(generator.next_bits() as u32)
+}
+
+fn random_registers(generator: &mut EdgeCaseGenerator) -> VuRegisterFile {
+    let mut registers = VuRegisterFile::new();
+    for index in 0..32 {
+        registers.set_vf(index, random_vector(generator));
+    }
+    for index in 0..16 {
+        registers.set_vi(index, generator.next_bits() as u16);
+    }
+    registers.acc = random_vector(generator);
+    registers.q = generator.next().unwrap();
+    registers.i = generator.next().unwrap();
+    registers.status_flags = StatusFlags::from_bits(generator.next_bits() as u16);
+    registers.mac_flags = MacFlags::from_bits(generator.next_bits() as u16);
+    registers
+}
+
+/// Returns a random upper instruction whose arithmetic is implemented.
+fn random_upper(generator: &mut EdgeCaseGenerator) -> UpperInstruction {
+    let dest = below(generator, 16) as u8;
+    let (ft, fs) = (below(generator, 32) as u8, below(generator, 32) as u8);
+    let fraction_bits = [0, 4, 12, 15][below(generator, 4) as usize];
+
+    match below(generator, 5) {
+        0 => UpperInstruction::Nop,
+        1 => UpperInstruction::Abs { dest, ft, fs },
+        2 => UpperInstruction::Itof { dest, ft, fs, fraction_bits },
+        3 => UpperInstruction::Ftoi { dest, ft, fs, fraction_bits },
+        _ => {
+            let operation =
+                [FmacOperation::Add, FmacOperation::Sub, FmacOperation::Max, FmacOperation::Mini]
+                    [below(generator, 4) as usize];
+            let operand = match below(generator, 4) {
+                0 => Operand::Register(ft),
+                1 => Operand::Broadcast(ft, random_lane(generator)),
+                2 => Operand::Q,
+                _ => Operand::I,
+            };
+            let fd = (below(generator, 4) != 0).then(|| below(generator, 32) as u8);
+            UpperInstruction::Fmac { operation, dest, fd, fs, operand }
+        }
+    }
+}
+
+/// Returns a random lower instruction moving data between registers.
+fn random_lower(generator: &mut EdgeCaseGenerator) -> LowerInstruction {
+    let dest = below(generator, 16) as u8;
+    let (ft, fs) = (below(generator, 32) as u8, below(generator, 32) as u8);
+
+    match below(generator, 5) {
+        0 => LowerInstruction::Nop,
+        1 => LowerInstruction::Move { dest, ft, fs },
+        2 => LowerInstruction::Mr32 { dest, ft, fs },
+        3 => LowerInstruction::Mfir { dest, ft, is: below(generator, 16) as u8 },
+        _ => LowerInstruction::Mtir {
+            it: below(generator, 16) as u8,
+            fs,
+            fsf: random_lane(generator),
+        },
+    }
+}
+
+/// Returns the lanes of `new` selected by `dest` and the rest from `old`.
+fn masked(old: Ps2Vec4, new: Ps2Vec4, dest: u8) -> Ps2Vec4 {
+    let (old, new) = (old.lanes(), new.lanes());
+    Ps2Vec4::from_lanes(std::array::from_fn(|i| if dest & (8 >> i) != 0 { new[i] } else { old[i] }))
+}
+
+fn write_vf(registers: &mut VuRegisterFile, index: u8, dest: u8, value: Ps2Vec4) {
+    let old = registers.vf(index as usize);
+    registers.set_vf(index as usize, masked(old, value, dest));
+}
+
+/// Sets the MAC flags of the written lanes and updates the status flags from
+/// them, like an FMAC instruction does.
+fn write_flags(registers: &mut VuRegisterFile, dest: u8, flags: MacFlags) {
+    let mac = flags.bits() & (dest as u16 * 0x1111);
+    let groups = [MacFlags::ZERO, MacFlags::SIGN, MacFlags::UNDERFLOW, MacFlags::OVERFLOW];
+    let summary = (0..4).filter(|&i| mac & groups[i] != 0).fold(0, |bits, i| bits | 1 << i);
+
+    let status = registers.status_flags.bits();
+    registers.mac_flags = MacFlags::from_bits(mac);
+    registers.status_flags = StatusFlags::from_bits((status & !0xF) | summary | (summary << 6));
+}
+
+/// Combines two vectors lane by lane with `f`.
+fn zip(a: Ps2Vec4, b: Ps2Vec4, f: fn(Ps2Float, Ps2Float) -> Ps2Float) -> Ps2Vec4 {
+    let (a, b) = (a.lanes(), b.lanes());
+    Ps2Vec4::from_lanes(std::array::from_fn(|i| f(a[i], b[i])))
+}
+
+/// Applies the model of a lower instruction to `expected`.
+fn model_lower(before: &VuRegisterFile, expected: &mut VuRegisterFile, lower: LowerInstruction) {
+    match lower {
+        LowerInstruction::Nop => {}
+        LowerInstruction::Move { dest, ft, fs } => {
+            write_vf(expected, ft, dest, before.vf(fs as usize));
+        }
+        LowerInstruction::Mr32 { dest, ft, fs } => {
+            let [x, y, z, w] = before.vf(fs as usize).lanes();
+            write_vf(expected, ft, dest, Ps2Vec4::new(y, z, w, x));
+        }
+        LowerInstruction::Mfir { dest, ft, is } => {
+            let value = Ps2Float::new(before.vi(is as usize) as i16 as u32);
+            write_vf(expected, ft, dest, Ps2Vec4::splat(value));
+        }
+        LowerInstruction::Mtir { it, fs, fsf } => {
+            expected.set_vi(it as usize, fsf.of(&before.vf(fs as usize)).as_u32() as u16);
+        }
+        other => unreachable!("{:?} isn't generated", other),
+    }
+}
+
+/// Applies the model of an upper instruction to `expected`.
+fn model_upper(before: &VuRegisterFile, expected: &mut VuRegisterFile, upper: UpperInstruction) {
+    match upper {
+        UpperInstruction::Nop => {}
+        UpperInstruction::Abs { dest, ft, fs } => {
+            let lanes = before.vf(fs as usize).lanes();
+            let value = Ps2Vec4::from_bits(lanes.map(|lane| lane.as_u32() & 0x7FFFFFFF));
+            write_vf(expected, ft, dest, value);
+        }
+        UpperInstruction::Itof { dest, ft, fs, fraction_bits } => {
+            let values = before.vf(fs as usize).to_bits().map(|bits| bits as i32);
+            write_vf(expected, ft, dest, Ps2Vec4::itof(values, fraction_bits));
+        }
+        UpperInstruction::Ftoi { dest, ft, fs, fraction_bits } => {
+            let values = before.vf(fs as usize).ftoi(fraction_bits);
+            write_vf(expected, ft, dest, Ps2Vec4::from_bits(values.map(|value| value as u32)));
+        }
+        UpperInstruction::Fmac { operation, dest, fd, fs, operand } => {
+            let a = before.vf(fs as usize);
+            let b = match operand {
+                Operand::Register(ft) => before.vf(ft as usize),
+                Operand::Broadcast(ft, lane) => Ps2Vec4::splat(lane.of(&before.vf(ft as usize))),
+                Operand::Q => Ps2Vec4::splat(before.q),
+                Operand::I => Ps2Vec4::splat(before.i),
+            };
+
+            let (result, flags) = match operation {
+                FmacOperation::Add => {
+                    let (result, flags) = a.add_flagged(&b);
+                    (result, Some(flags))
+                }
+                FmacOperation::Sub => {
+                    let (result, flags) = a.sub_flagged(&b);
+                    (result, Some(flags))
+                }
+                // MAX and MINI don't touch the flags.
+                FmacOperation::Max => (zip(a, b, Ps2Float::max_of), None),
+                FmacOperation::Mini => (zip(a, b, Ps2Float::min_of), None),
+                other => unreachable!("{:?} isn't generated", other),
+            };
+
+            match fd {
+                Some(fd) => write_vf(expected, fd, dest, result),
+                None => expected.acc = masked(expected.acc, result, dest),
+            }
+            if let Some(flags) = flags {
+                write_flags(expected, dest, flags);
+            }
+        }
+        other => unreachable!("{:?} isn't generated", other),
+    }
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(0xDEADBEEF)]
+fn vu_executor_matches_scalar_api(#[case] seed: u64) {
+    let mut generator = EdgeCaseGenerator::new(seed);
+
+    for _ in 0..PAIRS {
+        let before = random_registers(&mut generator);
+        let (upper, lower) = (random_upper(&mut generator), random_lower(&mut generator));
+
+        // The upper instruction wins when both write the same register.
+        let mut expected = before.clone();
+        model_lower(&before, &mut expected, lower);
+        model_upper(&before, &mut expected, upper);
+
+        let mut vu = VuInterpreter::new(Vec::new());
+        vu.registers = before;
+        vu.execute(upper, lower);
+
+        assert_eq!(expected.diff(&vu.registers), [], "{:?} / {:?}", upper, lower);
+    }
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(0xDEADBEEF)]
+fn vu_executor_deferred_flags_match_eager(#[case] seed: u64) {
+    let mut generator = EdgeCaseGenerator::new(seed);
+    let mut eager = VuInterpreter::new(Vec::new());
+    eager.registers = random_registers(&mut generator);
+    let mut deferred = eager.clone();
+    deferred.set_flag_mode(FlagMode::Deferred);
+
+    for i in 0..PAIRS {
+        let upper = random_upper(&mut generator);
+        // Read the flags now and then, which computes the deferred ones.
+        let lower = match i % 50 {
+            0 => LowerInstruction::Fmor { it: 1, is: 2 },
+            25 => LowerInstruction::Fsor { it: 3, imm: 0 },
+            _ => random_lower(&mut generator),
+        };
+
+        eager.execute(upper, lower);
+        deferred.execute(upper, lower);
+    }
+    deferred.sync_flags();
+
+    assert_eq!(eager.registers.diff(&deferred.registers), []);
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "wasm")]
+
+use pretty_assertions::assert_eq;
+use ps2_floating_point::wasm::WasmPs2Float;
+
+#[test]
+fn wasm_arithmetic() {
+    let a = WasmPs2Float::new(0x40400000); // 3.00
+    let b = WasmPs2Float::from_params(false, 0x7F, 0); // 1.00
+
+    assert_eq!(a.add(&b).bits(), 0x40800000);
+    assert_eq!(a.sub(&b).bits(), 0x40000000);
+    assert_eq!(a.sub(&b).value(), 2.0);
+}
+
+#[test]
+fn wasm_breakdown() {
+    let value = WasmPs2Float::new(0x7FFFFFFF);
+
+    assert_eq!((value.sign(), value.exponent(), value.mantissa()), (false, 0xFF, 0x7FFFFF));
+    assert_eq!(value.classification(), "Fmax");
+    assert!(value.breakdown().starts_with("0x7FFFFFFF  6.805646932770577e38"));
+}